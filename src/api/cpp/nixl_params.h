@@ -61,6 +61,18 @@ class nixlAgentConfig {
          */
         std::chrono::microseconds etcdWatchTimeout;
 
+        /**
+         * @var ETCD endpoints, comma separated
+         *      Overrides NIXL_ETCD_ENDPOINTS for this agent when not empty.
+         */
+        std::string etcdEndpoints;
+
+        /**
+         * @var ETCD key namespace
+         *      Overrides NIXL_ETCD_NAMESPACE for this agent when not empty.
+         */
+        std::string etcdNamespace;
+
         /**
          * @brief  Agent configuration constructor for enabling various features.
          * @param use_prog_thread    flag to determine use of progress thread
@@ -0,0 +1,58 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Compares building a large transfer descriptor list with and without a
+//! capacity hint
+//!
+//! Run with `cargo bench --bench dlist_capacity`.
+
+use nixl_sys::{MemType, NixlError, XferDescList};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Number of descriptors per list, on the order of a large KV cache
+const DESC_COUNT: usize = 50_000;
+const ITERATIONS: u32 = 20;
+
+fn build(list: &mut XferDescList) -> Result<(), NixlError> {
+    for i in 0..DESC_COUNT {
+        list.add_desc(0x1000 * (i + 1), 0x1000, 0)?;
+    }
+    // Building the C-side list happens on first use
+    black_box(list.as_ptr());
+    Ok(())
+}
+
+fn time(name: &str, mut make_list: impl FnMut() -> Result<XferDescList<'static>, NixlError>) {
+    let mut total = Duration::ZERO;
+    for _ in 0..ITERATIONS {
+        let start = Instant::now();
+        let mut list = make_list().expect("Failed to create descriptor list");
+        build(&mut list).expect("Failed to add descriptors");
+        total += start.elapsed();
+        drop(list);
+    }
+    println!(
+        "{name:<16} {:>10.3?} per list of {DESC_COUNT}",
+        total / ITERATIONS
+    );
+}
+
+fn main() {
+    time("default", || XferDescList::new(MemType::Dram));
+    time("with_capacity", || {
+        XferDescList::with_capacity(MemType::Dram, DESC_COUNT)
+    });
+}
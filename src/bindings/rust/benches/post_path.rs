@@ -0,0 +1,93 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Times the uncontended post and status path of a single transfer request,
+//! which takes the agent's internal locks on every call
+//!
+//! Compare the default locks with `parking_lot` by running
+//! `cargo bench --bench post_path` and
+//! `cargo bench --bench post_path --features parking_lot`.
+
+use nixl_sys::{
+    Agent, MemType, NixlError, NixlRegistration, OptArgs, SystemStorage, XferDescList, XferOp,
+    XferStatus,
+};
+use std::time::{Duration, Instant};
+
+const ITERATIONS: u32 = 100_000;
+
+fn create_agent(name: &str) -> Result<(Agent, OptArgs), NixlError> {
+    let agent = Agent::new(name)?;
+    let (_mems, params) = agent.get_plugin_params("UCX")?;
+    let backend = agent.create_backend("UCX", &params)?;
+    let mut opt_args = OptArgs::new()?;
+    opt_args.add_backend(&backend)?;
+    Ok((agent, opt_args))
+}
+
+fn report(name: &str, elapsed: Duration, calls: u32) {
+    println!("{name:<8} {:>10.3?} per call over {calls} calls", elapsed / calls.max(1));
+}
+
+fn main() -> Result<(), NixlError> {
+    let (agent, opt_args) = create_agent("post_bench_local")?;
+    let (remote, remote_opt_args) = create_agent("post_bench_remote")?;
+
+    let mut local_storage = SystemStorage::new(4096)?;
+    local_storage.register(&agent, Some(&opt_args))?;
+    let mut remote_storage = SystemStorage::new(4096)?;
+    remote_storage.register(&remote, Some(&remote_opt_args))?;
+    agent.load_remote_md(&remote.get_local_md()?)?;
+
+    let mut local_descs = XferDescList::new(MemType::Dram)?;
+    local_descs.add_storage_desc(&local_storage)?;
+    let mut remote_descs = XferDescList::new(MemType::Dram)?;
+    remote_descs.add_storage_desc(&remote_storage)?;
+
+    let req = agent.create_xfer_req(
+        XferOp::Write,
+        &local_descs,
+        &remote_descs,
+        &remote.name(),
+        Some(&opt_args),
+    )?;
+
+    // Post and status are timed separately so the wait for the transfer
+    // itself is left out of both
+    let mut post = Duration::ZERO;
+    let mut status = Duration::ZERO;
+    let mut status_calls = 0;
+    for _ in 0..ITERATIONS {
+        let start = Instant::now();
+        let mut in_progress = agent.post_xfer_req(&req, Some(&opt_args))?;
+        post += start.elapsed();
+
+        while in_progress {
+            let start = Instant::now();
+            let current = agent.get_xfer_status(&req)?;
+            status += start.elapsed();
+            status_calls += 1;
+            match current {
+                XferStatus::InProgress => {}
+                XferStatus::Success => in_progress = false,
+                XferStatus::Failed { code } => panic!("Transfer failed with status {code}"),
+            }
+        }
+    }
+
+    report("post", post, ITERATIONS);
+    report("status", status, status_calls);
+    Ok(())
+}
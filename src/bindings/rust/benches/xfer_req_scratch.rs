@@ -0,0 +1,140 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Counts Rust heap allocations per transfer request made from prepared
+//! lists, with fresh index vectors versus a reused [`XferReqScratch`]
+//!
+//! Allocations made inside the NIXL library are not counted. Run with
+//! `cargo bench --bench xfer_req_scratch`.
+
+use nixl_sys::{
+    Agent, MemType, NixlError, NixlRegistration, OptArgs, SystemStorage, XferDescList, XferOp,
+    XferReqScratch,
+};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Instant;
+
+const DESC_COUNT: usize = 256;
+const REQUESTS: u64 = 10_000;
+
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn create_agent(name: &str) -> Result<(Agent, OptArgs), NixlError> {
+    let agent = Agent::new(name)?;
+    let (_mems, params) = agent.get_plugin_params("UCX")?;
+    let backend = agent.create_backend("UCX", &params)?;
+    let mut opt_args = OptArgs::new()?;
+    opt_args.add_backend(&backend)?;
+    Ok((agent, opt_args))
+}
+
+fn create_storage(agent: &Agent, opt_args: &OptArgs) -> Result<Vec<SystemStorage>, NixlError> {
+    let mut storage = Vec::with_capacity(DESC_COUNT);
+    for _ in 0..DESC_COUNT {
+        let mut region = SystemStorage::new(4096)?;
+        region.register(agent, Some(opt_args))?;
+        storage.push(region);
+    }
+    Ok(storage)
+}
+
+fn create_dlist(storage: &[SystemStorage]) -> Result<XferDescList<'_>, NixlError> {
+    let mut dlist = XferDescList::with_capacity(MemType::Dram, storage.len())?;
+    for region in storage {
+        dlist.add_storage_desc(region)?;
+    }
+    Ok(dlist)
+}
+
+fn run(name: &str, mut make_req: impl FnMut(i32) -> Result<(), NixlError>) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    for i in 0..REQUESTS {
+        make_req(i as i32).expect("Failed to make transfer request");
+    }
+    let elapsed = start.elapsed();
+    let per_req = (ALLOCATIONS.load(Ordering::Relaxed) - allocations) as f64 / REQUESTS as f64;
+    println!(
+        "{name:<10} {per_req:>6.2} allocations {:>10.3?} per request",
+        elapsed / REQUESTS as u32
+    );
+}
+
+fn main() -> Result<(), NixlError> {
+    let (agent, opt_args) = create_agent("scratch_bench_local")?;
+    let (remote, remote_opt_args) = create_agent("scratch_bench_remote")?;
+    let local_storage = create_storage(&agent, &opt_args)?;
+    let remote_storage = create_storage(&remote, &remote_opt_args)?;
+    agent.load_remote_md(&remote.get_local_md()?)?;
+
+    let local_handle = agent.prepare_xfer_dlist("", &create_dlist(&local_storage)?, None)?;
+    let remote_handle =
+        agent.prepare_xfer_dlist(&remote.name(), &create_dlist(&remote_storage)?, None)?;
+
+    // Each request moves a window of descriptors that shifts every time
+    let window = |i: i32| (0..DESC_COUNT as i32 / 2).map(move |idx| (idx + i) % DESC_COUNT as i32);
+
+    run("fresh", |i| {
+        let local_indices: Vec<i32> = window(i).collect();
+        let remote_indices: Vec<i32> = window(i + 1).collect();
+        agent
+            .make_xfer_req(
+                XferOp::Write,
+                &local_handle,
+                &local_indices,
+                &remote_handle,
+                &remote_indices,
+                None,
+            )
+            .map(drop)
+    });
+
+    let mut scratch = XferReqScratch::with_capacity(DESC_COUNT);
+    run("scratch", |i| {
+        scratch.set_indices(window(i), window(i + 1));
+        agent
+            .make_xfer_req_with_scratch(
+                XferOp::Write,
+                &local_handle,
+                &remote_handle,
+                &scratch,
+                None,
+            )
+            .map(drop)
+    });
+    Ok(())
+}
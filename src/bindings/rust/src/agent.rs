@@ -30,29 +30,142 @@ impl From<ThreadSync> for nixl_capi_thread_sync_t {
     }
 }
 
+/// How often [`Agent::drain`] checks outstanding transfers
+const DRAIN_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(1);
+
+/// How often [`Agent::verify_checksum`] checks for the remote checksum
+const CHECKSUM_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(1);
+
+/// How often [`Agent::copy`] checks its transfer
+const COPY_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(1);
+
+/// How often remotes are checked for invalidation, see [`Agent::on_remote_invalidated`]
+const REMOTE_WATCH_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(100);
+
+/// NIXL's `NIXL_ERR_CANCELED` status, reported for cancelled transfers
+const NIXL_ERR_CANCELED: i32 = -11;
+
+/// Number of live agents per name in this process, see [`AgentConfig::unique_name`]
+static LIVE_AGENT_NAMES: std::sync::OnceLock<Mutex<HashMap<String, usize>>> = std::sync::OnceLock::new();
+
+/// An agent name counted in [`LIVE_AGENT_NAMES`], released on drop
+#[derive(Debug)]
+struct AgentNameClaim(String);
+
+impl AgentNameClaim {
+    /// Counts `name` for a new agent
+    ///
+    /// # Errors
+    /// * `DuplicateAgentName` - If `unique` is set and a live agent already
+    ///   has the name
+    fn claim(name: &str, unique: bool) -> Result<Self, NixlError> {
+        let mut names = LIVE_AGENT_NAMES.get_or_init(Default::default).lock();
+        let count = names.entry(name.to_string()).or_default();
+        if unique && *count > 0 {
+            tracing::error!(target: TRACING_TARGET, agent.name = %name, "An agent with this name already exists");
+            return Err(NixlError::DuplicateAgentName(name.to_string()));
+        }
+        *count += 1;
+        Ok(Self(name.to_string()))
+    }
+}
+
+impl Drop for AgentNameClaim {
+    fn drop(&mut self) {
+        if let Some(names) = LIVE_AGENT_NAMES.get() {
+            let mut names = names.lock();
+            if let Some(count) = names.get_mut(&self.0) {
+                *count -= 1;
+                if *count == 0 {
+                    names.remove(&self.0);
+                }
+            }
+        }
+    }
+}
+
+/// A callback registered with [`Agent::on_remote_invalidated`]
+type InvalidationCallback = Box<dyn Fn(&str) + Send>;
+
 /// A NIXL agent that can create backends and manage memory
 #[derive(Debug, Clone)]
 pub struct Agent {
     inner: Arc<RwLock<AgentInner>>,
+    correlation_id: Option<Arc<str>>,
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum XferStatus {
     Success,
     InProgress,
+    /// The transfer was posted but failed, `code` is the NIXL error status
+    Failed { code: i32 },
 }
 
 impl XferStatus {
     pub fn is_success(&self) -> bool {
         return *self == XferStatus::Success;
     }
+
+    /// Returns true if the transfer was cancelled with [`Agent::cancel_xfer_req`]
+    pub fn is_cancelled(&self) -> bool {
+        *self == XferStatus::Failed { code: NIXL_ERR_CANCELED }
+    }
+}
+
+/// Result of [`Agent::try_post_xfer_req`]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum PostOutcome {
+    /// The transfer completed immediately
+    Completed,
+    /// The transfer was posted and is in progress
+    InProgress,
+    /// The backend is temporarily full; the request was not posted
+    WouldBlock,
+}
+
+/// Result of [`Agent::post_xfer_req2`]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum PostState {
+    /// The transfer completed immediately
+    Completed,
+    /// The transfer was posted and is in progress; poll
+    /// [`Agent::get_xfer_status`] until it completes
+    InProgress,
+}
+
+/// Result of [`Agent::connect_all`]
+#[derive(Debug, Default)]
+pub struct ConnectSummary {
+    /// Remotes a connection was made to, sorted by name
+    pub connected: Vec<String>,
+    /// Remotes that could not be connected to, with the error of each
+    pub failed: Vec<(String, NixlError)>,
+}
+
+impl ConnectSummary {
+    /// Returns true if every remote was connected to
+    pub fn all_connected(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+impl PostState {
+    /// Returns true if the transfer is still running after the post
+    pub fn is_in_progress(&self) -> bool {
+        *self == PostState::InProgress
+    }
 }
 
 impl Agent {
     /// Creates a new agent with the given name
+    ///
+    /// Other agents in this process may have the same name; see
+    /// [`AgentConfig::unique_name`] to reject duplicates.
     pub fn new(name: &str) -> Result<Self, NixlError> {
-        tracing::trace!(agent.name = %name, "Creating new NIXL agent");
+        tracing::trace!(target: TRACING_TARGET, agent.name = %name, "Creating new NIXL agent");
         let c_name = CString::new(name)?;
+        let name_claim = AgentNameClaim::claim(name, false)?;
         let mut agent = ptr::null_mut();
         let status = unsafe { nixl_capi_create_agent(c_name.as_ptr(), &mut agent) };
 
@@ -60,26 +173,38 @@ impl Agent {
             NIXL_CAPI_SUCCESS => {
                 // SAFETY: If status is NIXL_CAPI_SUCCESS, agent is non-null
                 let handle = unsafe { NonNull::new_unchecked(agent) };
-                tracing::trace!(agent.name = %name, "Successfully created NIXL agent");
+                tracing::trace!(target: TRACING_TARGET, agent.name = %name, "Successfully created NIXL agent");
                 Ok(Self {
-                    inner: Arc::new(RwLock::new(AgentInner::new(handle, name.to_string()))),
+                    inner: Arc::new(RwLock::new(AgentInner::new(handle, Arc::from(name), ThreadSync::Default, name_claim))),
+                    correlation_id: None,
                 })
             }
             NIXL_CAPI_ERROR_INVALID_PARAM => {
-                tracing::error!(agent.name = %name, error = "invalid_param", "Failed to create NIXL agent");
+                tracing::error!(target: TRACING_TARGET, agent.name = %name, error = "invalid_param", "Failed to create NIXL agent");
                 Err(NixlError::InvalidParam)
             }
             _ => {
-                tracing::error!(agent.name = %name, error = "backend_error", "Failed to create NIXL agent");
+                tracing::error!(target: TRACING_TARGET, agent.name = %name, error = "backend_error", "Failed to create NIXL agent");
                 Err(NixlError::BackendError)
             }
         }
     }
 
     /// Creates a new agent with the given configuration
+    ///
+    /// # Errors
+    /// * `DuplicateAgentName` - If `cfg.unique_name` is set and another live
+    ///   agent in this process has the same name
     pub fn new_configured(name: &str, cfg: &AgentConfig) -> Result<Self, NixlError> {
-        tracing::trace!(agent.name = %name, "Creating configured NIXL agent");
+        tracing::trace!(target: TRACING_TARGET, agent.name = %name, "Creating configured NIXL agent");
         let c_name = CString::new(name)?;
+        let name_claim = AgentNameClaim::claim(name, cfg.unique_name)?;
+        let c_etcd_endpoints = if cfg.etcd_endpoints.is_empty() {
+            None
+        } else {
+            Some(CString::new(cfg.etcd_endpoints.join(","))?)
+        };
+        let c_etcd_namespace = cfg.etcd_namespace.as_deref().map(CString::new).transpose()?;
 
         // Prepare C ABI config
         let mut c_cfg = nixl_capi_agent_config_t {
@@ -91,6 +216,8 @@ impl Agent {
             pthr_delay_us: cfg.pthr_delay_us,
             lthr_delay_us: cfg.lthr_delay_us,
             capture_telemetry: cfg.capture_telemetry,
+            etcd_endpoints: c_etcd_endpoints.as_ref().map_or(ptr::null(), |s| s.as_ptr()),
+            etcd_namespace: c_etcd_namespace.as_ref().map_or(ptr::null(), |s| s.as_ptr()),
         };
 
         let mut agent = ptr::null_mut();
@@ -102,36 +229,155 @@ impl Agent {
             NIXL_CAPI_SUCCESS => {
                 // SAFETY: If status is NIXL_CAPI_SUCCESS, agent is non-null
                 let handle = unsafe { NonNull::new_unchecked(agent) };
-                tracing::trace!(agent.name = %name, "Successfully created configured NIXL agent");
+                tracing::trace!(target: TRACING_TARGET, agent.name = %name, "Successfully created configured NIXL agent");
                 Ok(Self {
-                    inner: Arc::new(RwLock::new(AgentInner::new(handle, name.to_string()))),
+                    inner: Arc::new(RwLock::new(AgentInner::new(handle, Arc::from(name), cfg.thread_sync, name_claim))),
+                    correlation_id: None,
                 })
             }
             NIXL_CAPI_ERROR_INVALID_PARAM => {
-                tracing::error!(agent.name = %name, error = "invalid_param", "Failed to create configured NIXL agent");
+                tracing::error!(target: TRACING_TARGET, agent.name = %name, error = "invalid_param", "Failed to create configured NIXL agent");
                 Err(NixlError::InvalidParam)
             }
             _ => {
-                tracing::error!(agent.name = %name, error = "backend_error", "Failed to create configured NIXL agent");
+                tracing::error!(target: TRACING_TARGET, agent.name = %name, error = "backend_error", "Failed to create configured NIXL agent");
                 Err(NixlError::BackendError)
             }
         }
     }
 
+    /// Creates a new agent and loads the metadata of known peers
+    ///
+    /// The agent has no backends yet, and the NIXL library only loads
+    /// metadata carrying connection info if a matching backend exists, so this
+    /// suits metadata exported without connection info (see
+    /// [`OptArgs::set_include_conn_info`]). To seed full metadata, create the
+    /// agent and its backends first and call [`Agent::load_remotes`].
+    ///
+    /// # Errors
+    /// * `RemoteLoadFailed` - Names the first peer whose metadata could not be loaded
+    pub fn with_remotes(name: &str, remotes: &[(String, Vec<u8>)]) -> Result<Self, NixlError> {
+        let agent = Self::new(name)?;
+        agent.load_remotes(remotes)?;
+        Ok(agent)
+    }
+
+    /// Loads the metadata of each `(peer name, metadata)` pair, in order
+    ///
+    /// Loading is all or nothing: if a blob is malformed or belongs to an
+    /// agent other than the named peer, the remotes loaded by this call are
+    /// invalidated again before returning the error.
+    ///
+    /// # Errors
+    /// * `RemoteLoadFailed` - Names the first peer whose metadata could not be loaded
+    pub fn load_remotes(&self, remotes: &[(String, Vec<u8>)]) -> Result<(), NixlError> {
+        let mut inner = self.inner.write();
+        let loaded_before = inner.remotes.clone();
+        for (remote, metadata) in remotes {
+            let result = inner.load_remote_md(metadata).and_then(|name| {
+                if &name == remote {
+                    return Ok(());
+                }
+                tracing::error!(target: TRACING_TARGET, remote.agent = %remote, metadata.agent = %name, "Metadata belongs to another agent");
+                if !loaded_before.contains(&name) {
+                    inner.invalidate_remote_md(&name)?;
+                }
+                Err(NixlError::InvalidParam)
+            });
+
+            if let Err(e) = result {
+                let loaded: Vec<String> = inner.remotes.difference(&loaded_before).cloned().collect();
+                for name in loaded {
+                    inner.invalidate_remote_md(&name)?;
+                }
+                return Err(NixlError::RemoteLoadFailed {
+                    remote: remote.clone(),
+                    source: Box::new(e),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns a handle to the same agent that tags its operations with a correlation id
+    ///
+    /// Every operation performed through the returned handle runs inside a
+    /// `nixl` tracing span carrying a `correlation_id` field, so all events
+    /// emitted for one logical transfer (registration, preparation, posting,
+    /// status polling, waiting, notifications) can be grouped together even
+    /// when many transfers interleave in the log stream. The underlying agent
+    /// is shared; only this handle and its clones carry the id.
+    pub fn with_correlation_id(&self, id: &str) -> Agent {
+        Agent {
+            inner: self.inner.clone(),
+            correlation_id: Some(Arc::from(id)),
+        }
+    }
+
+    /// Wraps an agent kept alive by another object, such as a transfer request
+    pub(crate) fn from_inner(inner: Arc<RwLock<AgentInner>>) -> Agent {
+        Agent {
+            inner,
+            correlation_id: None,
+        }
+    }
+
+    /// Gets the correlation id attached to this handle, if any
+    pub fn correlation_id(&self) -> Option<&str> {
+        self.correlation_id.as_deref()
+    }
+
+    /// Creates the tracing span operations on this handle run in
+    ///
+    /// The span is at `INFO` level so the id still reaches warnings and
+    /// errors when trace output is filtered out.
+    fn span(&self) -> tracing::Span {
+        match &self.correlation_id {
+            Some(id) => tracing::info_span!(target: TRACING_TARGET, "nixl", correlation_id = %id),
+            None => tracing::Span::none(),
+        }
+    }
+
     /// Gets the name of the agent
     pub fn name(&self) -> String {
-        self.inner.read().unwrap().name.clone()
+        self.inner.read().name.to_string()
+    }
+
+    /// Gets a shared handle to the name of the agent
+    ///
+    /// Unlike [`Agent::name`], this only bumps a reference count and does not
+    /// allocate, which makes it suitable for hot logging paths.
+    pub fn name_arc(&self) -> Arc<str> {
+        self.inner.read().name.clone()
+    }
+
+    /// Returns the raw C handle of the agent, for calling unwrapped C functions
+    ///
+    /// # Safety
+    /// The handle stays valid only while this agent, one of its clones or one
+    /// of its transfer requests is alive, and must not be destroyed by the
+    /// caller. Calls through it bypass this crate's lock and
+    /// bookkeeping, so concurrent use requires an agent created with
+    /// `ThreadSync::Strict` or `ThreadSync::Rw`, and state changed behind the
+    /// crate's back (such as loaded remotes) is not reflected by its methods.
+    pub unsafe fn as_raw(&self) -> *mut nixl_capi_agent_s {
+        self.inner.read().handle.as_ptr()
+    }
+
+    /// Calls `f` with the name of the agent without allocating
+    pub fn with_name<R>(&self, f: impl FnOnce(&str) -> R) -> R {
+        f(&self.inner.read().name)
     }
 
     /// Gets the list of available plugins
     pub fn get_available_plugins(&self) -> Result<utils::StringList, NixlError> {
-        tracing::trace!("Getting available NIXL plugins");
+        tracing::trace!(target: TRACING_TARGET, "Getting available NIXL plugins");
         let mut plugins = ptr::null_mut();
 
         // SAFETY: self.inner is guaranteed to be valid by NonNull
         let status = unsafe {
             nixl_capi_get_available_plugins(
-                self.inner.write().unwrap().handle.as_ptr(),
+                self.inner.write().handle.as_ptr(),
                 &mut plugins,
             )
         };
@@ -140,20 +386,46 @@ impl Agent {
             0 => {
                 // SAFETY: If status is 0, plugins was successfully created and is non-null
                 let inner = unsafe { NonNull::new_unchecked(plugins) };
-                tracing::trace!("Successfully retrieved NIXL plugins");
+                tracing::trace!(target: TRACING_TARGET, "Successfully retrieved NIXL plugins");
                 Ok(utils::StringList::new(inner))
             }
             -1 => {
-                tracing::error!(error = "invalid_param", "Failed to get NIXL plugins");
+                tracing::error!(target: TRACING_TARGET, error = "invalid_param", "Failed to get NIXL plugins");
                 Err(NixlError::InvalidParam)
             }
             _ => {
-                tracing::error!(error = "backend_error", "Failed to get NIXL plugins");
+                tracing::error!(target: TRACING_TARGET, error = "backend_error", "Failed to get NIXL plugins");
                 Err(NixlError::BackendError)
             }
         }
     }
 
+    /// Checks whether a plugin is available without fetching the full plugin list
+    ///
+    /// # Arguments
+    /// * `name` - The plugin name, matched case-sensitively
+    ///
+    /// # Errors
+    /// Returns `InvalidParam` if the name contains interior nul bytes
+    pub fn has_plugin(&self, name: &str) -> Result<bool, NixlError> {
+        let c_name = CString::new(name).map_err(|_| NixlError::InvalidParam)?;
+        let mut has_plugin = false;
+
+        let status = unsafe {
+            nixl_capi_has_plugin(
+                self.inner.read().handle.as_ptr(),
+                c_name.as_ptr(),
+                &mut has_plugin,
+            )
+        };
+
+        match status {
+            NIXL_CAPI_SUCCESS => Ok(has_plugin),
+            NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
+            _ => Err(NixlError::BackendError),
+        }
+    }
+
     /// Gets the parameters for a plugin
     ///
     /// # Arguments
@@ -177,7 +449,7 @@ impl Agent {
         // SAFETY: self.inner is guaranteed to be valid by NonNull
         let status = unsafe {
             nixl_capi_get_plugin_params(
-                self.inner.read().unwrap().handle.as_ptr(),
+                self.inner.read().handle.as_ptr(),
                 plugin_name.as_ptr(),
                 &mut mems,
                 &mut params,
@@ -205,13 +477,13 @@ impl Agent {
         plugin: &str,
         params: &utils::Params,
     ) -> Result<Backend, NixlError> {
-        tracing::trace!(plugin.name = %plugin, "Creating new NIXL backend");
+        tracing::trace!(target: TRACING_TARGET, plugin.name = %plugin, "Creating new NIXL backend");
         let c_plugin = CString::new(plugin).map_err(|_| NixlError::InvalidParam)?;
         let name = c_plugin.to_string_lossy().to_string();
         let mut backend = ptr::null_mut();
         let status = unsafe {
             nixl_capi_create_backend(
-                self.inner.write().unwrap().handle.as_ptr(),
+                self.inner.write().handle.as_ptr(),
                 c_plugin.as_ptr(),
                 params.handle(),
                 &mut backend,
@@ -221,34 +493,91 @@ impl Agent {
         match status {
             NIXL_CAPI_SUCCESS => {
                 let backend_handle = NonNull::new(backend).ok_or(NixlError::BackendError)?;
-                self.inner
-                    .write()
-                    .unwrap()
-                    .backends
-                    .insert(name.clone(), backend_handle);
-                tracing::trace!(plugin.name = %plugin, "Successfully created NIXL backend");
-                Ok(Backend {
-                    inner: backend_handle,
-                })
+                let mut inner_guard = self.inner.write();
+                inner_guard.backends.insert(name.clone(), backend_handle);
+                inner_guard.backend_stats.entry(name.clone()).or_default();
+                drop(inner_guard);
+                tracing::trace!(target: TRACING_TARGET, plugin.name = %plugin, "Successfully created NIXL backend");
+                Ok(Backend::new(backend_handle, name, &self.inner))
             }
             NIXL_CAPI_ERROR_INVALID_PARAM => {
-                tracing::error!(plugin.name = %plugin, error = "invalid_param", "Failed to create NIXL backend");
+                tracing::error!(target: TRACING_TARGET, plugin.name = %plugin, error = "invalid_param", "Failed to create NIXL backend");
                 Err(NixlError::InvalidParam)
             }
             _ => {
-                tracing::error!(plugin.name = %plugin, error = "backend_error", "Failed to create NIXL backend");
+                tracing::error!(target: TRACING_TARGET, plugin.name = %plugin, error = "backend_error", "Failed to create NIXL backend");
                 Err(NixlError::BackendError)
             }
         }
     }
 
+    /// Creates a backend for each `(plugin, params)` pair, in order
+    ///
+    /// Stops at the first failure. Backends created before it remain
+    /// registered with the agent.
+    ///
+    /// # Errors
+    /// * `BackendAtIndex` - Wraps the error of the first spec that failed
+    pub fn create_backends(&self, specs: &[(String, utils::Params)]) -> Result<Vec<Backend>, NixlError> {
+        specs
+            .iter()
+            .enumerate()
+            .map(|(index, (plugin, params))| {
+                self.create_backend(plugin, params)
+                    .map_err(|e| NixlError::BackendAtIndex {
+                        index,
+                        plugin: plugin.clone(),
+                        source: Box::new(e),
+                    })
+            })
+            .collect()
+    }
+
+    /// Creates a backend for each `(plugin, params)` pair, skipping the ones that fail
+    ///
+    /// # Returns
+    /// The backends that were created, and the index and error of each spec that failed
+    pub fn create_backends_best_effort(
+        &self,
+        specs: &[(String, utils::Params)],
+    ) -> (Vec<Backend>, Vec<(usize, NixlError)>) {
+        let mut backends = Vec::new();
+        let mut failures = Vec::new();
+        for (index, (plugin, params)) in specs.iter().enumerate() {
+            match self.create_backend(plugin, params) {
+                Ok(backend) => backends.push(backend),
+                Err(e) => failures.push((index, e)),
+            }
+        }
+        (backends, failures)
+    }
+
+    /// Returns the names of all backends created on this agent, sorted
+    pub fn backend_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.inner.read().backends.keys().cloned().collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Returns all backends created on this agent, sorted by name
+    pub fn backends(&self) -> Vec<Backend> {
+        let mut backends: Vec<Backend> = self
+            .inner
+            .read()
+            .backends
+            .iter()
+            .map(|(name, backend)| Backend::new(*backend, name.clone(), &self.inner))
+            .collect();
+        backends.sort_unstable_by(|a, b| a.plugin.cmp(&b.plugin));
+        backends
+    }
+
     /// Gets a backend by name
     pub fn get_backend(&self, name: &str) -> Option<Backend> {
         self.inner
             .read()
-            .unwrap()
             .get_backend(name)
-            .map(|backend| Backend { inner: backend })
+            .map(|backend| Backend::new(backend, name.to_string(), &self.inner))
     }
 
     /// Gets the parameters and memory types for a backend after initialization
@@ -256,60 +585,250 @@ impl Agent {
         &self,
         backend: &Backend,
     ) -> Result<(MemList, utils::Params), NixlError> {
-        let mut mem_list = ptr::null_mut();
-        let mut params = ptr::null_mut();
+        let (_backend_agent, backend_handle) = backend.handle()?;
+        self.inner.read().backend_params(backend_handle)
+    }
 
+    /// Registers a memory descriptor with the agent
+    ///
+    /// Backends selected through `opt_args` are remembered by the returned
+    /// handle, so deregistration targets the same backends.
+    ///
+    /// # Arguments
+    /// * `descriptor` - The memory descriptor to register
+    /// * `opt_args` - Optional arguments for the registration
+    pub fn register_memory(
+        &self,
+        descriptor: &impl NixlDescriptor,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<RegistrationHandle, NixlError> {
+        let _span = self.span().entered();
+        let mut reg_dlist = RegDescList::new(descriptor.mem_type())?;
         let status = unsafe {
-            nixl_capi_get_backend_params(
-                self.inner.read().unwrap().handle.as_ptr(),
-                backend.inner.as_ptr(),
-                &mut mem_list,
-                &mut params,
+            reg_dlist.add_storage_desc(descriptor)?;
+
+            nixl_capi_register_mem(
+                self.inner.write().handle.as_ptr(),
+                reg_dlist.handle(),
+                opt_args.map_or(std::ptr::null_mut(), |args| args.inner.as_ptr()),
             )
         };
-
-        if status != NIXL_CAPI_SUCCESS {
-            return Err(NixlError::BackendError);
+        match status {
+            NIXL_CAPI_SUCCESS => Ok(RegistrationHandle::new(
+                &self.inner,
+                descriptor,
+                opt_args.map_or_else(Vec::new, |args| args.backend_names().to_vec()),
+            )),
+            NIXL_CAPI_ERROR_INVALID_PARAM => {
+                tracing::error!(target: TRACING_TARGET, error = "invalid_param", "Failed to register memory");
+                Err(NixlError::InvalidParam)
+            }
+            _ => {
+                tracing::error!(target: TRACING_TARGET, error = "backend_error", "Failed to register memory");
+                Err(NixlError::BackendError)
+            }
         }
+    }
 
-        // SAFETY: If status is NIXL_CAPI_SUCCESS, both pointers are non-null
-        unsafe {
-            Ok((
-                MemList {
-                    inner: NonNull::new_unchecked(mem_list),
-                },
-                utils::Params::new(NonNull::new_unchecked(params)),
-            ))
+    /// Registers a memory region and attaches `tag` to the returned handle
+    ///
+    /// The tag is only stored in the [`RegistrationHandle`], where
+    /// [`RegistrationHandle::tag`] returns it. NIXL has no per-region user
+    /// metadata in the exported agent metadata, so remote agents cannot see it.
+    ///
+    /// # Arguments
+    /// * `descriptor` - The memory descriptor to register
+    /// * `tag` - A `u64` or string identifying the region to the caller
+    /// * `opt_args` - Optional arguments selecting the backends
+    pub fn register_memory_tagged(
+        &self,
+        descriptor: &impl NixlDescriptor,
+        tag: impl Into<RegistrationTag>,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<RegistrationHandle, NixlError> {
+        Ok(self.register_memory(descriptor, opt_args)?.with_tag(tag.into()))
+    }
+
+    /// Allocates `size` bytes of zeroed memory aligned to `align` and registers it
+    ///
+    /// RDMA backends perform best on page-aligned memory, which they pin when
+    /// it is registered. The returned buffer deregisters and frees the memory
+    /// when dropped.
+    ///
+    /// # Errors
+    /// * `InvalidParam` - If `size` is zero or `align` is not a power of two
+    /// * `Unsupported` - If `mem_type` is not [`MemType::Dram`]
+    pub fn alloc_registered(
+        &self,
+        size: usize,
+        align: usize,
+        mem_type: MemType,
+    ) -> Result<RegisteredBuffer, NixlError> {
+        RegisteredBuffer::new(self, size, align, mem_type)
+    }
+
+    /// Lists the memory regions currently registered through this agent
+    ///
+    /// Regions are returned in registration order. Regions deregistered
+    /// through their handle or [`Agent::deregister_all`] are not listed.
+    pub fn registrations(&self) -> Vec<RegistrationInfo> {
+        let inner = self.inner.read();
+        let mut records: Vec<_> = inner.registrations.iter().collect();
+        records.sort_unstable_by_key(|(id, _)| **id);
+        records
+            .into_iter()
+            .map(|(_, record)| RegistrationInfo {
+                addr: record.ptr,
+                size: record.size,
+                dev_id: record.dev_id,
+                mem_type: record.mem_type,
+                tag: record.tag.clone(),
+            })
+            .collect()
+    }
+
+    /// Deregisters all memory registered through this agent
+    ///
+    /// Outstanding [`RegistrationHandle`]s become no-ops: dropping or
+    /// deregistering them afterwards does not touch the region again. A handle
+    /// deregistered concurrently on another thread is released exactly once,
+    /// by whichever call takes the agent lock first.
+    ///
+    /// All regions are attempted even if some fail; the first error is returned.
+    pub fn deregister_all(&self) -> Result<(), NixlError> {
+        let mut inner = self.inner.write();
+        let records = std::mem::take(&mut inner.registrations);
+        let mut result = Ok(());
+        for record in records.values() {
+            if let Err(e) = inner.deregister_record(record) {
+                tracing::error!(target: TRACING_TARGET, error = ?e, ptr = record.ptr, size = record.size, "Failed to deregister memory");
+                if result.is_ok() {
+                    result = Err(e);
+                }
+            }
         }
+        result
     }
 
-    /// Registers a memory descriptor with the agent
+    /// Registers a memory descriptor with exactly the given backends
+    ///
+    /// Backends already listed in `opt_args` are registered with as well;
+    /// NIXL reads nothing but the backend list when registering memory.
+    ///
+    /// Registration is best effort across the backends: each one is asked on
+    /// its own, the call succeeds if at least one of them accepts the region,
+    /// and the region stays registered with every backend that accepted it.
+    /// If one backend accepts and another rejects it, the returned handle only
+    /// records the one that accepted, so deregistering it releases exactly
+    /// that registration. It fails with `BackendError` only if all of them
+    /// reject the region.
     ///
     /// # Arguments
     /// * `descriptor` - The memory descriptor to register
-    /// * `opt_args` - Optional arguments for the registration
-    pub fn register_memory(
+    /// * `backends` - The backends to register with; must not be empty
+    /// * `opt_args` - Optional arguments for registering memory
+    pub fn register_memory_with(
         &self,
         descriptor: &impl NixlDescriptor,
+        backends: &[&Backend],
         opt_args: Option<&OptArgs>,
     ) -> Result<RegistrationHandle, NixlError> {
+        let _span = self.span().entered();
+        if backends.is_empty() {
+            return Err(NixlError::InvalidParam);
+        }
+
+        let mut extra = Vec::new();
+        for name in opt_args.map_or(&[][..], OptArgs::backend_names) {
+            if !backends.iter().any(|backend| backend.plugin_name() == *name) {
+                extra.push(self.get_backend(name).ok_or(NixlError::InvalidParam)?);
+            }
+        }
+
         let mut reg_dlist = RegDescList::new(descriptor.mem_type())?;
-        unsafe {
-            reg_dlist.add_storage_desc(descriptor)?;
+        reg_dlist.add_storage_desc(descriptor)?;
+        let mut accepted: Vec<String> = Vec::new();
+        for backend in backends.iter().copied().chain(&extra) {
+            let name = backend.plugin_name();
+            if accepted.contains(&name) {
+                continue;
+            }
+            let mut single = OptArgs::new()?;
+            single.add_backend(backend)?;
+            let status = unsafe {
+                nixl_capi_register_mem(self.inner.write().handle.as_ptr(), reg_dlist.handle(), single.inner.as_ptr())
+            };
+            match status {
+                NIXL_CAPI_SUCCESS => accepted.push(name),
+                NIXL_CAPI_ERROR_INVALID_PARAM => return Err(NixlError::InvalidParam),
+                _ => tracing::debug!(target: TRACING_TARGET, backend = %name, "Backend rejected the memory registration"),
+            }
+        }
+        if accepted.is_empty() {
+            tracing::error!(target: TRACING_TARGET, error = "backend_error", "No backend accepted the memory registration");
+            return Err(NixlError::BackendError);
+        }
+        Ok(RegistrationHandle::new(&self.inner, descriptor, accepted))
+    }
+
+    /// Registers every descriptor of a list and prepares the same regions for transfers
+    ///
+    /// The prepared list is built from the registered one with
+    /// [`RegDescList::to_xfer_dlist`], so it covers exactly what was
+    /// registered. Each descriptor gets its own handle, and the registrations
+    /// are undone if preparing fails.
+    ///
+    /// # Arguments
+    /// * `agent_name` - The agent the prepared list refers to, empty for this agent
+    /// * `descs` - The descriptors to register
+    /// * `opt_args` - Optional arguments for registering and preparing
+    ///
+    /// # Errors
+    /// * `InvalidParam` - If `descs` is empty or has a null or empty descriptor
+    pub fn register_and_prepare(
+        &self,
+        agent_name: &str,
+        descs: &RegDescList,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<(Vec<RegistrationHandle>, XferDlistHandle), NixlError> {
+        let _span = self.span().entered();
+        let c_agent_name = CString::new(agent_name)?;
+        let mem_type = descs.get_type()?;
+        if descs.is_empty()? {
+            return Err(NixlError::InvalidParam);
+        }
+        let regions = descs
+            .descriptors()
+            .iter()
+            .map(|desc| RawDescriptor::try_from((desc.addr, desc.len, desc.dev_id, mem_type)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let xfer_descs = descs.to_xfer_dlist()?;
 
+        let status = unsafe {
             nixl_capi_register_mem(
-                self.inner.write().unwrap().handle.as_ptr(),
-                reg_dlist.handle(),
+                self.inner.write().handle.as_ptr(),
+                descs.handle(),
                 opt_args.map_or(std::ptr::null_mut(), |args| args.inner.as_ptr()),
-            );
+            )
+        };
+        match status {
+            NIXL_CAPI_SUCCESS => {}
+            NIXL_CAPI_ERROR_INVALID_PARAM => return Err(NixlError::InvalidParam),
+            _ => return Err(NixlError::BackendError),
         }
-        Ok(RegistrationHandle {
-            agent: Some(self.inner.clone()),
-            ptr: unsafe { descriptor.as_ptr() } as usize,
-            size: descriptor.size(),
-            dev_id: descriptor.device_id(),
-            mem_type: descriptor.mem_type(),
-        })
+
+        let backends = opt_args.map_or_else(Vec::new, |args| args.backend_names().to_vec());
+        let handles: Vec<_> = regions
+            .iter()
+            .map(|region| RegistrationHandle::new(&self.inner, region, backends.clone()))
+            .collect();
+        // The lock is released before the handles can be dropped on failure,
+        // which deregisters the regions
+        let prepared = {
+            let inner_guard = self.inner.read();
+            AgentInner::prepare_xfer_dlist(inner_guard.handle, &c_agent_name, &xfer_descs, opt_args)
+        }?;
+        Ok((handles, prepared))
     }
 
     /// Query information about memory/storage
@@ -329,7 +848,7 @@ impl Agent {
         let resp = QueryResponseList::new()?;
 
         let status = {
-            let inner_guard = self.inner.write().unwrap();
+            let inner_guard = self.inner.write();
             unsafe {
                 nixl_capi_query_mem(
                     inner_guard.handle.as_ptr(),
@@ -349,50 +868,86 @@ impl Agent {
 
     /// Gets the local metadata for this agent as a byte array
     pub fn get_local_md(&self) -> Result<Vec<u8>, NixlError> {
-        tracing::trace!("Getting local metadata");
+        self.get_local_md_buf().map(|buf| buf.to_vec())
+    }
+
+    /// Gets the local metadata for this agent, compressed as selected by
+    /// [`OptArgs::set_md_compression`]
+    ///
+    /// Compression keeps large metadata within the value size limit of the
+    /// store it is exchanged through, such as etcd's 1.5 MiB default.
+    pub fn get_local_md_with(&self, opt_args: Option<&OptArgs>) -> Result<Vec<u8>, NixlError> {
+        compress_md(self.get_local_md()?, opt_args.map_or(Compression::None, OptArgs::md_compression))
+    }
+
+    /// Gets the local metadata for this agent without copying it
+    ///
+    /// The returned buffer owns the allocation made by the C API and frees
+    /// it when dropped.
+    pub fn get_local_md_buf(&self) -> Result<MdBuffer, NixlError> {
+        tracing::trace!(target: TRACING_TARGET, "Getting local metadata");
         let mut data = std::ptr::null_mut();
         let mut len = 0;
 
         let status = unsafe {
             nixl_capi_get_local_md(
-                self.inner.write().unwrap().handle.as_ptr(),
+                self.inner.write().handle.as_ptr(),
                 &mut data as *mut *mut _,
                 &mut len,
             )
         };
 
-        let data = data as *const u8;
-
-        if data.is_null() {
+        let Some(data) = NonNull::new(data as *mut u8) else {
             tracing::trace!(
+                target: TRACING_TARGET,
                 error = "invalid_data_pointer",
                 "Failed to get local metadata"
             );
             return Err(NixlError::InvalidDataPointer);
-        }
+        };
 
         match status {
             NIXL_CAPI_SUCCESS => {
-                let bytes = unsafe {
-                    let slice = std::slice::from_raw_parts(data, len);
-                    let vec = slice.to_vec();
-                    libc::free(data as *mut libc::c_void);
-                    vec
-                };
-                tracing::trace!(metadata.size = len, "Successfully retrieved local metadata");
-                Ok(bytes)
+                tracing::trace!(target: TRACING_TARGET, metadata.size = len, "Successfully retrieved local metadata");
+                // SAFETY: The C API allocated `len` bytes at `data` with malloc
+                Ok(unsafe { MdBuffer::from_raw(data, len) })
             }
             NIXL_CAPI_ERROR_INVALID_PARAM => {
-                tracing::error!(error = "invalid_param", "Failed to get local metadata");
+                tracing::error!(target: TRACING_TARGET, error = "invalid_param", "Failed to get local metadata");
                 Err(NixlError::InvalidParam)
             }
             _ => {
-                tracing::error!(error = "backend_error", "Failed to get local metadata");
+                tracing::error!(target: TRACING_TARGET, error = "backend_error", "Failed to get local metadata");
                 Err(NixlError::BackendError)
             }
         }
     }
 
+    /// Summarizes the local metadata this agent would send to remote agents
+    ///
+    /// Shorthand for [`parse_md_summary`] over [`Agent::get_local_md`].
+    pub fn describe_local_md(&self) -> Result<MdSummary, NixlError> {
+        parse_md_summary(&self.get_local_md_buf()?)
+    }
+
+    /// Gets the local metadata for this agent wrapped in [`AgentMetadata`]
+    pub fn local_md(&self) -> Result<AgentMetadata, NixlError> {
+        self.get_local_md().map(AgentMetadata::new)
+    }
+
+    /// Gets the local metadata for this agent as a base64 string
+    pub fn export_md_base64(&self) -> Result<String, NixlError> {
+        Ok(self.local_md()?.to_base64())
+    }
+
+    /// Loads remote metadata from a base64 string
+    ///
+    /// # Returns
+    /// The name of the remote agent
+    pub fn import_md_base64(&self, encoded: &str) -> Result<String, NixlError> {
+        self.load_remote_md(AgentMetadata::from_base64(encoded)?.as_bytes())
+    }
+
     /// Gets the local partial metadata as a byte array
     ///
     /// # Arguments
@@ -403,10 +958,10 @@ impl Agent {
     /// A byte array containing the local partial metadata
     ///
     pub fn get_local_partial_md(&self, descs: &RegDescList, opt_args: Option<&OptArgs>) -> Result<Vec<u8>, NixlError> {
-        tracing::trace!("Getting local partial metadata");
+        tracing::trace!(target: TRACING_TARGET, "Getting local partial metadata");
         let mut data = std::ptr::null_mut();
         let mut len: usize = 0;
-        let inner_guard = self.inner.write().unwrap();
+        let inner_guard = self.inner.write();
 
         let status = unsafe {
             nixl_capi_get_local_partial_md(
@@ -425,60 +980,113 @@ impl Agent {
                     libc::free(data as *mut libc::c_void);
                     vec
                 };
-                tracing::trace!(metadata.size = len, "Successfully retrieved local partial metadata");
-                Ok(bytes)
+                tracing::trace!(target: TRACING_TARGET, metadata.size = len, "Successfully retrieved local partial metadata");
+                compress_md(bytes, opt_args.map_or(Compression::None, OptArgs::md_compression))
             }
             NIXL_CAPI_ERROR_INVALID_PARAM => {
-                tracing::error!(error = "invalid_param", "Failed to get local partial metadata");
+                tracing::error!(target: TRACING_TARGET, error = "invalid_param", "Failed to get local partial metadata");
                 Err(NixlError::InvalidParam)
             }
             _ => {
-                tracing::error!(error = "backend_error", "Failed to get local partial metadata");
+                tracing::error!(target: TRACING_TARGET, error = "backend_error", "Failed to get local partial metadata");
                 Err(NixlError::BackendError)
             }
         }
     }
 
     /// Loads remote metadata from a byte slice
+    ///
+    /// Compressed metadata is decompressed first, see
+    /// [`OptArgs::set_md_compression`].
+    ///
+    /// # Errors
+    /// * `IncompatibleMdVersion` - If compressed metadata was produced by a
+    ///   newer version of this crate in a format it cannot read, see
+    ///   [`md_version`]. Raw metadata carries no version, so metadata from an
+    ///   incompatible NIXL release fails with the error of the C API instead.
     pub fn load_remote_md(&self, metadata: &[u8]) -> Result<String, NixlError> {
-        tracing::trace!(metadata.size = metadata.len(), "Loading remote metadata");
-        let mut agent_name = std::ptr::null_mut();
-
-        let status = unsafe {
-            nixl_capi_load_remote_md(
-                self.inner.write().unwrap().handle.as_ptr(),
-                metadata.as_ptr() as *const std::ffi::c_void,
-                metadata.len(),
-                &mut agent_name,
-            )
-        };
+        self.inner.write().load_remote_md(metadata)
+    }
 
-        match status {
-            NIXL_CAPI_SUCCESS => {
-                let name = unsafe {
-                    let c_str = std::ffi::CStr::from_ptr(agent_name);
-                    let s = c_str.to_str().unwrap().to_string();
-                    libc::free(agent_name as *mut libc::c_void);
-                    s
-                };
-                self.inner.write().unwrap().remotes.insert(name.clone());
-                tracing::trace!(remote.agent = %name, "Successfully loaded remote metadata");
-                Ok(name)
-            }
-            NIXL_CAPI_ERROR_INVALID_PARAM => {
-                tracing::error!(error = "invalid_param", "Failed to load remote metadata");
-                Err(NixlError::InvalidParam)
-            }
-            _ => {
-                tracing::error!(error = "backend_error", "Failed to load remote metadata");
-                Err(NixlError::BackendError)
+    /// Replaces the loaded metadata of a remote agent in one step
+    ///
+    /// The old metadata is invalidated and `metadata` is loaded while the
+    /// agent is locked, so other users of this agent never observe
+    /// `remote_agent` as unloaded. Descriptor lists prepared against the old
+    /// metadata are dropped from the preparation cache; transfer requests
+    /// created from it must be recreated. If `remote_agent` was not loaded,
+    /// this behaves like [`Agent::load_remote_md`].
+    ///
+    /// # Errors
+    /// * `InvalidParam` - If `metadata` belongs to another agent
+    ///
+    /// On any error, `remote_agent` is left unloaded.
+    ///
+    /// # Returns
+    /// The name of the remote agent
+    pub fn reload_remote_md(&self, remote_agent: &str, metadata: &[u8]) -> Result<String, NixlError> {
+        tracing::trace!(target: TRACING_TARGET, remote.agent = %remote_agent, "Reloading remote metadata");
+        let mut inner = self.inner.write();
+        let loaded_before = inner.remotes.clone();
+        if loaded_before.contains(remote_agent) {
+            inner.invalidate_remote_md(remote_agent)?;
+        }
+
+        let name = inner.load_remote_md(metadata)?;
+        if name != remote_agent {
+            tracing::error!(target: TRACING_TARGET, remote.agent = %remote_agent, metadata.agent = %name, "Metadata belongs to another agent");
+            if !loaded_before.contains(&name) {
+                inner.invalidate_remote_md(&name)?;
             }
+            return Err(NixlError::InvalidParam);
+        }
+        Ok(name)
+    }
+
+    /// Loads only the parts of remote metadata that cover the given descriptors
+    ///
+    /// The blob is filtered before it is handed to the C API: of the regions
+    /// it advertises, only those of the memory type of `descs` that overlap
+    /// one of its descriptors on the same device are loaded, which keeps the
+    /// receiver's footprint small when the sender exports its full metadata.
+    /// Connection info is always loaded.
+    ///
+    /// If the loaded regions do not cover every descriptor in `descs`,
+    /// `InvalidParam` is returned and the remote is invalidated again, unless
+    /// its metadata had already been loaded before. The whole sequence holds
+    /// the agent's lock, so a concurrent load of the same remote is not
+    /// undone by this call.
+    ///
+    /// # Returns
+    /// The name of the remote agent
+    pub fn load_remote_partial_md(
+        &self,
+        metadata: &[u8],
+        descs: &XferDescList,
+    ) -> Result<String, NixlError> {
+        let (name, filtered) = filter_md(metadata, descs.get_type()?, descs.descriptors())?;
+        let mut inner = self.inner.write();
+        let loaded_before = inner.remotes.contains(&name);
+        let name = inner.load_remote_md(&filtered)?;
+        if inner.covers_remote_descs(&name, descs) {
+            return Ok(name);
+        }
+
+        tracing::error!(target: TRACING_TARGET, remote.agent = %name, "Remote metadata does not cover the requested descriptors");
+        if !loaded_before {
+            inner.invalidate_remote_md(&name)?;
         }
+        Err(NixlError::InvalidParam)
     }
 
+    /// Connects to a remote agent whose metadata has been loaded
+    ///
+    /// Backends connect synchronously, so the connection is usable once this
+    /// returns `Ok(())`.
     pub fn make_connection(&self, remote_agent: &str, opt_args: Option<&OptArgs>) -> Result<(), NixlError> {
+        let _span = self.span().entered();
         let remote_agent = CString::new(remote_agent)?;
-        let inner_guard = self.inner.write().unwrap();
+        let inner_guard = self.inner.write();
 
         let status = unsafe {
             nixl_capi_agent_make_connection(
@@ -495,43 +1103,131 @@ impl Agent {
         }
     }
 
+    /// Makes a connection to every loaded remote agent
+    ///
+    /// Connections are otherwise set up by the first transfer to a remote, so
+    /// calling this during warmup keeps that latency out of steady state
+    /// transfers. Remotes are connected one at a time, since each connection
+    /// holds the agent's lock; a failure is recorded and the remaining remotes
+    /// are still connected.
+    ///
+    /// # Arguments
+    /// * `opt_args` - Optional arguments for the connections, such as the backends
+    pub fn connect_all(&self, opt_args: Option<&OptArgs>) -> ConnectSummary {
+        let _span = self.span().entered();
+        let mut summary = ConnectSummary::default();
+        for remote in self.loaded_remotes() {
+            match self.make_connection(&remote, opt_args) {
+                Ok(()) => summary.connected.push(remote),
+                Err(e) => {
+                    tracing::warn!(target: TRACING_TARGET, remote.agent = %remote, error = ?e, "Failed to connect to remote agent");
+                    summary.failed.push((remote, e));
+                }
+            }
+        }
+        summary
+    }
+
+    /// Connects to a remote agent on a background thread, calling `callback` with the result
+    ///
+    /// The callback runs once the backends have finished connecting, at which
+    /// point transfers to `remote_agent` can be issued.
+    ///
+    /// # Arguments
+    /// * `remote_agent` - Name of the remote agent to connect to
+    /// * `opt_args` - Optional arguments; only the selected backends are used
+    /// * `callback` - Called on the background thread with the connection result
+    pub fn make_connection_with_cb<F>(
+        &self,
+        remote_agent: &str,
+        opt_args: Option<&OptArgs>,
+        callback: F,
+    ) -> std::thread::JoinHandle<()>
+    where
+        F: FnOnce(Result<(), NixlError>) + Send + 'static,
+    {
+        let agent = self.clone();
+        let remote_agent = remote_agent.to_string();
+        // OptArgs cannot cross threads, so the backend selection is rebuilt there
+        let backends = opt_args.map_or_else(Vec::new, |args| args.backend_names().to_vec());
+
+        std::thread::spawn(move || {
+            let result = (|| {
+                if backends.is_empty() {
+                    return agent.make_connection(&remote_agent, None);
+                }
+                let mut opt_args = OptArgs::new()?;
+                for name in &backends {
+                    let backend = agent.get_backend(name).ok_or(NixlError::InvalidParam)?;
+                    opt_args.add_backend(&backend)?;
+                }
+                agent.make_connection(&remote_agent, Some(&opt_args))
+            })();
+            callback(result)
+        })
+    }
+
     pub fn prepare_xfer_dlist(
         &self,
         agent_name: &str,
         descs: &XferDescList,
         opt_args: Option<&OptArgs>,
     ) -> Result<XferDlistHandle, NixlError> {
+        let _span = self.span().entered();
         let c_agent_name = CString::new(agent_name)?;
-        let mut dlist_hndl = std::ptr::null_mut();
-        let inner_guard = self.inner.read().unwrap();
+        let inner_guard = self.inner.read();
+        AgentInner::prepare_xfer_dlist(inner_guard.handle, &c_agent_name, descs, opt_args)
+    }
 
-        let status = unsafe {
-            nixl_capi_prep_xfer_dlist(
-                inner_guard.handle.as_ptr(),
-                c_agent_name.as_ptr(),
-                descs.handle(),
-                &mut dlist_hndl,
-                opt_args.map_or(std::ptr::null_mut(), |args| args.inner.as_ptr()),
-            )
-        };
+    /// Prepares a descriptor list, reusing a previous preparation of the same list
+    ///
+    /// Lists are cached per agent name, descriptors and selected backends.
+    /// The cache holds up to 64 lists, evicting the least recently used one,
+    /// and lists prepared against a remote are dropped when its metadata is
+    /// invalidated.
+    ///
+    /// # Arguments
+    /// * `agent_name` - The agent the descriptors belong to
+    /// * `descs` - The descriptors to prepare
+    /// * `opt_args` - Optional arguments selecting the backends
+    pub fn prepare_xfer_dlist_cached(
+        &self,
+        agent_name: &str,
+        descs: &XferDescList,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<Arc<XferDlistHandle>, NixlError> {
+        let _span = self.span().entered();
+        let c_agent_name = CString::new(agent_name)?;
+        let mut inner_guard = self.inner.write();
+        let inner = &mut *inner_guard;
+        inner.prep_cache.get_or_prepare(agent_name, descs, opt_args, || {
+            AgentInner::prepare_xfer_dlist(inner.handle, &c_agent_name, descs, opt_args)
+        })
+    }
 
-        match status {
-            NIXL_CAPI_SUCCESS => Ok(XferDlistHandle::new(dlist_hndl, inner_guard.handle)),
-            _ => Err(NixlError::BackendError),
-        }
+    /// Drops all descriptor lists cached by [`Agent::prepare_xfer_dlist_cached`]
+    pub fn clear_prep_cache(&self) {
+        self.inner.write().prep_cache.clear();
     }
 
     pub fn make_xfer_req(&self, operation: XferOp,
                          local_descs: &XferDlistHandle, local_indices: &[i32],
                          remote_descs: &XferDlistHandle, remote_indices: &[i32],
                          opt_args: Option<&OptArgs>) -> Result<XferRequest, NixlError> {
+        let _span = self.span().entered();
+        let c_operation = operation.to_capi()?;
+        opt_args.map_or(Ok(()), OptArgs::check_priority)?;
+        if opt_args.is_some_and(OptArgs::checksum_enabled) {
+            tracing::error!(target: TRACING_TARGET, "Checksums need the descriptors of the transfer, use create_xfer_req");
+            return Err(NixlError::Unsupported);
+        }
         let mut req = std::ptr::null_mut();
-        let inner_guard = self.inner.read().unwrap();
+        let inner_guard = self.inner.read();
 
         let status = unsafe {
             nixl_capi_make_xfer_req(
                 inner_guard.handle.as_ptr(),
-                operation as bindings::nixl_capi_xfer_op_t,
+                c_operation,
                 local_descs.handle(),
                 local_indices.as_ptr(),
                 local_indices.len() as usize,
@@ -547,12 +1243,38 @@ impl Agent {
             NIXL_CAPI_SUCCESS => Ok(XferRequest::new(NonNull::new(req)
                 .ok_or(NixlError::FailedToCreateXferRequest)?,
                 self.inner.clone(),
-            )),
+                operation,
+                local_indices.len(),
+            )
+            .with_deadline(opt_args.and_then(OptArgs::deadline))
+            .with_xfer_tag(opt_args.and_then(OptArgs::xfer_tag))),
             NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
             _ => Err(NixlError::BackendError),
         }
     }
 
+    /// Creates a transfer request from prepared lists, taking the indices from
+    /// a reusable scratch
+    ///
+    /// Behaves like [`Agent::make_xfer_req`], see [`XferReqScratch`].
+    pub fn make_xfer_req_with_scratch(
+        &self,
+        operation: XferOp,
+        local_descs: &XferDlistHandle,
+        remote_descs: &XferDlistHandle,
+        scratch: &XferReqScratch,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<XferRequest, NixlError> {
+        self.make_xfer_req(
+            operation,
+            local_descs,
+            scratch.local_indices(),
+            remote_descs,
+            scratch.remote_indices(),
+            opt_args,
+        )
+    }
+
     /// Check if remote metadata for a specific agent is available
     ///
     /// This function checks if the metadata for the specified remote agent has been
@@ -567,12 +1289,13 @@ impl Agent {
     /// `true` if the remote agent's metadata is available (and descriptors are found if provided),
     /// `false` otherwise
     pub fn check_remote_metadata(&self, remote_agent: &str, descs: Option<&XferDescList>) -> bool {
-        tracing::trace!(remote_agent = %remote_agent, "Checking remote metadata");
+        tracing::trace!(target: TRACING_TARGET, remote_agent = %remote_agent, "Checking remote metadata");
 
         let c_remote_name = match CString::new(remote_agent) {
             Ok(name) => name,
             Err(_) => {
                 tracing::trace!(
+                    target: TRACING_TARGET,
                     error = "invalid_param",
                     remote_agent = %remote_agent,
                     "Invalid remote agent name"
@@ -583,7 +1306,7 @@ impl Agent {
 
         let status = unsafe {
             bindings::nixl_capi_check_remote_md(
-                self.inner.read().unwrap().handle.as_ptr(),
+                self.inner.read().handle.as_ptr(),
                 c_remote_name.as_ptr(),
                 descs.map_or(std::ptr::null_mut(), |d| d.as_ptr()),
             )
@@ -591,27 +1314,117 @@ impl Agent {
 
         match status {
             NIXL_CAPI_SUCCESS => {
-                tracing::trace!(remote_agent = %remote_agent, "Remote metadata is available");
+                tracing::trace!(target: TRACING_TARGET, remote_agent = %remote_agent, "Remote metadata is available");
                 true
             }
             _ => {
-                tracing::trace!(remote_agent = %remote_agent, "Remote metadata is not available");
+                tracing::trace!(target: TRACING_TARGET, remote_agent = %remote_agent, "Remote metadata is not available");
                 false
             }
         }
     }
 
+    /// Counts how many descriptors of `descs` are covered by a remote agent's metadata
+    ///
+    /// Each descriptor is checked individually, so partial coverage can be
+    /// detected. Returns `Ok(0)` if no metadata is loaded for the remote agent.
+    pub fn count_remote_metadata(
+        &self,
+        remote_agent: &str,
+        descs: &XferDescList,
+    ) -> Result<usize, NixlError> {
+        CString::new(remote_agent)?;
+
+        let mut count = 0;
+        for desc in descs.descriptors() {
+            let mut single = XferDescList::new(descs.get_type()?)?;
+            single.add_desc(desc.addr, desc.len, desc.dev_id)?;
+            if self.check_remote_metadata(remote_agent, Some(&single)) {
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
     /// Invalidates a remote metadata for this agent
     pub fn invalidate_remote_md(&self, remote_agent: &str) -> Result<(), NixlError> {
         self.inner
             .write()
-            .unwrap()
             .invalidate_remote_md(remote_agent)
     }
 
+    /// Invalidates the remote metadata of each named agent
+    ///
+    /// Unlike calling [`Agent::invalidate_remote_md`] in a loop, the agent is
+    /// locked once for the whole batch and unknown names do not abort it.
+    ///
+    /// # Returns
+    /// The names that had no loaded remote metadata
+    pub fn invalidate_remotes(&self, names: &[&str]) -> Result<Vec<String>, NixlError> {
+        let mut inner = self.inner.write();
+        let mut not_found = Vec::new();
+        for &name in names {
+            match inner.invalidate_remote_md(name) {
+                Ok(()) => {}
+                Err(NixlError::InvalidParam) => not_found.push(name.to_string()),
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(not_found)
+    }
+
     /// Invalidates all remote metadata for this agent
     pub fn invalidate_all_remotes(&self) -> Result<(), NixlError> {
-        self.inner.write().unwrap().invalidate_all_remotes()
+        self.inner.write().invalidate_all_remotes()
+    }
+
+    /// Checks whether metadata for `remote_agent` has been loaded by this agent
+    ///
+    /// Unlike [`Agent::check_remote_metadata`], this only consults the locally
+    /// tracked set of remotes and does not call into the C API.
+    pub fn is_remote_loaded(&self, remote_agent: &str) -> bool {
+        self.inner.read().remotes.contains(remote_agent)
+    }
+
+    /// Returns the names of the remote agents whose metadata is currently loaded
+    ///
+    /// Reflects agents added by `load_remote_md` and `fetch_remote_md` and not
+    /// yet invalidated. The names are sorted.
+    pub fn loaded_remotes(&self) -> Vec<String> {
+        let mut remotes: Vec<String> = self.inner.read().remotes.iter().cloned().collect();
+        remotes.sort_unstable();
+        remotes
+    }
+
+    /// Registers a callback invoked with the name of each remote agent whose
+    /// metadata is invalidated by its peer
+    ///
+    /// The C API does not report invalidations it receives through etcd
+    /// watches or its listener thread, so a background thread checks the
+    /// loaded remotes every 100 ms. A remote is reported once its metadata
+    /// has been seen loaded and then disappears, so remotes still being
+    /// fetched are not mistaken for departed ones, and remotes invalidated
+    /// through this agent are not reported. Reported remotes are forgotten by
+    /// the agent as if they had been invalidated locally.
+    ///
+    /// Callbacks run on the polling thread, which exits once the agent is
+    /// dropped. The agent owns its callbacks, so a callback capturing a clone
+    /// of the agent keeps it alive. A callback may register further
+    /// callbacks; they are first invoked for remotes departing afterwards.
+    /// A panic in a callback is logged and does not stop the other callbacks
+    /// or later reports.
+    pub fn on_remote_invalidated(&self, callback: impl Fn(&str) + Send + 'static) {
+        // The callbacks are locked apart from the agent, since a panicking
+        // callback leaves their lock poisoned
+        let callbacks = {
+            let mut inner = self.inner.write();
+            if !inner.remote_watch_started {
+                inner.remote_watch_started = true;
+                spawn_remote_watch(Arc::downgrade(&self.inner), inner.invalidation_callbacks.clone());
+            }
+            inner.invalidation_callbacks.clone()
+        };
+        callbacks.lock().push(Box::new(callback));
     }
 
     /// Send this agent's metadata to etcdAdd commentMore actions
@@ -621,8 +1434,9 @@ impl Agent {
     /// # Arguments
     /// * `opt_args` - Optional arguments for sending metadata
     pub fn send_local_md(&self, opt_args: Option<&OptArgs>) -> Result<(), NixlError> {
-        tracing::trace!("Sending local metadata to etcd");
-        let inner_guard = self.inner.write().unwrap();
+        tracing::trace!(target: TRACING_TARGET, "Sending local metadata to etcd");
+        check_md_uncompressed(opt_args)?;
+        let inner_guard = self.inner.write();
         let status = unsafe {
             bindings::nixl_capi_send_local_md(
                 inner_guard.handle.as_ptr(),
@@ -632,11 +1446,12 @@ impl Agent {
 
         match status {
             NIXL_CAPI_SUCCESS => {
-                tracing::trace!("Successfully sent local metadata to etcd");
+                tracing::trace!(target: TRACING_TARGET, "Successfully sent local metadata to etcd");
                 Ok(())
             }
             NIXL_CAPI_ERROR_INVALID_PARAM => {
                 tracing::error!(
+                    target: TRACING_TARGET,
                     error = "invalid_param",
                     "Failed to send local metadata to etcd"
                 );
@@ -644,6 +1459,7 @@ impl Agent {
             }
             _ => {
                 tracing::error!(
+                    target: TRACING_TARGET,
                     error = "backend_error",
                     "Failed to send local metadata to etcd"
                 );
@@ -652,14 +1468,26 @@ impl Agent {
         }
     }
 
+    /// Send this agent's metadata to etcd, invalidating it when the returned guard is dropped
+    ///
+    /// See [`LocalMdGuard`] for the cases the guard cannot cover.
+    ///
+    /// # Arguments
+    /// * `opt_args` - Optional arguments for sending metadata
+    pub fn publish_md_scoped(&self, opt_args: Option<&OptArgs>) -> Result<LocalMdGuard, NixlError> {
+        self.send_local_md(opt_args)?;
+        Ok(LocalMdGuard::new(self.clone()))
+    }
+
     /// Send this agent's partial metadata
     ///
     /// # Arguments
     /// * `descs` - Registration descriptor list to send
     /// * `opt_args` - Optional arguments for sending metadata
     pub fn send_local_partial_md(&self, descs: &RegDescList, opt_args: Option<&OptArgs>) -> Result<(), NixlError> {
-        tracing::trace!("Sending local partial metadata to etcd");
-        let inner_guard = self.inner.write().unwrap();
+        tracing::trace!(target: TRACING_TARGET, "Sending local partial metadata to etcd");
+        check_md_uncompressed(opt_args)?;
+        let inner_guard = self.inner.write();
         let status = unsafe {
             nixl_capi_send_local_partial_md(
                 inner_guard.handle.as_ptr(),
@@ -669,11 +1497,11 @@ impl Agent {
         };
         match status {
             NIXL_CAPI_SUCCESS => {
-                tracing::trace!("Successfully sent local partial metadata to etcd");
+                tracing::trace!(target: TRACING_TARGET, "Successfully sent local partial metadata to etcd");
                 Ok(())
             }
             NIXL_CAPI_ERROR_INVALID_PARAM => {
-                tracing::error!(error = "invalid_param", "Failed to send local partial metadata to etcd");
+                tracing::error!(target: TRACING_TARGET, error = "invalid_param", "Failed to send local partial metadata to etcd");
                 Err(NixlError::InvalidParam)
             }
             _ => Err(NixlError::BackendError)
@@ -694,38 +1522,34 @@ impl Agent {
         remote_name: &str,
         opt_args: Option<&OptArgs>,
     ) -> Result<(), NixlError> {
-        tracing::trace!(remote_agent = %remote_name, "Fetching remote metadata from etcd");
-
         let c_remote_name = CString::new(remote_name)?;
-        let inner_guard = self.inner.write().unwrap();
-
-        let status = unsafe {
-            bindings::nixl_capi_fetch_remote_md(
-                inner_guard.handle.as_ptr(),
-                c_remote_name.as_ptr(),
-                opt_args.map_or(std::ptr::null_mut(), |args| args.inner.as_ptr()),
-            )
-        };
+        self.inner.write().fetch_remote_md(remote_name, &c_remote_name, opt_args)
+    }
 
-        match status {
-            NIXL_CAPI_SUCCESS => {
-                self.inner
-                    .write()
-                    .unwrap()
-                    .remotes
-                    .insert(remote_name.to_string());
-                tracing::trace!(remote_agent = %remote_name, "Successfully fetched remote metadata from etcd");
-                Ok(())
-            }
-            NIXL_CAPI_ERROR_INVALID_PARAM => {
-                tracing::error!(error = "invalid_param", remote_agent = %remote_name, "Failed to fetch remote metadata from etcd");
-                Err(NixlError::InvalidParam)
-            }
-            _ => {
-                tracing::error!(error = "backend_error", remote_agent = %remote_name, "Failed to fetch remote metadata from etcd");
-                Err(NixlError::BackendError)
-            }
+    /// Replaces the loaded metadata of a remote agent with a fresh copy from etcd
+    ///
+    /// The etcd analog of [`Agent::reload_remote_md`]: the old metadata is
+    /// invalidated and the fetch is requested while the agent is locked, so
+    /// the remote stays tracked as loaded throughout. The NIXL library loads
+    /// fetched metadata asynchronously, so transfers to `remote_agent` can
+    /// still fail until it arrives; poll [`Agent::check_remote_metadata`] to
+    /// wait for it.
+    ///
+    /// # Arguments
+    /// * `remote_agent` - Name of the remote agent to fetch metadata for
+    /// * `opt_args` - Optional arguments for fetching metadata
+    pub fn refetch_remote_md(
+        &self,
+        remote_agent: &str,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<(), NixlError> {
+        tracing::trace!(target: TRACING_TARGET, remote_agent = %remote_agent, "Refetching remote metadata from etcd");
+        let c_remote_name = CString::new(remote_agent)?;
+        let mut inner = self.inner.write();
+        if inner.remotes.contains(remote_agent) {
+            inner.invalidate_remote_md(remote_agent)?;
         }
+        inner.fetch_remote_md(remote_agent, &c_remote_name, opt_args)
     }
 
     /// Invalidate this agent's metadata in etcd
@@ -735,8 +1559,8 @@ impl Agent {
     /// # Arguments
     /// * `opt_args` - Optional arguments for invalidating metadata
     pub fn invalidate_local_md(&self, opt_args: Option<&OptArgs>) -> Result<(), NixlError> {
-        tracing::trace!("Invalidating local metadata in etcd");
-        let inner_guard = self.inner.write().unwrap();
+        tracing::trace!(target: TRACING_TARGET, "Invalidating local metadata in etcd");
+        let inner_guard = self.inner.write();
         let status = unsafe {
             bindings::nixl_capi_invalidate_local_md(
                 inner_guard.handle.as_ptr(),
@@ -746,11 +1570,12 @@ impl Agent {
 
         match status {
             NIXL_CAPI_SUCCESS => {
-                tracing::trace!("Successfully invalidated local metadata in etcd");
+                tracing::trace!(target: TRACING_TARGET, "Successfully invalidated local metadata in etcd");
                 Ok(())
             }
             NIXL_CAPI_ERROR_INVALID_PARAM => {
                 tracing::error!(
+                    target: TRACING_TARGET,
                     error = "invalid_param",
                     "Failed to invalidate local metadata in etcd"
                 );
@@ -758,6 +1583,7 @@ impl Agent {
             }
             _ => {
                 tracing::error!(
+                    target: TRACING_TARGET,
                     error = "backend_error",
                     "Failed to invalidate local metadata in etcd"
                 );
@@ -775,17 +1601,55 @@ impl Agent {
     ///
     /// # Returns
     /// `Ok(())` if the notification was sent successfully
+    ///
+    /// Remotes loaded behind this agent's back, through the listener thread,
+    /// an IP fetch or etcd, are not tracked by [`Agent::is_remote_loaded`];
+    /// for those the C agent is asked with [`Agent::check_remote_metadata`].
+    ///
+    /// # Errors
+    /// * `RemoteNotLoaded` - If the C agent has no metadata for the remote agent
     pub fn send_notification(
         &self,
         remote_agent: &str,
         message: &[u8],
         backend: Option<&Backend>,
     ) -> Result<(), NixlError> {
-        tracing::trace!(remote_agent = %remote_agent, "Sending notification");
+        let _span = self.span().entered();
+        if !self.is_remote_loaded(remote_agent) && !self.check_remote_metadata(remote_agent, None) {
+            tracing::error!(target: TRACING_TARGET, remote_agent = %remote_agent, "Remote metadata not loaded, cannot send notification");
+            return Err(NixlError::RemoteNotLoaded(remote_agent.to_string()));
+        }
+        self.send_notification_unchecked(remote_agent, message, backend)
+    }
 
-        let c_remote_name = CString::new(remote_agent)?;
-        let inner_guard = self.inner.write().unwrap();
+    /// Send a UTF-8 notification to a remote agent
+    ///
+    /// Equivalent to [`Agent::send_notification`] with the bytes of `message`.
+    /// Received messages can be decoded with [`NotificationMap::iter_str`].
+    pub fn send_notification_str(
+        &self,
+        remote_agent: &str,
+        message: &str,
+        backend: Option<&Backend>,
+    ) -> Result<(), NixlError> {
+        self.send_notification(remote_agent, message.as_bytes(), backend)
+    }
+
+    /// Send a notification to a remote agent without checking that its metadata is loaded
+    ///
+    /// Unlike [`Agent::send_notification`], the remote is not looked up in the
+    /// set of remotes loaded through this agent, so a missing remote surfaces
+    /// as a backend error.
+    pub fn send_notification_unchecked(
+        &self,
+        remote_agent: &str,
+        message: &[u8],
+        backend: Option<&Backend>,
+    ) -> Result<(), NixlError> {
+        let _span = self.span().entered();
+        tracing::trace!(target: TRACING_TARGET, remote_agent = %remote_agent, "Sending notification");
 
+        let c_remote_name = CString::new(remote_agent)?;
         let opt_args = if backend.is_some() {
             let mut args = OptArgs::new()?;
             if let Some(b) = backend {
@@ -796,6 +1660,7 @@ impl Agent {
             None
         };
 
+        let inner_guard = self.inner.write();
         let status = unsafe {
             nixl_capi_gen_notif(
                 inner_guard.handle.as_ptr(),
@@ -810,15 +1675,15 @@ impl Agent {
 
         match status {
             NIXL_CAPI_SUCCESS => {
-                tracing::trace!(remote_agent = %remote_agent, "Successfully sent notification");
+                tracing::trace!(target: TRACING_TARGET, remote_agent = %remote_agent, "Successfully sent notification");
                 Ok(())
             }
             NIXL_CAPI_ERROR_INVALID_PARAM => {
-                tracing::error!(error = "invalid_param", remote_agent = %remote_agent, "Failed to send notification");
+                tracing::error!(target: TRACING_TARGET, error = "invalid_param", remote_agent = %remote_agent, "Failed to send notification");
                 Err(NixlError::InvalidParam)
             }
             _ => {
-                tracing::error!(error = "backend_error", remote_agent = %remote_agent, "Failed to send notification");
+                tracing::error!(target: TRACING_TARGET, error = "backend_error", remote_agent = %remote_agent, "Failed to send notification");
                 Err(NixlError::BackendError)
             }
         }
@@ -831,13 +1696,20 @@ impl Agent {
     /// * `local_descs` - The local descriptor list
     /// * `remote_descs` - The remote descriptor list
     /// * `remote_agent` - The name of the remote agent
-    /// * `opt_args` - Optional arguments for the transfer
+    /// * `opt_args` - Optional arguments for the transfer; a message set with
+    ///   [`OptArgs::set_notification`] is delivered to the remote agent once
+    ///   the transferred data has landed
     ///
     /// # Returns
     /// A handle to the transfer request
     ///
     /// # Errors
-    /// Returns a NixlError if the operation fails
+    /// Returns a NixlError if the operation fails, or `Unsupported` for
+    /// [`XferOp::WriteDurable`], for remote atomics and for priorities set with
+    /// [`OptArgs::set_priority`]. With [`OptArgs::enable_checksum`], local
+    /// descriptors outside DRAM are `Unsupported` and a missing notification
+    /// message is `InvalidParam`. With [`OptArgs::set_strict_validation`],
+    /// memory types no candidate backend supports are `InvalidParam`.
     pub fn create_xfer_req(
         &self,
         operation: XferOp,
@@ -846,17 +1718,30 @@ impl Agent {
         remote_agent: &str,
         opt_args: Option<&OptArgs>,
     ) -> Result<XferRequest, NixlError> {
-        let remote_agent = CString::new(remote_agent)?;
+        let _span = self.span().entered();
+        let c_operation = operation.to_capi()?;
+        opt_args.map_or(Ok(()), OptArgs::check_priority)?;
+        if let Some(args) = opt_args.filter(|args| args.strict_validation()) {
+            let mem_types = [local_descs.get_type()?, remote_descs.get_type()?];
+            self.inner.read().check_mem_types(&mem_types, args)?;
+        }
+        let checksum = match opt_args {
+            Some(args) if args.checksum_enabled() => {
+                Some(checksum::XferChecksum::for_xfer(local_descs, remote_agent, args)?)
+            }
+            _ => None,
+        };
+        let c_remote_agent = CString::new(remote_agent)?;
         let mut req = std::ptr::null_mut();
 
         // SAFETY: All pointers are guaranteed to be valid
         let status = unsafe {
             bindings::nixl_capi_create_xfer_req(
-                self.inner.read().unwrap().handle.as_ptr(),
-                operation as bindings::nixl_capi_xfer_op_t,
+                self.inner.read().handle.as_ptr(),
+                c_operation,
                 local_descs.handle(),
                 remote_descs.handle(),
-                remote_agent.as_ptr(),
+                c_remote_agent.as_ptr(),
                 &mut req,
                 opt_args.map_or(std::ptr::null_mut(), |args| args.inner.as_ptr()),
             )
@@ -866,13 +1751,69 @@ impl Agent {
             NIXL_CAPI_SUCCESS => {
                 // SAFETY: If status is NIXL_CAPI_SUCCESS, req is guaranteed to be non-null
                 let inner = NonNull::new(req).ok_or(NixlError::FailedToCreateXferRequest)?;
-                Ok(XferRequest::new(inner, self.inner.clone()))
+                let total_bytes = local_descs.descriptors().iter().map(|d| d.len as u64).sum();
+                Ok(XferRequest::new(inner, self.inner.clone(), operation, local_descs.descriptors().len())
+                    .with_remote_agent(remote_agent)
+                    .with_total_bytes(total_bytes)
+                    .with_checksum(checksum)
+                    .with_deadline(opt_args.and_then(OptArgs::deadline))
+                    .with_xfer_tag(opt_args.and_then(OptArgs::xfer_tag)))
             }
             NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
             _ => Err(NixlError::FailedToCreateXferRequest),
         }
     }
 
+    /// Creates one transfer request per `chunk_bytes` of a large transfer
+    ///
+    /// The paired local and remote descriptors are walked in order and split
+    /// at chunk boundaries, so every request but the last moves exactly
+    /// `chunk_bytes` bytes and a chunk may span several descriptors. The
+    /// requests can be posted together and waited for with
+    /// [`Agent::wait_all`]. `opt_args` apply to every chunk, so a
+    /// notification message is delivered once per chunk.
+    ///
+    /// # Errors
+    /// * `InvalidParam` - If `chunk_bytes` is zero, or the lists differ in
+    ///   length or in the length of a descriptor pair
+    /// * `Unsupported` - If `opt_args` enable checksums, which identify a
+    ///   transfer by its notification message
+    ///
+    /// Other errors are those of [`Agent::create_xfer_req`]; requests created
+    /// before a failure are released.
+    pub fn create_chunked_xfer(
+        &self,
+        operation: XferOp,
+        local_descs: &XferDescList,
+        remote_descs: &XferDescList,
+        remote_agent: &str,
+        chunk_bytes: usize,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<Vec<XferRequest>, NixlError> {
+        if chunk_bytes == 0 || local_descs.len()? != remote_descs.len()? {
+            return Err(NixlError::InvalidParam);
+        }
+        if opt_args.is_some_and(OptArgs::checksum_enabled) {
+            tracing::error!(target: TRACING_TARGET, "Chunks of a transfer cannot be told apart by checksum notifications");
+            return Err(NixlError::Unsupported);
+        }
+
+        let chunks = chunk_descs(local_descs.descriptors(), remote_descs.descriptors(), chunk_bytes)?;
+        let (local_type, remote_type) = (local_descs.get_type()?, remote_descs.get_type()?);
+        chunks
+            .into_iter()
+            .map(|chunk| {
+                let mut local = XferDescList::with_capacity(local_type, chunk.len())?;
+                let mut remote = XferDescList::with_capacity(remote_type, chunk.len())?;
+                for (local_desc, remote_desc) in chunk {
+                    local.add_desc(local_desc.addr, local_desc.len, local_desc.dev_id)?;
+                    remote.add_desc(remote_desc.addr, remote_desc.len, remote_desc.dev_id)?;
+                }
+                self.create_xfer_req(operation, &local, &remote, remote_agent, opt_args)
+            })
+            .collect()
+    }
+
     /// Estimates the cost of a transfer request
     ///
     /// # Arguments
@@ -889,26 +1830,39 @@ impl Agent {
         req: &XferRequest,
         opt_args: Option<&OptArgs>,
     ) -> Result<(i64, i64, CostMethod), NixlError> {
-        let mut duration_us: i64 = 0;
-        let mut err_margin_us: i64 = 0;
-        let mut method: u32 = 0;
+        AgentInner::lock_xfer(&self.inner).estimate_xfer_cost(req, opt_args)
+    }
 
-        let status = unsafe {
-            nixl_capi_estimate_xfer_cost(
-                self.inner.write().unwrap().handle.as_ptr(),
-                req.handle(),
-                opt_args.map_or(ptr::null_mut(), |args| args.inner.as_ptr()),
-                &mut duration_us,
-                &mut err_margin_us,
-                &mut method as *mut u32 as *mut bindings::nixl_capi_cost_t,
-            )
-        };
+    /// Estimates the cost of several transfer requests
+    ///
+    /// All estimates are made under a single lock acquisition.
+    ///
+    /// # Returns
+    /// One (duration, error margin, cost method) tuple per request, in order
+    ///
+    /// # Errors
+    /// Returns the first error encountered
+    pub fn estimate_xfer_costs(
+        &self,
+        reqs: &[&XferRequest],
+        opt_args: Option<&OptArgs>,
+    ) -> Result<Vec<(i64, i64, CostMethod)>, NixlError> {
+        let inner = AgentInner::lock_xfer(&self.inner);
+        reqs.iter()
+            .map(|req| inner.estimate_xfer_cost(req, opt_args))
+            .collect()
+    }
 
-        match status {
-            NIXL_CAPI_SUCCESS => Ok((duration_us, err_margin_us, CostMethod::from(method))),
-            NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
-            _ => Err(NixlError::BackendError),
-        }
+    /// Returns the index of the request with the lowest estimated duration
+    ///
+    /// Returns `Ok(None)` if `reqs` is empty.
+    pub fn cheapest_xfer(&self, reqs: &[&XferRequest]) -> Result<Option<usize>, NixlError> {
+        let costs = self.estimate_xfer_costs(reqs, None)?;
+        Ok(costs
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, (duration_us, _, _))| *duration_us)
+            .map(|(index, _)| index))
     }
 
     /// Posts a transfer request to initiate a transfer
@@ -925,15 +1879,105 @@ impl Agent {
     /// * `Ok(false)` - If the transfer completed immediately
     /// * `Ok(true)` - If the transfer is in progress
     /// * `Err` - If there was an error posting the transfer request
+    ///
+    /// # Buffers
+    /// NIXL reads and writes the local and remote buffers until the transfer
+    /// is done, so while it is in progress they must stay allocated, the
+    /// source must not be modified and the destination must not be accessed.
+    /// The transfer is done once [`Agent::get_xfer_status`] reports success or
+    /// failure, or it was cancelled with [`Agent::cancel_xfer_req`], unless
+    /// cancelling it failed.
+    /// [`Agent::post_xfer_req_guarded`] enforces this for the local buffers
+    /// with an exclusive borrow.
+    ///
+    /// # Thread safety
+    /// For agents configured with [`ThreadSync::Strict`] or [`ThreadSync::Rw`],
+    /// the C++ agent guards `postXferReq` with its own lock and only a read
+    /// lock on the agent is taken, so multiple threads may post concurrently.
+    /// Otherwise, as for [`Agent::new`], posts are serialized with the write
+    /// lock.
+    ///
+    /// Prefer [`Agent::post_xfer_req2`], whose result cannot be mistaken for a
+    /// success flag.
     pub fn post_xfer_req(
         &self,
         req: &XferRequest,
         opt_args: Option<&OptArgs>,
     ) -> Result<bool, NixlError> {
-        tracing::trace!("Posting transfer request");
+        Ok(self.post_xfer_req2(req, opt_args)?.is_in_progress())
+    }
+
+    /// Posts a transfer request to initiate a transfer
+    ///
+    /// Same as [`Agent::post_xfer_req`], but reports whether the transfer
+    /// completed within the call as a [`PostState`] instead of a `bool`.
+    ///
+    /// # Arguments
+    /// * `req` - Transfer request handle obtained from `create_xfer_req`
+    /// * `opt_args` - Optional arguments for the transfer request
+    pub fn post_xfer_req2(
+        &self,
+        req: &XferRequest,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<PostState, NixlError> {
+        match self.try_post_xfer_req(req, opt_args)? {
+            PostOutcome::Completed => Ok(PostState::Completed),
+            PostOutcome::InProgress => Ok(PostState::InProgress),
+            PostOutcome::WouldBlock => {
+                tracing::error!(target: TRACING_TARGET, error = "backend_error", "Failed to post transfer request");
+                Err(NixlError::BackendError)
+            }
+        }
+    }
+
+    /// Posts a transfer request and borrows its local buffers until it completes
+    ///
+    /// The returned [`InFlight`] guard holds exclusive borrows of `_buffers`,
+    /// so the compiler rejects accessing, moving or freeing them while the
+    /// transfer may still use them. The borrows are exclusive whatever the
+    /// operation: a read writes into the buffers, and the source of a write
+    /// must not change until it is sent. Pass the storage backing every local
+    /// descriptor of the request; this is not checked.
+    ///
+    /// # Arguments
+    /// * `req` - Transfer request handle obtained from `create_xfer_req`
+    /// * `_buffers` - The local buffers the request reads or writes, borrowed by the guard
+    /// * `opt_args` - Optional arguments for the transfer request
+    pub fn post_xfer_req_guarded<'a>(
+        &'a self,
+        req: &'a XferRequest,
+        _buffers: &mut [&'a mut dyn MemoryRegion],
+        opt_args: Option<&OptArgs>,
+    ) -> Result<InFlight<'a>, NixlError> {
+        let state = self.post_xfer_req2(req, opt_args)?;
+        Ok(InFlight::new(self, req, state == PostState::Completed))
+    }
+
+    /// Posts a transfer request, reporting a full backend queue as `WouldBlock`
+    ///
+    /// Like [`Agent::post_xfer_req`], but a backend that is temporarily out of
+    /// submission slots is not an error: the request is left unposted and can
+    /// be posted again later, so senders can apply backpressure. Only the
+    /// GUSLI plugin signals a full queue, through NIXL's `NIXL_ERR_NOT_ALLOWED`
+    /// status. Other plugins use that status for requests that can never be
+    /// posted, such as HF3FS with too many descriptors, so it yields
+    /// `XferRejected` for them, and backends that report a full queue as a
+    /// generic failure still yield `BackendError`.
+    ///
+    /// # Arguments
+    /// * `req` - Transfer request handle obtained from `create_xfer_req`
+    /// * `opt_args` - Optional arguments for the transfer request
+    pub fn try_post_xfer_req(
+        &self,
+        req: &XferRequest,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<PostOutcome, NixlError> {
+        let _span = self.span().entered();
+        req.start_deadline(opt_args);
+        let inner_guard = AgentInner::lock_xfer(&self.inner);
         let status = unsafe {
             nixl_capi_post_xfer_req(
-                self.inner.write().unwrap().handle.as_ptr(),
+                inner_guard.handle.as_ptr(),
                 req.handle(),
                 opt_args.map_or(ptr::null_mut(), |args| args.inner.as_ptr()),
             )
@@ -942,77 +1986,490 @@ impl Agent {
         match status {
             NIXL_CAPI_SUCCESS => {
                 tracing::trace!(
+                    target: TRACING_TARGET,
                     status = "completed",
                     "Transfer request completed immediately"
                 );
-                Ok(false)
+                req.mark_posted();
+                inner_guard.record_xfer_outcome(req, true);
+                Ok(PostOutcome::Completed)
             }
             NIXL_CAPI_IN_PROG => {
-                tracing::trace!(status = "in_progress", "Transfer request in progress");
-                Ok(true)
+                tracing::trace!(target: TRACING_TARGET, status = "in_progress", "Transfer request in progress");
+                req.mark_posted();
+                inner_guard.track_in_flight(req);
+                Ok(PostOutcome::InProgress)
+            }
+            NIXL_CAPI_ERROR_WOULD_BLOCK => {
+                tracing::debug!(target: TRACING_TARGET, status = "would_block", "Backend queue is full");
+                Ok(PostOutcome::WouldBlock)
             }
             NIXL_CAPI_ERROR_INVALID_PARAM => {
-                tracing::error!(error = "invalid_param", "Failed to post transfer request");
+                tracing::error!(target: TRACING_TARGET, error = "invalid_param", "Failed to post transfer request");
                 Err(NixlError::InvalidParam)
             }
+            NIXL_CAPI_ERROR_INVALID_STATE => {
+                tracing::error!(target: TRACING_TARGET, error = "repost_active", "Failed to post transfer request");
+                Err(NixlError::XferInProgress)
+            }
+            NIXL_CAPI_ERROR_REMOTE_DISCONNECT => {
+                tracing::error!(target: TRACING_TARGET, error = "remote_disconnected", "Failed to post transfer request");
+                Err(NixlError::RemoteDisconnected)
+            }
+            NIXL_CAPI_ERROR_REJECTED => {
+                tracing::error!(target: TRACING_TARGET, error = "rejected", "Failed to post transfer request");
+                Err(NixlError::XferRejected)
+            }
             _ => {
-                tracing::error!(error = "backend_error", "Failed to post transfer request");
+                tracing::error!(target: TRACING_TARGET, error = "backend_error", "Failed to post transfer request");
                 Err(NixlError::BackendError)
             }
         }
     }
 
-    /// Checks the status of a transfer request
+    /// Posts a transfer request, retrying when the backend fails to accept it
     ///
-    /// Returns `Ok(true)` if the transfer is still in progress, `Ok(false)` if it completed successfully.
+    /// Only `BackendError`, NIXL's generic backend failure that covers
+    /// transient resource exhaustion, is retried. Requests the backend
+    /// refused for a reason a repost cannot fix, such as mismatched
+    /// descriptors or an unsupported operation (`XferRejected`), invalid
+    /// requests, requests already in flight (`XferInProgress`) and requests
+    /// whose remote went away (`RemoteDisconnected`) are never reposted.
     ///
-    /// # Arguments
-    /// * `req` - Transfer request handle after `post_xfer_req`
-    pub fn get_xfer_status(&self, req: &XferRequest) -> Result<XferStatus, NixlError> {
-        let status = unsafe {
-            nixl_capi_get_xfer_status(self.inner.write().unwrap().handle.as_ptr(), req.handle())
-        };
-
+    /// # Returns
+    /// The result of the first successful [`Agent::post_xfer_req`], or the
+    /// last error once `policy.max_attempts` is exhausted
+    pub fn post_xfer_req_retry(
+        &self,
+        req: &XferRequest,
+        opt_args: Option<&OptArgs>,
+        policy: RetryPolicy,
+    ) -> Result<bool, NixlError> {
+        let mut attempt = 1;
+        loop {
+            match self.post_xfer_req(req, opt_args) {
+                Err(NixlError::BackendError) if attempt < policy.max_attempts => {
+                    let delay = policy.delay_for(attempt);
+                    tracing::debug!(target: TRACING_TARGET, attempt, ?delay, "Retrying transfer request post");
+                    std::thread::sleep(delay);
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Checks the status of a transfer request
+    ///
+    /// A transfer that failed after posting is reported as `Ok(XferStatus::Failed { .. })`,
+    /// while errors are reserved for requests whose status could not be checked.
+    ///
+    /// # Arguments
+    /// * `req` - Transfer request handle after `post_xfer_req`
+    ///
+    /// # Errors
+    /// * `InvalidParam` - If the request handle is invalid or was never posted
+    ///
+    /// Like [`Agent::post_xfer_req`], this only takes a read lock on agents
+    /// configured with [`ThreadSync::Strict`] or [`ThreadSync::Rw`].
+    pub fn get_xfer_status(&self, req: &XferRequest) -> Result<XferStatus, NixlError> {
+        let _span = self.span().entered();
+        if req.is_cancelled() {
+            return Ok(XferStatus::Failed { code: NIXL_ERR_CANCELED });
+        }
+        let mut code = 0;
+        let inner_guard = AgentInner::lock_xfer(&self.inner);
+        let status = unsafe {
+            nixl_capi_get_xfer_status(inner_guard.handle.as_ptr(), req.handle(), &mut code)
+        };
+
         match status {
-            NIXL_CAPI_SUCCESS => Ok(XferStatus::Success), // Transfer completed
-            NIXL_CAPI_IN_PROG => Ok(XferStatus::InProgress),  // Transfer in progress
-            NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
+            NIXL_CAPI_SUCCESS => {
+                // Transfer completed
+                inner_guard.untrack_in_flight(req);
+                inner_guard.record_xfer_outcome(req, true);
+                Ok(XferStatus::Success)
+            }
+            NIXL_CAPI_IN_PROG => Ok(XferStatus::InProgress), // Transfer in progress
+            NIXL_CAPI_ERROR_XFER_FAILED => {
+                inner_guard.untrack_in_flight(req);
+                inner_guard.record_xfer_outcome(req, false);
+                Ok(XferStatus::Failed { code })
+            }
+            NIXL_CAPI_ERROR_INVALID_PARAM | NIXL_CAPI_ERROR_INVALID_STATE => {
+                Err(NixlError::InvalidParam)
+            }
             _ => Err(NixlError::BackendError),
         }
     }
 
+    /// Cancels a transfer request that is still in progress
+    ///
+    /// The backend is asked to abort the transfer and the request is
+    /// released, so [`Agent::get_xfer_status`] reports it as failed with
+    /// NIXL's cancelled status from then on, see [`XferStatus::is_cancelled`].
+    /// Requests that already finished, or were never posted, are left
+    /// untouched and keep reporting their own status.
+    ///
+    /// Once this returns `Ok`, the backend no longer touches the transfer's
+    /// buffers and they can be reused. NIXL offers no way to tell beforehand
+    /// whether a backend can abort a transfer, and a failed attempt ends the
+    /// request: from then on [`Agent::get_xfer_status`] reports it as failed
+    /// with the backend's error, even though the backend may still be moving
+    /// data. After an error, the buffers must therefore be treated as in use
+    /// until the agent is dropped.
+    ///
+    /// # Errors
+    /// * `CancelFailed` - If the backend could not abort the transfer; the
+    ///   request can no longer be polled for its real outcome
+    /// * `InvalidParam` - If the request was not created through this agent
+    /// * `BackendError` - If the request could not be released
+    pub fn cancel_xfer_req(&self, req: &XferRequest) -> Result<(), NixlError> {
+        let _span = self.span().entered();
+        if !req.belongs_to(&self.inner) {
+            tracing::error!(target: TRACING_TARGET, "Transfer request was created by another agent");
+            return Err(NixlError::InvalidParam);
+        }
+        // The write lock keeps concurrent status checks off the request
+        let inner_guard = self.inner.write();
+        if req.is_cancelled() {
+            return Ok(());
+        }
+        let status = unsafe {
+            nixl_capi_get_xfer_status(inner_guard.handle.as_ptr(), req.handle(), ptr::null_mut())
+        };
+        if status != NIXL_CAPI_IN_PROG {
+            return Ok(());
+        }
+
+        // The backend can no longer be resolved once the request is released,
+        // but the outcome is only counted if the release ended the transfer
+        let backend = inner_guard.stats_backend(req);
+        let status = unsafe { nixl_capi_release_xfer_req(inner_guard.handle.as_ptr(), req.handle()) };
+        match status {
+            NIXL_CAPI_SUCCESS => {
+                inner_guard.count_xfer_outcome(req, backend.as_deref(), false);
+                inner_guard.untrack_in_flight(req);
+                req.mark_cancelled();
+                Ok(())
+            }
+            NIXL_CAPI_ERROR_INVALID_STATE => {
+                // NIXL replaced the in-progress status with the backend's
+                // error, so the request never reports its real outcome
+                tracing::error!(target: TRACING_TARGET, "Backend could not abort the transfer, its buffers may still be in use");
+                inner_guard.count_xfer_outcome(req, backend.as_deref(), false);
+                inner_guard.untrack_in_flight(req);
+                req.mark_cancel_failed();
+                Err(NixlError::CancelFailed)
+            }
+            _ => {
+                tracing::error!(target: TRACING_TARGET, error = "backend_error", "Failed to cancel transfer request");
+                Err(NixlError::BackendError)
+            }
+        }
+    }
+
+    /// Waits until every transfer posted through this agent has finished
+    ///
+    /// Tracks requests that were still in progress when posted, across all
+    /// handles to the agent, until a status check sees them succeed or fail
+    /// or they are released. Call this before deregistering memory or
+    /// invalidating local metadata, so no backend operation is still touching
+    /// the buffers. Transfers posted while draining are waited for as well.
+    ///
+    /// # Errors
+    /// * `DrainTimeout` - If transfers are still in progress after `timeout`
+    pub fn drain(&self, timeout: Option<std::time::Duration>) -> Result<(), NixlError> {
+        let _span = self.span().entered();
+        let deadline = timeout.map(|timeout| std::time::Instant::now() + timeout);
+        loop {
+            let outstanding = AgentInner::lock_xfer(&self.inner).poll_in_flight();
+            if outstanding == 0 {
+                return Ok(());
+            }
+            if deadline.is_some_and(|deadline| std::time::Instant::now() >= deadline) {
+                tracing::warn!(target: TRACING_TARGET, outstanding, "Timed out draining transfers");
+                return Err(NixlError::DrainTimeout { outstanding });
+            }
+            std::thread::sleep(DRAIN_POLL_INTERVAL);
+        }
+    }
+
+    /// Waits until a transfer request has completed
+    ///
+    /// Sleeps `poll_interval` between status checks. This is [`Agent::wait_all`]
+    /// for a single request, so errors report the request as index 0.
+    ///
+    /// # Errors
+    /// * `Timeout` - If the request is still in progress after `timeout` or past its deadline
+    /// * `XferFailed` - If the transfer failed
+    pub fn wait_xfer(
+        &self,
+        req: &XferRequest,
+        poll_interval: std::time::Duration,
+        timeout: Option<std::time::Duration>,
+    ) -> Result<(), NixlError> {
+        self.wait_all(&[req], poll_interval, timeout)
+    }
+
+    /// Waits until all the given transfer requests have completed
+    ///
+    /// Requests are polled with [`Agent::get_xfer_status`] every
+    /// `poll_interval`; completed requests are not polled again. Requests
+    /// still in progress past their deadline (see [`OptArgs::set_deadline`])
+    /// are cancelled with [`Agent::cancel_xfer_req`] and fail the wait with
+    /// `Timeout` listing them. Whether their buffers can be reused then
+    /// depends on the cancellation: a request that could not be cancelled
+    /// reports failure from then on, but its buffers may still be in use.
+    ///
+    /// # Arguments
+    /// * `reqs` - Posted transfer requests to wait for
+    /// * `poll_interval` - Delay between polling rounds
+    /// * `timeout` - Maximum time to wait, or `None` to wait indefinitely
+    ///
+    /// # Errors
+    /// * `Timeout` - Lists the indices of the requests still in progress, or
+    ///   of those past their deadline
+    /// * `XferFailed` - If a request failed, with its index and error code
+    pub fn wait_all(
+        &self,
+        reqs: &[&XferRequest],
+        poll_interval: std::time::Duration,
+        timeout: Option<std::time::Duration>,
+    ) -> Result<(), NixlError> {
+        let _span = self.span().entered();
+        let deadline = timeout.map(|timeout| std::time::Instant::now() + timeout);
+        let mut pending: Vec<usize> = (0..reqs.len()).collect();
+
+        loop {
+            let mut still_pending = Vec::with_capacity(pending.len());
+            let mut late = Vec::new();
+            let now = std::time::Instant::now();
+            for index in pending {
+                match self.get_xfer_status(reqs[index])? {
+                    XferStatus::Success => {}
+                    XferStatus::InProgress if reqs[index].deadline().is_some_and(|due| now >= due) => {
+                        late.push(index)
+                    }
+                    XferStatus::InProgress => still_pending.push(index),
+                    XferStatus::Failed { code } => return Err(NixlError::XferFailed { index, code }),
+                }
+            }
+            pending = still_pending;
+
+            if !late.is_empty() {
+                for &index in &late {
+                    match self.cancel_xfer_req(reqs[index]) {
+                        Ok(()) => tracing::warn!(target: TRACING_TARGET, index, "Cancelled transfer past its deadline"),
+                        Err(e) => tracing::warn!(target: TRACING_TARGET, index, error = %e, "Failed to cancel transfer past its deadline"),
+                    }
+                }
+                return Err(NixlError::Timeout { pending: late });
+            }
+
+            if pending.is_empty() {
+                return Ok(());
+            }
+            if deadline.is_some_and(|deadline| std::time::Instant::now() >= deadline) {
+                tracing::error!(target: TRACING_TARGET, pending = ?pending, "Timed out waiting for transfers");
+                return Err(NixlError::Timeout { pending });
+            }
+            std::thread::sleep(poll_interval);
+        }
+    }
+
+    /// Streams the completions of a batch of posted transfer requests
+    ///
+    /// Yields the index of each request in `reqs` with its outcome as soon as
+    /// it is seen to finish, so the first chunks of a transfer can be consumed
+    /// while later ones are still in flight. Failed transfers are reported as
+    /// `XferFailed` with their index. Pending requests are polled every
+    /// `poll_interval`. Only available with the `async` feature.
+    ///
+    /// # Errors
+    /// Each item carries its own result; the stream itself does not fail.
+    #[cfg(feature = "async")]
+    pub fn completion_stream(
+        &self,
+        reqs: Vec<XferRequest>,
+        poll_interval: std::time::Duration,
+    ) -> CompletionStream {
+        CompletionStream::new(self.clone(), reqs, poll_interval)
+    }
+
+    /// Copies one region to or from a remote agent, blocking until done
+    ///
+    /// A convenience for one-shot transfers: `local` is registered unless a
+    /// registration through this agent already covers it, single-descriptor
+    /// lists are built, and the transfer is created, posted and waited for.
+    /// Temporary registrations and requests are released before returning.
+    /// The remote agent's metadata must be loaded. Prefer the lower-level
+    /// calls where throughput matters.
+    ///
+    /// The wait is bounded only by the deadline in `opt_args` (see
+    /// [`OptArgs::set_deadline`]): a copy still in progress past it is
+    /// cancelled and fails with `Timeout`. Without a deadline, `copy` blocks
+    /// until the transfer completes. If the cancellation fails, a temporary
+    /// registration of `local` is kept rather than released under a backend
+    /// that may still be using it.
+    ///
+    /// # Arguments
+    /// * `operation` - Whether to read `remote` into `local` or write `local` to `remote`
+    /// * `local` - The local region
+    /// * `remote_agent` - Name of the agent owning `remote`
+    /// * `remote` - The remote region, as large as `local`
+    /// * `remote_mem_type` - Memory type of `remote`
+    /// * `opt_args` - Optional arguments for registration and the transfer
+    ///
+    /// # Errors
+    /// * `InvalidParam` - If the regions differ in size
+    /// * `Timeout` - If the copy was still in progress past its deadline
+    /// * `XferFailed` - If the transfer failed
+    pub fn copy(
+        &self,
+        operation: XferOp,
+        local: &impl NixlDescriptor,
+        remote_agent: &str,
+        remote: &XferDescriptor,
+        remote_mem_type: MemType,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<(), NixlError> {
+        let _span = self.span().entered();
+        if local.size() != remote.len {
+            tracing::error!(target: TRACING_TARGET, local.size = local.size(), remote.size = remote.len, "Copy regions differ in size");
+            return Err(NixlError::InvalidParam);
+        }
+
+        let local_desc = XferDescriptor {
+            addr: unsafe { local.as_ptr() } as usize,
+            len: local.size(),
+            dev_id: local.device_id(),
+        };
+        let covered = self
+            .inner
+            .read()
+            .is_registered(local.mem_type(), &local_desc);
+        let registration = if covered {
+            None
+        } else {
+            Some(self.register_memory(local, opt_args)?)
+        };
+
+        let mut local_descs = XferDescList::new(local.mem_type())?;
+        local_descs.add_desc(local_desc.addr, local_desc.len, local_desc.dev_id)?;
+        let mut remote_descs = XferDescList::new(remote_mem_type)?;
+        remote_descs.add_desc(remote.addr, remote.len, remote.dev_id)?;
+
+        let req = self.create_xfer_req(operation, &local_descs, &remote_descs, remote_agent, opt_args)?;
+        self.post_xfer_req(&req, opt_args)?;
+        // A deadline in opt_args bounds the wait and cancels a late copy
+        let result = self.wait_xfer(&req, COPY_POLL_INTERVAL, None);
+        if req.is_cancel_failed() {
+            tracing::warn!(target: TRACING_TARGET, "Keeping the copy's registration, its cancellation failed");
+            std::mem::forget(registration);
+        }
+        result
+    }
+
+    /// Gets the progress of a transfer request
+    ///
+    /// The C API only reports whether a transfer has completed, so progress
+    /// is known once the transfer is done.
+    ///
+    /// # Errors
+    /// * `Unsupported` - If the transfer is still in progress, or the request
+    ///   was created with `make_xfer_req` and its size is unknown
+    /// * `XferFailed` - If the transfer failed, with index 0 and its error code
+    pub fn get_xfer_progress(&self, req: &XferRequest) -> Result<XferProgress, NixlError> {
+        let bytes_total = req.total_bytes().ok_or(NixlError::Unsupported)?;
+        match self.get_xfer_status(req)? {
+            XferStatus::Success => Ok(XferProgress {
+                bytes_completed: bytes_total,
+                bytes_total,
+            }),
+            XferStatus::InProgress => Err(NixlError::Unsupported),
+            XferStatus::Failed { code } => Err(NixlError::XferFailed { index: 0, code }),
+        }
+    }
+
     /// Queries the backend for a transfer request
     ///
+    /// NIXL selects the backend when the request is created, so this can be
+    /// called before `post_xfer_req`, for example to attribute the transfer
+    /// to a backend or to apply backend-specific settings.
+    ///
     /// # Arguments
-    /// * `req` - Transfer request handle after `post_xfer_req`
+    /// * `req` - Transfer request handle obtained from `create_xfer_req`
     ///
     /// # Returns
     /// A handle to the backend used for the transfer
     ///
     /// # Errors
-    /// Returns a NixlError if the operation fails
+    /// * `InvalidParam` - If the request was created by another agent
+    /// * `BackendError` - If the backend could not be resolved
     pub fn query_xfer_backend(&self, req: &XferRequest) -> Result<Backend, NixlError> {
-        let mut backend = std::ptr::null_mut();
-        let inner_guard = self.inner.write().unwrap();
+        if !req.belongs_to(&self.inner) {
+            tracing::error!(target: TRACING_TARGET, "Transfer request was created by another agent");
+            return Err(NixlError::InvalidParam);
+        }
+        let inner_guard = self.inner.write();
+        let name = inner_guard.xfer_backend_name(req)?;
+        let handle = inner_guard.get_backend(&name).ok_or(NixlError::BackendError)?;
+        Ok(Backend::new(handle, name, &self.inner))
+    }
+
+    pub(crate) fn xfer_debug_info(&self, req: &XferRequest) -> Result<XferDebugInfo, NixlError> {
+        if !req.belongs_to(&self.inner) {
+            tracing::error!(target: TRACING_TARGET, "Transfer request was created by another agent");
+            return Err(NixlError::InvalidParam);
+        }
+        let inner_guard = AgentInner::lock_xfer(&self.inner);
+        let mut code = 0;
         let status = unsafe {
-            nixl_capi_query_xfer_backend(
-                inner_guard.handle.as_ptr(),
-                req.handle(),
-                &mut backend
-            )
+            nixl_capi_get_xfer_status(inner_guard.handle.as_ptr(), req.handle(), &mut code)
         };
-        match status {
-            NIXL_CAPI_SUCCESS => {
-                Ok(Backend{ inner: NonNull::new(backend).ok_or(NixlError::FailedToCreateBackend)? })
-            }
-            NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
-            _ => Err(NixlError::BackendError),
-        }
+        let backend_status = match status {
+            _ if req.is_cancelled() => "cancelled".to_string(),
+            NIXL_CAPI_SUCCESS => "complete".to_string(),
+            NIXL_CAPI_IN_PROG => "in progress".to_string(),
+            NIXL_CAPI_ERROR_INVALID_STATE => "not posted".to_string(),
+            NIXL_CAPI_ERROR_XFER_FAILED => format!("failed with status {code}"),
+            other => format!("status query failed with {other}"),
+        };
+
+        Ok(XferDebugInfo {
+            backend: inner_guard.xfer_backend_name(req).ok(),
+            op: req.op(),
+            desc_count: req.desc_count(),
+            remote_agent: req.remote_agent().map(str::to_string),
+            backend_status,
+        })
+    }
+
+    pub(crate) fn backend_stats(&self, name: &str) -> Result<BackendStats, NixlError> {
+        self.inner
+            .read()
+            .backend_stats
+            .get(name)
+            .map(BackendCounters::snapshot)
+            .ok_or(NixlError::InvalidParam)
     }
 
 
+    /// Starts pushing notifications from other agents into a channel
+    ///
+    /// A background thread calls [`Agent::get_notifications`] every
+    /// `poll_interval` and forwards each notification to the returned
+    /// receiver. The thread keeps this agent alive until the receiver is dropped.
+    pub fn notification_stream(&self, poll_interval: std::time::Duration) -> NotificationReceiver {
+        NotificationReceiver::spawn(self.clone(), poll_interval)
+    }
+
     /// Gets notifications from other agents
     ///
+    /// Notifications set aside by [`Agent::get_notifications_from`] are
+    /// delivered first, ahead of newly received ones.
+    ///
     /// # Arguments
     /// * `notifs` - Notification map to populate with notifications
     /// * `opt_args` - Optional arguments to filter notifications by backend
@@ -1021,28 +2478,142 @@ impl Agent {
         notifs: &mut NotificationMap,
         opt_args: Option<&OptArgs>,
     ) -> Result<(), NixlError> {
-        tracing::trace!("Getting notifications");
-        let status = unsafe {
-            nixl_capi_get_notifs(
-                self.inner.write().unwrap().handle.as_ptr(),
-                notifs.inner.as_ptr(),
-                opt_args.map_or(ptr::null_mut(), |args| args.inner.as_ptr()),
-            )
-        };
+        let mut inner = self.inner.write();
+        let agent_names: Vec<String> = inner.pending_notifs.keys().cloned().collect();
+        for agent_name in agent_names {
+            inner.take_pending_notifs(&agent_name, notifs, usize::MAX)?;
+        }
+        inner.fetch_notifs(notifs, opt_args)
+    }
 
-        match status {
-            NIXL_CAPI_SUCCESS => {
-                tracing::trace!("Successfully retrieved notifications");
-                Ok(())
+    /// Gets the notifications sent by one remote agent
+    ///
+    /// The C API can only drain the notifications of all agents at once, so
+    /// the others are set aside in the agent and returned by later calls to
+    /// this method or [`Agent::get_notifications`]. Consumers can therefore
+    /// partition notifications by sender without losing any, although every
+    /// call still takes the agent's write lock.
+    ///
+    /// # Arguments
+    /// * `remote_agent` - Name of the agent whose notifications to get
+    /// * `notifs` - Notification map to populate with notifications
+    /// * `opt_args` - Optional arguments to filter notifications by backend
+    pub fn get_notifications_from(
+        &self,
+        remote_agent: &str,
+        notifs: &mut NotificationMap,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<(), NixlError> {
+        tracing::trace!(target: TRACING_TARGET, remote.agent = %remote_agent, "Getting notifications from remote agent");
+        let mut inner = self.inner.write();
+        inner.stash_notifs(opt_args)?;
+        inner.take_pending_notifs(remote_agent, notifs, usize::MAX)?;
+        Ok(())
+    }
+
+    /// Gets at most `max` notifications, returning whether more remain
+    ///
+    /// Lets an event loop drain a burst of notifications in bounded batches.
+    /// The C API has no limit: every call, even with `max` of zero, takes
+    /// everything it has received, and what does not fit is set aside in the
+    /// agent and returned first by later calls to this method or
+    /// [`Agent::get_notifications`]. Notifications of one remote agent keep
+    /// their order; there is no order across remote agents.
+    ///
+    /// The backend filter of `opt_args` only applies to notifications taken
+    /// from the C API by this call. Notifications set aside earlier are
+    /// returned whichever backend delivered them.
+    ///
+    /// # Arguments
+    /// * `notifs` - Notification map to populate with notifications
+    /// * `max` - Maximum number of notifications to add to `notifs`
+    /// * `opt_args` - Optional arguments to filter notifications by backend
+    pub fn get_notifications_limited(
+        &self,
+        notifs: &mut NotificationMap,
+        max: usize,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<bool, NixlError> {
+        let mut inner = self.inner.write();
+        inner.stash_notifs(opt_args)?;
+        let mut remaining = max;
+        let agent_names: Vec<String> = inner.pending_notifs.keys().cloned().collect();
+        for agent_name in agent_names {
+            if remaining == 0 {
+                break;
             }
-            NIXL_CAPI_ERROR_INVALID_PARAM => {
-                tracing::error!(error = "invalid_param", "Failed to get notifications");
-                Err(NixlError::InvalidParam)
+            remaining -= inner.take_pending_notifs(&agent_name, notifs, remaining)?;
+        }
+        Ok(!inner.pending_notifs.is_empty())
+    }
+
+    /// Sends the checksum of the local side of a transfer to its initiator
+    ///
+    /// Call this once the transfer's notification `tag` has arrived, with the
+    /// regions the transfer wrote to or read from, in descriptor order. See
+    /// [`OptArgs::enable_checksum`].
+    ///
+    /// # Errors
+    /// * `Unsupported` - If a region is not in DRAM
+    pub fn send_checksum(
+        &self,
+        remote_agent: &str,
+        tag: &[u8],
+        regions: &[&dyn NixlDescriptor],
+    ) -> Result<(), NixlError> {
+        let crc = checksum::crc32c_regions(regions)?;
+        tracing::trace!(target: TRACING_TARGET, remote.agent = %remote_agent, crc, "Sending transfer checksum");
+        self.send_notification(remote_agent, &checksum::encode_notif(tag, crc), None)
+    }
+
+    /// Waits for the remote checksum of a completed transfer and compares it
+    ///
+    /// The transfer must have been created with [`OptArgs::enable_checksum`].
+    /// The local checksum is taken over `regions`, the local buffers the
+    /// transfer read from or wrote to, in descriptor order, as passed to
+    /// [`Agent::send_checksum`] on the remote side. Other notifications
+    /// arriving meanwhile are kept for [`Agent::get_notifications`].
+    ///
+    /// # Errors
+    /// * `InvalidParam` - If checksums were not enabled for the transfer
+    /// * `XferInProgress` - If the transfer has not completed yet
+    /// * `XferFailed` - If the transfer failed
+    /// * `Unsupported` - If a region is not in DRAM
+    /// * `ChecksumNotReceived` - If the remote checksum did not arrive within `timeout`
+    /// * `ChecksumMismatch` - If the data differs between both sides
+    pub fn verify_checksum(
+        &self,
+        req: &XferRequest,
+        regions: &[&dyn NixlDescriptor],
+        timeout: Option<std::time::Duration>,
+    ) -> Result<(), NixlError> {
+        let _span = self.span().entered();
+        let checksum = req.checksum().ok_or(NixlError::InvalidParam)?;
+        match self.get_xfer_status(req)? {
+            XferStatus::Success => {}
+            XferStatus::InProgress => return Err(NixlError::XferInProgress),
+            XferStatus::Failed { code } => return Err(NixlError::XferFailed { index: 0, code }),
+        }
+        let local = checksum::crc32c_regions(regions)?;
+
+        let deadline = timeout.map(|timeout| std::time::Instant::now() + timeout);
+        loop {
+            let remote = self
+                .inner
+                .write()
+                .take_checksum_notif(&checksum.remote_agent, &checksum.tag)?;
+            match remote {
+                Some(remote) if remote == local => return Ok(()),
+                Some(remote) => {
+                    tracing::error!(target: TRACING_TARGET, local, remote, "Transfer checksum mismatch");
+                    return Err(NixlError::ChecksumMismatch { local, remote });
+                }
+                None => {}
             }
-            _ => {
-                tracing::error!(error = "backend_error", "Failed to get notifications");
-                Err(NixlError::BackendError)
+            if deadline.is_some_and(|deadline| std::time::Instant::now() >= deadline) {
+                return Err(NixlError::ChecksumNotReceived);
             }
+            std::thread::sleep(CHECKSUM_POLL_INTERVAL);
         }
     }
 }
@@ -1050,13 +2621,33 @@ impl Agent {
 /// Inner state for an agent that manages the raw pointer
 #[derive(Debug)]
 pub(crate) struct AgentInner {
-    pub(crate) name: String,
+    pub(crate) name: Arc<str>,
     pub(crate) handle: NonNull<bindings::nixl_capi_agent_s>,
     pub(crate) backends: HashMap<String, NonNull<bindings::nixl_capi_backend_s>>,
     pub(crate) remotes: HashSet<String>,
+    pub(crate) backend_stats: HashMap<String, BackendCounters>,
+    prep_cache: descriptors::PrepDlistCache,
+    pub(crate) registrations: HashMap<u64, RegistrationRecord>,
+    next_registration_id: u64,
+    /// Handles of posted requests not yet seen to finish, used by [`Agent::drain`]
+    ///
+    /// Updated while only holding the read lock, hence the mutex. Handles are
+    /// removed before a request is released, so a drain never polls a freed one.
+    in_flight: Mutex<HashSet<usize>>,
+    /// Notifications drained from the C agent but not yet handed out, see
+    /// [`Agent::get_notifications_from`]
+    pending_notifs: HashMap<String, Vec<Vec<u8>>>,
+    /// Shared with the polling thread, which calls them without holding the
+    /// agent's lock
+    invalidation_callbacks: Arc<Mutex<Vec<InvalidationCallback>>>,
+    remote_watch_started: bool,
+    /// Locking mode of the C agent, see [`AgentInner::lock_xfer`]
+    thread_sync: ThreadSync,
+    /// Released after the C agent is destroyed, as fields drop after `drop`
+    _name_claim: AgentNameClaim,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ThreadSync {
     None,
     Strict,
@@ -1074,6 +2665,15 @@ pub struct AgentConfig {
     pub pthr_delay_us: u64,
     pub lthr_delay_us: u64,
     pub capture_telemetry: bool,
+    /// etcd endpoints for this agent; falls back to `NIXL_ETCD_ENDPOINTS` when empty
+    pub etcd_endpoints: Vec<String>,
+    /// etcd key namespace for this agent; falls back to `NIXL_ETCD_NAMESPACE` when `None`
+    pub etcd_namespace: Option<String>,
+    /// Rejects the agent if another live agent in this process has the same
+    /// name, which would clash when both publish metadata to the same place.
+    /// Off by default, since setups such as several runtimes with separate
+    /// etcd namespaces reuse names.
+    pub unique_name: bool,
 }
 
 impl Default for AgentConfig {
@@ -1087,6 +2687,9 @@ impl Default for AgentConfig {
             pthr_delay_us: 0,
             lthr_delay_us: 100_000,
             capture_telemetry: false,
+            etcd_endpoints: Vec::new(),
+            etcd_namespace: None,
+            unique_name: false,
         }
     }
 }
@@ -1094,59 +2697,686 @@ impl Default for AgentConfig {
 unsafe impl Send for AgentInner {}
 unsafe impl Sync for AgentInner {}
 
+/// Lock on an agent held across a call into the C agent, see [`AgentInner::lock_xfer`]
+pub(crate) enum XferGuard<'a> {
+    Shared(RwLockReadGuard<'a, AgentInner>),
+    Exclusive(RwLockWriteGuard<'a, AgentInner>),
+}
+
+impl std::ops::Deref for XferGuard<'_> {
+    type Target = AgentInner;
+
+    fn deref(&self) -> &AgentInner {
+        match self {
+            XferGuard::Shared(guard) => guard,
+            XferGuard::Exclusive(guard) => guard,
+        }
+    }
+}
+
 impl AgentInner {
-    fn new(handle: NonNull<bindings::nixl_capi_agent_s>, name: String) -> Self {
+    /// Locks `agent` for posting, polling or estimating transfers
+    ///
+    /// The C agent only guards these calls with its own lock when created
+    /// with `ThreadSync::Strict` or `ThreadSync::Rw`, so they can share the
+    /// read lock. In the default mode they are serialized with the write lock.
+    pub(crate) fn lock_xfer(agent: &RwLock<AgentInner>) -> XferGuard<'_> {
+        let guard = agent.read();
+        if matches!(guard.thread_sync, ThreadSync::Strict | ThreadSync::Rw) {
+            return XferGuard::Shared(guard);
+        }
+        drop(guard);
+        XferGuard::Exclusive(agent.write())
+    }
+
+    fn new(
+        handle: NonNull<bindings::nixl_capi_agent_s>,
+        name: Arc<str>,
+        thread_sync: ThreadSync,
+        name_claim: AgentNameClaim,
+    ) -> Self {
         Self {
             name,
             handle,
             backends: HashMap::new(),
             remotes: HashSet::new(),
+            backend_stats: HashMap::new(),
+            prep_cache: descriptors::PrepDlistCache::new(descriptors::PrepDlistCache::DEFAULT_CAPACITY),
+            registrations: HashMap::new(),
+            next_registration_id: 0,
+            in_flight: Mutex::default(),
+            pending_notifs: HashMap::new(),
+            invalidation_callbacks: Arc::default(),
+            remote_watch_started: false,
+            thread_sync,
+            _name_claim: name_claim,
+        }
+    }
+
+    /// Checks whether a tracked registration covers the whole of `desc`
+    fn is_registered(&self, mem_type: MemType, desc: &XferDescriptor) -> bool {
+        self.registrations.values().any(|record| {
+            record.mem_type == mem_type
+                && record.dev_id == desc.dev_id
+                && record.ptr <= desc.addr
+                && desc.addr + desc.len <= record.ptr + record.size
+        })
+    }
+
+    pub(crate) fn track_registration(&mut self, record: RegistrationRecord) -> u64 {
+        let id = self.next_registration_id;
+        self.next_registration_id += 1;
+        self.registrations.insert(id, record);
+        id
+    }
+
+    /// Deregisters a tracked region, doing nothing if it was already deregistered
+    pub(crate) fn deregister(&mut self, id: u64) -> Result<(), NixlError> {
+        match self.registrations.remove(&id) {
+            Some(record) => self.deregister_record(&record),
+            None => Ok(()),
+        }
+    }
+
+    fn deregister_record(&self, record: &RegistrationRecord) -> Result<(), NixlError> {
+        let mut reg_dlist = RegDescList::new(record.mem_type)?;
+        reg_dlist.add_desc(record.ptr, record.size, record.dev_id)?;
+        let opt_args = OptArgs::new()?;
+        for name in &record.backends {
+            let backend = self.get_backend(name).ok_or(NixlError::InvalidParam)?;
+            let status =
+                unsafe { nixl_capi_opt_args_add_backend(opt_args.inner.as_ptr(), backend.as_ptr()) };
+            if status != NIXL_CAPI_SUCCESS {
+                return Err(NixlError::BackendError);
+            }
+        }
+        let status = unsafe {
+            nixl_capi_deregister_mem(self.handle.as_ptr(), reg_dlist.handle(), opt_args.inner.as_ptr())
+        };
+        match status {
+            NIXL_CAPI_SUCCESS => {
+                tracing::trace!(target: TRACING_TARGET, "Memory deregistered successfully");
+                Ok(())
+            }
+            NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
+            _ => Err(NixlError::BackendError),
+        }
+    }
+
+    fn prepare_xfer_dlist(
+        handle: NonNull<bindings::nixl_capi_agent_s>,
+        c_agent_name: &CStr,
+        descs: &XferDescList,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<XferDlistHandle, NixlError> {
+        let mut dlist_hndl = std::ptr::null_mut();
+
+        let status = unsafe {
+            nixl_capi_prep_xfer_dlist(
+                handle.as_ptr(),
+                c_agent_name.as_ptr(),
+                descs.handle(),
+                &mut dlist_hndl,
+                opt_args.map_or(std::ptr::null_mut(), |args| args.inner.as_ptr()),
+            )
+        };
+
+        match status {
+            NIXL_CAPI_SUCCESS => Ok(XferDlistHandle::new(dlist_hndl, handle)),
+            _ => Err(NixlError::BackendError),
         }
     }
 
-    fn get_backend(&self, name: &str) -> Option<NonNull<bindings::nixl_capi_backend_s>> {
+    /// Resolves the name of the backend a transfer request was assigned to
+    fn xfer_backend_name(&self, req: &XferRequest) -> Result<String, NixlError> {
+        let mut backend = std::ptr::null_mut();
+        let status = unsafe { nixl_capi_query_xfer_backend(self.handle.as_ptr(), req.handle(), &mut backend) };
+        match status {
+            NIXL_CAPI_SUCCESS => {
+                let queried = NonNull::new(backend).ok_or(NixlError::FailedToCreateBackend)?;
+                // The C API returns a fresh handle, so resolve it to the one owned by the agent
+                let found = self.backends.iter().find(|(_, handle)| {
+                    let mut same = false;
+                    let status = unsafe {
+                        nixl_capi_backend_is_same(handle.as_ptr(), queried.as_ptr(), &mut same)
+                    };
+                    status == NIXL_CAPI_SUCCESS && same
+                });
+                let result = found.map(|(name, _)| name.clone()).ok_or(NixlError::BackendError);
+                unsafe { nixl_capi_destroy_backend(queried.as_ptr()) };
+                result
+            }
+            NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
+            _ => Err(NixlError::BackendError),
+        }
+    }
+
+    /// Appends the notifications received by the C agent to `notifs`
+    fn fetch_notifs(
+        &self,
+        notifs: &mut NotificationMap,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<(), NixlError> {
+        let status = unsafe {
+            nixl_capi_get_notifs(
+                self.handle.as_ptr(),
+                notifs.inner.as_ptr(),
+                opt_args.map_or(ptr::null_mut(), |args| args.inner.as_ptr()),
+            )
+        };
+
+        match status {
+            NIXL_CAPI_SUCCESS => Ok(()),
+            NIXL_CAPI_ERROR_INVALID_PARAM => {
+                tracing::error!(target: TRACING_TARGET, error = "invalid_param", "Failed to get notifications");
+                Err(NixlError::InvalidParam)
+            }
+            _ => {
+                tracing::error!(target: TRACING_TARGET, error = "backend_error", "Failed to get notifications");
+                Err(NixlError::BackendError)
+            }
+        }
+    }
+
+    /// Moves the notifications received by the C agent to `pending_notifs`
+    fn stash_notifs(&mut self, opt_args: Option<&OptArgs>) -> Result<(), NixlError> {
+        let mut fetched = NotificationMap::new()?;
+        self.fetch_notifs(&mut fetched, opt_args)?;
+        // The C API has already handed the notifications over, so everything
+        // readable is stashed before an error is reported
+        let mut result = Ok(());
+        for agent_name in fetched.agents() {
+            let notifications =
+                agent_name.and_then(|agent_name| Ok((agent_name, fetched.get_notifications(agent_name)?)));
+            let (agent_name, notifications) = match notifications {
+                Ok(found) => found,
+                Err(e) => {
+                    tracing::error!(target: TRACING_TARGET, error = ?e, "Failed to read received notifications");
+                    result = result.and(Err(e));
+                    continue;
+                }
+            };
+            for notification in notifications {
+                match notification {
+                    Ok(notification) => self.pending_notifs.entry(agent_name.to_string()).or_default().push(notification),
+                    Err(e) => {
+                        tracing::error!(target: TRACING_TARGET, error = ?e, "Failed to read received notification");
+                        result = result.and(Err(e));
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// Appends up to `max` pending notifications of `agent_name` to `notifs`,
+    /// returning how many were appended
+    ///
+    /// Notifications leave the agent only once appended, so none are lost if
+    /// appending fails.
+    fn take_pending_notifs(
+        &mut self,
+        agent_name: &str,
+        notifs: &mut NotificationMap,
+        max: usize,
+    ) -> Result<usize, NixlError> {
+        let Some(pending) = self.pending_notifs.get_mut(agent_name) else {
+            return Ok(0);
+        };
+        let mut appended = 0;
+        let result = pending.iter().take(max).try_for_each(|notification| {
+            notifs.append(agent_name, notification)?;
+            appended += 1;
+            Ok::<_, NixlError>(())
+        });
+        pending.drain(..appended);
+        if pending.is_empty() {
+            self.pending_notifs.remove(agent_name);
+        }
+        result.map(|()| appended)
+    }
+
+    /// Takes the checksum `remote_agent` sent for the transfer tagged `tag`,
+    /// leaving its other notifications pending
+    fn take_checksum_notif(&mut self, remote_agent: &str, tag: &[u8]) -> Result<Option<u32>, NixlError> {
+        self.stash_notifs(None)?;
+        let Some(pending) = self.pending_notifs.get_mut(remote_agent) else {
+            return Ok(None);
+        };
+        let found = pending.iter().enumerate().find_map(|(index, notification)| {
+            checksum::decode_notif(notification)
+                .filter(|(notif_tag, _)| *notif_tag == tag)
+                .map(|(_, crc)| (index, crc))
+        });
+        let Some((index, crc)) = found else {
+            return Ok(None);
+        };
+        pending.remove(index);
+        if pending.is_empty() {
+            self.pending_notifs.remove(remote_agent);
+        }
+        Ok(Some(crc))
+    }
+
+    pub(crate) fn track_in_flight(&self, req: &XferRequest) {
+        self.in_flight.lock().insert(req.handle() as usize);
+    }
+
+    pub(crate) fn untrack_in_flight(&self, req: &XferRequest) {
+        self.in_flight.lock().remove(&(req.handle() as usize));
+    }
+
+    /// Polls every in-flight request once, returning how many are still in progress
+    ///
+    /// Must be called under [`AgentInner::lock_xfer`], like any status check.
+    fn poll_in_flight(&self) -> usize {
+        let mut in_flight = self.in_flight.lock();
+        in_flight.retain(|&req| {
+            let status = unsafe {
+                nixl_capi_get_xfer_status(
+                    self.handle.as_ptr(),
+                    req as *mut bindings::nixl_capi_xfer_req_s,
+                    ptr::null_mut(),
+                )
+            };
+            status == NIXL_CAPI_IN_PROG
+        });
+        in_flight.len()
+    }
+
+    /// Counts the outcome of a posted transfer in its backend's statistics
+    fn record_xfer_outcome(&self, req: &XferRequest, succeeded: bool) {
+        let backend = self.stats_backend(req);
+        self.count_xfer_outcome(req, backend.as_deref(), succeeded);
+    }
+
+    /// Resolves the backend whose statistics a transfer counts toward, if
+    /// its outcome is yet to be counted
+    fn stats_backend(&self, req: &XferRequest) -> Option<String> {
+        if !req.is_stats_pending() {
+            return None;
+        }
+        match self.xfer_backend_name(req) {
+            Ok(name) => Some(name),
+            Err(e) => {
+                tracing::debug!(target: TRACING_TARGET, error = ?e, "Failed to resolve backend for transfer statistics");
+                None
+            }
+        }
+    }
+
+    /// Counts the outcome of a posted transfer toward `backend`, which
+    /// [`AgentInner::stats_backend`] resolved
+    fn count_xfer_outcome(&self, req: &XferRequest, backend: Option<&str>, succeeded: bool) {
+        if !req.take_stats_pending() {
+            return;
+        }
+        if let Some(counters) = backend.and_then(|name| self.backend_stats.get(name)) {
+            counters.record(req.op(), req.total_bytes(), succeeded);
+        }
+    }
+
+    pub(crate) fn get_backend(&self, name: &str) -> Option<NonNull<bindings::nixl_capi_backend_s>> {
         self.backends.get(name).cloned()
     }
 
-    fn invalidate_remote_md(&mut self, remote_agent: &str) -> Result<(), NixlError> {
-        unsafe {
-            if self.remotes.remove(remote_agent) {
-                nixl_capi_invalidate_remote_md(self.handle.as_ptr(), remote_agent.as_ptr().cast());
-            } else {
-                return Err(NixlError::InvalidParam);
+    fn estimate_xfer_cost(
+        &self,
+        req: &XferRequest,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<(i64, i64, CostMethod), NixlError> {
+        let mut duration_us: i64 = 0;
+        let mut err_margin_us: i64 = 0;
+        let mut method: u32 = 0;
+
+        let status = unsafe {
+            nixl_capi_estimate_xfer_cost(
+                self.handle.as_ptr(),
+                req.handle(),
+                opt_args.map_or(ptr::null_mut(), |args| args.inner.as_ptr()),
+                &mut duration_us,
+                &mut err_margin_us,
+                &mut method as *mut u32 as *mut bindings::nixl_capi_cost_t,
+            )
+        };
+
+        match status {
+            NIXL_CAPI_SUCCESS => Ok((duration_us, err_margin_us, CostMethod::from(method))),
+            NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
+            _ => Err(NixlError::BackendError),
+        }
+    }
+
+    fn load_remote_md(&mut self, metadata: &[u8]) -> Result<String, NixlError> {
+        tracing::trace!(target: TRACING_TARGET, metadata.size = metadata.len(), "Loading remote metadata");
+        let metadata = decompress_md(metadata)?;
+        let mut agent_name = std::ptr::null_mut();
+
+        let status = unsafe {
+            nixl_capi_load_remote_md(
+                self.handle.as_ptr(),
+                metadata.as_ptr() as *const std::ffi::c_void,
+                metadata.len(),
+                &mut agent_name,
+            )
+        };
+
+        match status {
+            NIXL_CAPI_SUCCESS => {
+                let name = unsafe {
+                    let name = CStr::from_ptr(agent_name).to_str().map(str::to_string);
+                    if name.is_err() {
+                        // The C agent has loaded it, but Rust could never name it again
+                        nixl_capi_invalidate_remote_md(self.handle.as_ptr(), agent_name);
+                    }
+                    libc::free(agent_name as *mut libc::c_void);
+                    name
+                };
+                let Ok(name) = name else {
+                    tracing::error!(target: TRACING_TARGET, error = "invalid_param", "Remote agent name is not UTF-8");
+                    return Err(NixlError::InvalidParam);
+                };
+                self.remotes.insert(name.clone());
+                tracing::trace!(target: TRACING_TARGET, remote.agent = %name, "Successfully loaded remote metadata");
+                Ok(name)
+            }
+            NIXL_CAPI_ERROR_INVALID_PARAM => {
+                tracing::error!(target: TRACING_TARGET, error = "invalid_param", "Failed to load remote metadata");
+                Err(NixlError::InvalidParam)
+            }
+            _ => {
+                tracing::error!(target: TRACING_TARGET, error = "backend_error", "Failed to load remote metadata");
+                Err(NixlError::BackendError)
+            }
+        }
+    }
+
+    fn fetch_remote_md(
+        &mut self,
+        remote_name: &str,
+        c_remote_name: &CStr,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<(), NixlError> {
+        tracing::trace!(target: TRACING_TARGET, remote_agent = %remote_name, "Fetching remote metadata from etcd");
+
+        let status = unsafe {
+            bindings::nixl_capi_fetch_remote_md(
+                self.handle.as_ptr(),
+                c_remote_name.as_ptr(),
+                opt_args.map_or(std::ptr::null_mut(), |args| args.inner.as_ptr()),
+            )
+        };
+
+        match status {
+            NIXL_CAPI_SUCCESS => {
+                self.remotes.insert(remote_name.to_string());
+                tracing::trace!(target: TRACING_TARGET, remote_agent = %remote_name, "Successfully fetched remote metadata from etcd");
+                Ok(())
+            }
+            NIXL_CAPI_ERROR_INVALID_PARAM => {
+                tracing::error!(target: TRACING_TARGET, error = "invalid_param", remote_agent = %remote_name, "Failed to fetch remote metadata from etcd");
+                Err(NixlError::InvalidParam)
+            }
+            _ => {
+                tracing::error!(target: TRACING_TARGET, error = "backend_error", remote_agent = %remote_name, "Failed to fetch remote metadata from etcd");
+                Err(NixlError::BackendError)
             }
         }
+    }
+
+    /// Returns true if the loaded metadata of `remote_agent` covers `descs`
+    fn covers_remote_descs(&self, remote_agent: &str, descs: &XferDescList) -> bool {
+        let Ok(c_remote_name) = CString::new(remote_agent) else {
+            return false;
+        };
+        let status = unsafe {
+            bindings::nixl_capi_check_remote_md(self.handle.as_ptr(), c_remote_name.as_ptr(), descs.as_ptr())
+        };
+        status == NIXL_CAPI_SUCCESS
+    }
+
+    fn invalidate_remote_md(&mut self, remote_agent: &str) -> Result<(), NixlError> {
+        // Membership is cleared before calling into C, so a remote is
+        // invalidated at most once, including by `Drop`
+        if !self.remotes.remove(remote_agent) {
+            return Err(NixlError::InvalidParam);
+        }
+        self.prep_cache.remove_agent(remote_agent);
+        self.invalidate_in_capi(remote_agent);
         Ok(())
     }
 
     fn invalidate_all_remotes(&mut self) -> Result<(), NixlError> {
+        for remote in std::mem::take(&mut self.remotes) {
+            self.prep_cache.remove_agent(&remote);
+            self.invalidate_in_capi(&remote);
+        }
+        Ok(())
+    }
+
+    fn backend_params(
+        &self,
+        backend: NonNull<bindings::nixl_capi_backend_s>,
+    ) -> Result<(MemList, utils::Params), NixlError> {
+        let mut mem_list = ptr::null_mut();
+        let mut params = ptr::null_mut();
+        let status = unsafe {
+            nixl_capi_get_backend_params(
+                self.handle.as_ptr(),
+                backend.as_ptr(),
+                &mut mem_list,
+                &mut params,
+            )
+        };
+
+        if status != NIXL_CAPI_SUCCESS {
+            return Err(NixlError::BackendError);
+        }
+
+        // SAFETY: If status is NIXL_CAPI_SUCCESS, both pointers are non-null
         unsafe {
-            for remote in self.remotes.drain() {
-                nixl_capi_invalidate_remote_md(self.handle.as_ptr(), remote.as_ptr().cast());
+            Ok((
+                MemList {
+                    inner: NonNull::new_unchecked(mem_list),
+                },
+                utils::Params::new(NonNull::new_unchecked(params)),
+            ))
+        }
+    }
+
+    /// Checks that a backend a transfer may use supports all of `mem_types`,
+    /// see [`OptArgs::set_strict_validation`]
+    fn check_mem_types(&self, mem_types: &[MemType], opt_args: &OptArgs) -> Result<(), NixlError> {
+        let mut candidates: Vec<&String> = opt_args.backend_names().iter().collect();
+        if candidates.is_empty() {
+            candidates = self.backends.keys().collect();
+        }
+        if candidates.is_empty() {
+            tracing::error!(target: TRACING_TARGET, "No backend to carry the transfer");
+            return Err(NixlError::InvalidParam);
+        }
+
+        for name in candidates {
+            let backend = *self.backends.get(name).ok_or(NixlError::InvalidParam)?;
+            let supported = self.backend_params(backend)?.0.to_vec()?;
+            match mem_types.iter().find(|mem_type| !supported.contains(mem_type)) {
+                None => return Ok(()),
+                Some(mem_type) => tracing::error!(
+                    target: TRACING_TARGET,
+                    backend = %name,
+                    mem_type = ?mem_type,
+                    supported = ?supported,
+                    "Backend does not support the memory type of the transfer"
+                ),
             }
         }
-        Ok(())
+        Err(NixlError::InvalidParam)
+    }
+
+    /// Checks whether the C agent still holds metadata for `remote_agent`
+    fn has_remote_md(&self, remote_agent: &str) -> bool {
+        let Ok(c_remote) = CString::new(remote_agent) else {
+            return false;
+        };
+        let status = unsafe {
+            bindings::nixl_capi_check_remote_md(self.handle.as_ptr(), c_remote.as_ptr(), ptr::null_mut())
+        };
+        status == NIXL_CAPI_SUCCESS
+    }
+
+    /// Forgets the remotes whose metadata was invalidated by their peers and
+    /// returns their names
+    ///
+    /// `seen` tracks the remotes whose metadata has been observed loaded
+    /// across calls, as remotes being fetched are tracked before it arrives.
+    fn take_departed_remotes(&mut self, seen: &mut HashSet<String>) -> Vec<String> {
+        seen.retain(|remote| self.remotes.contains(remote));
+        let mut departed = Vec::new();
+        for remote in &self.remotes {
+            if self.has_remote_md(remote) {
+                seen.insert(remote.clone());
+            } else if seen.remove(remote) {
+                departed.push(remote.clone());
+            }
+        }
+        for remote in &departed {
+            tracing::debug!(target: TRACING_TARGET, remote.agent = %remote, "Remote metadata was invalidated by its peer");
+            self.remotes.remove(remote);
+            self.prep_cache.remove_agent(remote);
+        }
+        departed
     }
+
+    /// Invalidates a remote on the C side, ignoring failures since the
+    /// remote may already have been invalidated there
+    fn invalidate_in_capi(&self, remote_agent: &str) {
+        let Ok(c_remote) = CString::new(remote_agent) else {
+            tracing::error!(target: TRACING_TARGET, remote.agent = %remote_agent, "Invalid remote agent name");
+            return;
+        };
+        let status =
+            unsafe { nixl_capi_invalidate_remote_md(self.handle.as_ptr(), c_remote.as_ptr()) };
+        if status != NIXL_CAPI_SUCCESS {
+            tracing::debug!(target: TRACING_TARGET, remote.agent = %remote_agent, "Remote agent was already invalid");
+        }
+    }
+}
+
+/// Splits paired descriptors into chunks of at most `chunk_bytes` bytes, see
+/// [`Agent::create_chunked_xfer`]
+fn chunk_descs(
+    local_descs: &[XferDescriptor],
+    remote_descs: &[XferDescriptor],
+    chunk_bytes: usize,
+) -> Result<Vec<Vec<(XferDescriptor, XferDescriptor)>>, NixlError> {
+    let mut chunks = Vec::new();
+    let mut chunk = Vec::new();
+    let mut chunk_len = 0;
+    for (local, remote) in local_descs.iter().zip(remote_descs) {
+        if local.len != remote.len {
+            return Err(NixlError::InvalidParam);
+        }
+        let mut offset = 0;
+        while offset < local.len {
+            let len = (local.len - offset).min(chunk_bytes - chunk_len);
+            chunk.push((
+                XferDescriptor {
+                    addr: local.addr + offset,
+                    len,
+                    dev_id: local.dev_id,
+                },
+                XferDescriptor {
+                    addr: remote.addr + offset,
+                    len,
+                    dev_id: remote.dev_id,
+                },
+            ));
+            offset += len;
+            chunk_len += len;
+            if chunk_len == chunk_bytes {
+                chunks.push(std::mem::take(&mut chunk));
+                chunk_len = 0;
+            }
+        }
+    }
+    if !chunk.is_empty() {
+        chunks.push(chunk);
+    }
+    Ok(chunks)
+}
+
+/// The NIXL library publishes metadata to etcd as it serializes it, so it
+/// cannot be compressed on the way
+fn check_md_uncompressed(opt_args: Option<&OptArgs>) -> Result<(), NixlError> {
+    if opt_args.is_some_and(|args| args.md_compression() != Compression::None) {
+        tracing::error!(target: TRACING_TARGET, "Metadata sent to etcd cannot be compressed");
+        return Err(NixlError::Unsupported);
+    }
+    Ok(())
+}
+
+/// Polls the remotes of an agent for invalidations until the agent is dropped,
+/// see [`Agent::on_remote_invalidated`]
+fn spawn_remote_watch(
+    agent: Weak<RwLock<AgentInner>>,
+    callbacks: Arc<Mutex<Vec<InvalidationCallback>>>,
+) {
+    std::thread::spawn(move || {
+        let mut seen = HashSet::new();
+        loop {
+            std::thread::sleep(REMOTE_WATCH_POLL_INTERVAL);
+            let Some(agent) = agent.upgrade() else {
+                return;
+            };
+            let departed = agent.write().take_departed_remotes(&mut seen);
+            drop(agent);
+
+            if departed.is_empty() {
+                continue;
+            }
+            // The callbacks run unlocked so that they can register more,
+            // which are kept after the running ones
+            let mut running = std::mem::take(&mut *callbacks.lock());
+            for remote in &departed {
+                for callback in &running {
+                    // A panicking callback must not end the thread and take
+                    // every callback with it
+                    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| callback(remote)));
+                    if result.is_err() {
+                        tracing::error!(target: TRACING_TARGET, remote.agent = %remote, "Remote invalidation callback panicked");
+                    }
+                }
+            }
+            let mut registered = callbacks.lock();
+            running.append(&mut registered);
+            *registered = running;
+        }
+    });
 }
 
+// Runs once the last `Agent` handle and the last object holding the agent,
+// such as an `XferRequest`, has been dropped, so no request outlives the
+// backends and C agent destroyed here
 impl Drop for AgentInner {
     fn drop(&mut self) {
-        tracing::trace!("Dropping NIXL agent");
+        tracing::trace!(target: TRACING_TARGET, "Dropping NIXL agent");
+        // Cached lists must be released while the agent still exists
+        self.prep_cache.clear();
         unsafe {
             // invalidate all remotes
-            for remote in self.remotes.iter() {
-                tracing::trace!(remote.agent = %remote, "Invalidating remote agent");
-                nixl_capi_invalidate_remote_md(self.handle.as_ptr(), remote.as_ptr().cast());
+            for remote in std::mem::take(&mut self.remotes) {
+                tracing::trace!(target: TRACING_TARGET, remote.agent = %remote, "Invalidating remote agent");
+                self.invalidate_in_capi(&remote);
             }
 
             // destroy all backends
             for backend in self.backends.values() {
-                tracing::trace!("Destroying backend");
+                tracing::trace!(target: TRACING_TARGET, "Destroying backend");
                 nixl_capi_destroy_backend(backend.as_ptr());
             }
 
             nixl_capi_destroy_agent(self.handle.as_ptr());
         }
-        tracing::trace!("NIXL agent dropped");
+        tracing::trace!(target: TRACING_TARGET, "NIXL agent dropped");
     }
 }
@@ -0,0 +1,154 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The operations an agent offers to its callers
+///
+/// [`Agent`] is the canonical implementation. Code written against
+/// `impl AgentApi` can also be driven by test doubles such as `MockAgent`
+/// (behind the `mock` feature) or by other transports. Handle types that wrap
+/// NIXL objects are associated types, so implementations are free to use
+/// their own representations.
+pub trait AgentApi {
+    /// Optional arguments accepted by registration and transfer operations
+    type OptArgs;
+    /// List of descriptors a transfer reads from or writes to
+    type XferDescList<'a>: ?Sized;
+    /// Handle to a created transfer request
+    type XferRequest;
+    /// Handle keeping a memory registration alive
+    type Registration;
+
+    /// Gets the name of the agent
+    fn name(&self) -> String;
+
+    /// Registers a memory region with the agent
+    fn register_memory(
+        &self,
+        descriptor: &impl NixlDescriptor,
+        opt_args: Option<&Self::OptArgs>,
+    ) -> Result<Self::Registration, NixlError>;
+
+    /// Gets the local metadata to send to remote agents
+    fn get_local_md(&self) -> Result<Vec<u8>, NixlError>;
+
+    /// Loads metadata of a remote agent, returning its name
+    fn load_remote_md(&self, metadata: &[u8]) -> Result<String, NixlError>;
+
+    /// Invalidates the loaded metadata of a remote agent
+    fn invalidate_remote_md(&self, remote_agent: &str) -> Result<(), NixlError>;
+
+    /// Checks if metadata for a remote agent is loaded
+    fn is_remote_loaded(&self, remote_agent: &str) -> bool;
+
+    /// Creates a transfer request between local and remote descriptors
+    fn create_xfer_req(
+        &self,
+        operation: XferOp,
+        local_descs: &Self::XferDescList<'_>,
+        remote_descs: &Self::XferDescList<'_>,
+        remote_agent: &str,
+        opt_args: Option<&Self::OptArgs>,
+    ) -> Result<Self::XferRequest, NixlError>;
+
+    /// Posts a transfer request, returning `true` while it is still in progress
+    fn post_xfer_req(
+        &self,
+        req: &Self::XferRequest,
+        opt_args: Option<&Self::OptArgs>,
+    ) -> Result<bool, NixlError>;
+
+    /// Checks the status of a posted transfer request
+    fn get_xfer_status(&self, req: &Self::XferRequest) -> Result<XferStatus, NixlError>;
+
+    /// Sends a notification to a remote agent
+    fn send_notification(&self, remote_agent: &str, message: &[u8]) -> Result<(), NixlError>;
+
+    /// Takes all pending notifications, grouped by sending agent
+    fn take_notifications(&self) -> Result<HashMap<String, Vec<Vec<u8>>>, NixlError>;
+}
+
+impl AgentApi for Agent {
+    type OptArgs = OptArgs;
+    type XferDescList<'a> = XferDescList<'a>;
+    type XferRequest = XferRequest;
+    type Registration = RegistrationHandle;
+
+    fn name(&self) -> String {
+        Agent::name(self)
+    }
+
+    fn register_memory(
+        &self,
+        descriptor: &impl NixlDescriptor,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<RegistrationHandle, NixlError> {
+        Agent::register_memory(self, descriptor, opt_args)
+    }
+
+    fn get_local_md(&self) -> Result<Vec<u8>, NixlError> {
+        Agent::get_local_md(self)
+    }
+
+    fn load_remote_md(&self, metadata: &[u8]) -> Result<String, NixlError> {
+        Agent::load_remote_md(self, metadata)
+    }
+
+    fn invalidate_remote_md(&self, remote_agent: &str) -> Result<(), NixlError> {
+        Agent::invalidate_remote_md(self, remote_agent)
+    }
+
+    fn is_remote_loaded(&self, remote_agent: &str) -> bool {
+        Agent::is_remote_loaded(self, remote_agent)
+    }
+
+    fn create_xfer_req(
+        &self,
+        operation: XferOp,
+        local_descs: &XferDescList<'_>,
+        remote_descs: &XferDescList<'_>,
+        remote_agent: &str,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<XferRequest, NixlError> {
+        Agent::create_xfer_req(self, operation, local_descs, remote_descs, remote_agent, opt_args)
+    }
+
+    fn post_xfer_req(
+        &self,
+        req: &XferRequest,
+        opt_args: Option<&OptArgs>,
+    ) -> Result<bool, NixlError> {
+        Agent::post_xfer_req(self, req, opt_args)
+    }
+
+    fn get_xfer_status(&self, req: &XferRequest) -> Result<XferStatus, NixlError> {
+        Agent::get_xfer_status(self, req)
+    }
+
+    fn send_notification(&self, remote_agent: &str, message: &[u8]) -> Result<(), NixlError> {
+        Agent::send_notification(self, remote_agent, message, None)
+    }
+
+    fn take_notifications(&self) -> Result<HashMap<String, Vec<Vec<u8>>>, NixlError> {
+        let mut notifs = NotificationMap::new()?;
+        Agent::get_notifications(self, &mut notifs, None)?;
+        let mut grouped: HashMap<String, Vec<Vec<u8>>> = HashMap::new();
+        for (remote_agent, message) in notifs.drain_bytes()? {
+            grouped.entry(remote_agent).or_default().push(message);
+        }
+        Ok(grouped)
+    }
+}
@@ -0,0 +1,122 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Software CRC32C verification of transfers
+//!
+//! No NIXL backend reports hardware checksums, so transfers created with
+//! [`OptArgs::enable_checksum`] are verified in software: the initiator
+//! computes a CRC32C over the local regions it passes to
+//! [`Agent::verify_checksum`] and the remote agent sends the CRC32C of its
+//! side back in a notification, see [`Agent::send_checksum`].
+
+use super::*;
+
+/// Prefix of notifications carrying a checksum
+const CHECKSUM_NOTIF_PREFIX: &[u8] = b"NIXL_CRC32C:";
+
+/// Lookup table for the reflected Castagnoli polynomial
+const CRC32C_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0x82f6_3b78 } else { crc >> 1 };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// Computes the CRC32C of the concatenation of `chunks`
+pub(crate) fn crc32c<'a>(chunks: impl IntoIterator<Item = &'a [u8]>) -> u32 {
+    let mut crc = !0u32;
+    for chunk in chunks {
+        for &byte in chunk {
+            crc = CRC32C_TABLE[((crc ^ byte as u32) & 0xff) as usize] ^ (crc >> 8);
+        }
+    }
+    !crc
+}
+
+/// Encodes a checksum notification for the transfer tagged `tag`
+pub(crate) fn encode_notif(tag: &[u8], crc: u32) -> Vec<u8> {
+    let mut message = Vec::with_capacity(CHECKSUM_NOTIF_PREFIX.len() + 4 + tag.len());
+    message.extend_from_slice(CHECKSUM_NOTIF_PREFIX);
+    message.extend_from_slice(&crc.to_le_bytes());
+    message.extend_from_slice(tag);
+    message
+}
+
+/// Decodes a checksum notification into its tag and checksum
+pub(crate) fn decode_notif(message: &[u8]) -> Option<(&[u8], u32)> {
+    let rest = message.strip_prefix(CHECKSUM_NOTIF_PREFIX)?;
+    let (crc, tag) = rest.split_first_chunk::<4>()?;
+    Some((tag, u32::from_le_bytes(*crc)))
+}
+
+/// Computes the CRC32C over host memory regions
+///
+/// # Errors
+/// * `Unsupported` - If a region is not in DRAM
+pub(crate) fn crc32c_regions(regions: &[&dyn NixlDescriptor]) -> Result<u32, NixlError> {
+    if regions.iter().any(|region| region.mem_type() != MemType::Dram) {
+        tracing::error!(target: TRACING_TARGET, "Checksums can only be computed over DRAM");
+        return Err(NixlError::Unsupported);
+    }
+    // SAFETY: Implementors of `MemoryRegion` guarantee the pointer covers
+    // `size()` bytes of live storage, which is host memory for DRAM
+    Ok(crc32c(regions.iter().map(|region| unsafe {
+        std::slice::from_raw_parts(region.as_ptr(), region.size())
+    })))
+}
+
+/// Checksum state of a transfer created with checksums enabled
+#[derive(Debug)]
+pub(crate) struct XferChecksum {
+    pub(crate) remote_agent: String,
+    /// The transfer's notification message, which identifies its checksum
+    pub(crate) tag: Vec<u8>,
+}
+
+impl XferChecksum {
+    /// Prepares checksum verification for a transfer about to be created
+    ///
+    /// # Errors
+    /// * `Unsupported` - If the local descriptors are not in DRAM
+    /// * `InvalidParam` - If `opt_args` has no notification message to tag the checksum with
+    pub(crate) fn for_xfer(
+        local_descs: &XferDescList,
+        remote_agent: &str,
+        opt_args: &OptArgs,
+    ) -> Result<Self, NixlError> {
+        if local_descs.get_type()? != MemType::Dram {
+            tracing::error!(target: TRACING_TARGET, "Checksums require local descriptors in DRAM");
+            return Err(NixlError::Unsupported);
+        }
+        let tag = opt_args.get_notification_message()?;
+        if tag.is_empty() || !opt_args.has_notification()? {
+            tracing::error!(target: TRACING_TARGET, "Checksums require a notification message to identify the transfer");
+            return Err(NixlError::InvalidParam);
+        }
+        Ok(Self {
+            remote_agent: remote_agent.to_string(),
+            tag,
+        })
+    }
+}
@@ -0,0 +1,137 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+//! Completion file descriptors for transfer requests
+//!
+//! No NIXL backend exposes a completion fd through the C API, so
+//! [`XferRequest::completion_fd`] is backed by a pipe: a background thread
+//! polls the request and writes a byte to the pipe once it has left the
+//! in-progress state. Only Linux is supported.
+
+use super::*;
+
+use std::os::fd::RawFd;
+use std::sync::atomic::AtomicBool;
+use std::thread::JoinHandle;
+
+/// How often the poller thread checks the status of its request
+const COMPLETION_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(1);
+
+/// Request handle moved to the poller thread
+struct PolledRequest(NonNull<bindings::nixl_capi_xfer_req_s>);
+
+// SAFETY: The handle is only used through the owning agent's lock, and the
+// `XferRequest` joins the poller before the handle is destroyed. A request
+// cancelled while polled keeps its C handle, which then reports an invalid
+// parameter and ends the polling.
+unsafe impl Send for PolledRequest {}
+
+/// A pipe that becomes readable once a transfer request completes
+#[derive(Debug)]
+pub(crate) struct CompletionFd {
+    read_fd: RawFd,
+    write_fd: RawFd,
+    stop: Arc<AtomicBool>,
+    poller: Option<JoinHandle<()>>,
+}
+
+impl CompletionFd {
+    /// Creates the pipe and starts polling `req` through `agent`
+    ///
+    /// # Errors
+    /// * `BackendError` - If the pipe or the poller thread could not be created
+    pub(crate) fn spawn(
+        agent: Arc<RwLock<AgentInner>>,
+        req: NonNull<bindings::nixl_capi_xfer_req_s>,
+    ) -> Result<Self, NixlError> {
+        let mut fds = [0 as RawFd; 2];
+        if unsafe { libc::pipe2(fds.as_mut_ptr(), libc::O_CLOEXEC | libc::O_NONBLOCK) } != 0 {
+            tracing::error!(target: TRACING_TARGET, error = %std::io::Error::last_os_error(), "Failed to create completion pipe");
+            return Err(NixlError::BackendError);
+        }
+        let [read_fd, write_fd] = fds;
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let req = PolledRequest(req);
+        let poller = std::thread::Builder::new()
+            .name("nixl-completion".to_string())
+            .spawn({
+                let stop = stop.clone();
+                move || poll_until_complete(&agent, req, write_fd, &stop)
+            });
+
+        match poller {
+            Ok(poller) => Ok(Self {
+                read_fd,
+                write_fd,
+                stop,
+                poller: Some(poller),
+            }),
+            Err(e) => {
+                tracing::error!(target: TRACING_TARGET, error = %e, "Failed to spawn completion poller");
+                unsafe {
+                    libc::close(read_fd);
+                    libc::close(write_fd);
+                }
+                Err(NixlError::BackendError)
+            }
+        }
+    }
+
+    pub(crate) fn read_fd(&self) -> RawFd {
+        self.read_fd
+    }
+}
+
+fn poll_until_complete(
+    agent: &RwLock<AgentInner>,
+    req: PolledRequest,
+    write_fd: RawFd,
+    stop: &AtomicBool,
+) {
+    while !stop.load(Ordering::Acquire) {
+        let status = {
+            let agent_guard = AgentInner::lock_xfer(agent);
+            unsafe {
+                nixl_capi_get_xfer_status(
+                    agent_guard.handle.as_ptr(),
+                    req.0.as_ptr(),
+                    ptr::null_mut(),
+                )
+            }
+        };
+        if status != NIXL_CAPI_IN_PROG {
+            let byte = 1u8;
+            // A full pipe is already readable, so a failed write is harmless
+            unsafe { libc::write(write_fd, &byte as *const u8 as *const libc::c_void, 1) };
+            return;
+        }
+        std::thread::sleep(COMPLETION_POLL_INTERVAL);
+    }
+}
+
+impl Drop for CompletionFd {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Release);
+        if let Some(poller) = self.poller.take() {
+            let _ = poller.join();
+        }
+        unsafe {
+            libc::close(self.read_fd);
+            libc::close(self.write_fd);
+        }
+    }
+}
@@ -15,20 +15,33 @@
 
 use super::*;
 
+mod memory;
+mod mixed;
 mod query;
 mod reg;
+mod storage;
 mod sync_manager;
 mod xfer;
 mod xfer_dlist_handle;
 
+pub use memory::{
+    cuda_device_count, DramDescriptor, RawDescriptor, RegisteredBuffer, StridedDescriptor,
+    VramDescriptor,
+};
+pub use mixed::MixedRegDescList;
 pub use query::{QueryResponse, QueryResponseIterator, QueryResponseList};
 pub use reg::{RegDescList, RegDescriptor};
+pub use storage::{FileDescriptor, ObjectDescriptor};
 pub use sync_manager::{BackendSyncable, SyncManager};
 pub use xfer::{XferDescList, XferDescriptor};
 pub use xfer_dlist_handle::XferDlistHandle;
+pub(crate) use xfer_dlist_handle::PrepDlistCache;
 
 /// Memory types supported by NIXL
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// Mirrors the C `nixl_mem_t` values and converts to and from the raw FFI
+/// memory type with `From`/`Into`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum MemType {
     Dram,
     Vram,
@@ -38,6 +51,41 @@ pub enum MemType {
     Unknown,
 }
 
+/// Identity of a memory region, for keying maps on descriptors
+///
+/// Two descriptors have the same key when they cover the same address range
+/// on the same device and memory type, so a region described twice can be
+/// registered once. The memory type keeps equal addresses in different
+/// address spaces, such as host and device memory, apart. Strided
+/// descriptors are keyed by their whole span, which is what registration
+/// covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DescKey {
+    pub addr: usize,
+    pub size: usize,
+    pub dev_id: u64,
+    pub mem_type: MemType,
+}
+
+impl DescKey {
+    /// Returns the key of `desc`
+    pub fn new(desc: &impl NixlDescriptor) -> Self {
+        Self {
+            // SAFETY: The address is only used as a value
+            addr: unsafe { desc.as_ptr() } as usize,
+            size: desc.size(),
+            dev_id: desc.device_id(),
+            mem_type: desc.mem_type(),
+        }
+    }
+}
+
+impl<T: NixlDescriptor> From<&T> for DescKey {
+    fn from(desc: &T) -> Self {
+        Self::new(desc)
+    }
+}
+
 impl From<nixl_capi_mem_type_t> for MemType {
     fn from(mem_type: nixl_capi_mem_type_t) -> Self {
         match mem_type {
@@ -51,20 +99,25 @@ impl From<nixl_capi_mem_type_t> for MemType {
     }
 }
 
+impl From<MemType> for nixl_capi_mem_type_t {
+    fn from(mem_type: MemType) -> Self {
+        match mem_type {
+            MemType::Dram => 0,
+            MemType::Vram => 1,
+            MemType::Block => 2,
+            MemType::Object => 3,
+            MemType::File => 4,
+            MemType::Unknown => 5,
+        }
+    }
+}
+
 impl fmt::Display for MemType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         // SAFETY: We know the memory type is valid and the string will be available
         let mut str_ptr = ptr::null();
         unsafe {
-            let mem_type = match self {
-                MemType::Dram => 0,
-                MemType::Vram => 1,
-                MemType::Block => 2,
-                MemType::Object => 3,
-                MemType::File => 4,
-                MemType::Unknown => 5,
-            };
-            nixl_capi_mem_type_to_string(mem_type, &mut str_ptr);
+            nixl_capi_mem_type_to_string((*self).into(), &mut str_ptr);
             let c_str = CStr::from_ptr(str_ptr);
             write!(f, "{}", c_str.to_str().unwrap())
         }
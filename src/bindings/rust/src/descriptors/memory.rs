@@ -0,0 +1,482 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use std::alloc::Layout;
+use std::ops::{Deref, DerefMut};
+use std::sync::OnceLock;
+
+const CUDA_SUCCESS: libc::c_int = 0;
+
+/// Returns the number of CUDA devices visible to this process
+///
+/// The CUDA driver is loaded at runtime, so this returns 0 when no driver is
+/// installed instead of failing. The result is computed once and cached.
+pub fn cuda_device_count() -> usize {
+    static COUNT: OnceLock<usize> = OnceLock::new();
+    *COUNT.get_or_init(|| unsafe { query_cuda_device_count() }.unwrap_or(0))
+}
+
+unsafe fn query_cuda_device_count() -> Option<usize> {
+    type CuInit = unsafe extern "C" fn(libc::c_uint) -> libc::c_int;
+    type CuDeviceGetCount = unsafe extern "C" fn(*mut libc::c_int) -> libc::c_int;
+
+    // The library handle is intentionally leaked, the driver stays loaded
+    // for the lifetime of the process anyway
+    let lib = libc::dlopen(c"libcuda.so.1".as_ptr(), libc::RTLD_NOW | libc::RTLD_LOCAL);
+    if lib.is_null() {
+        return None;
+    }
+
+    let init = libc::dlsym(lib, c"cuInit".as_ptr());
+    let get_count = libc::dlsym(lib, c"cuDeviceGetCount".as_ptr());
+    if init.is_null() || get_count.is_null() {
+        return None;
+    }
+
+    let init: CuInit = std::mem::transmute(init);
+    let get_count: CuDeviceGetCount = std::mem::transmute(get_count);
+
+    if init(0) != CUDA_SUCCESS {
+        return None;
+    }
+    let mut count = 0;
+    if get_count(&mut count) != CUDA_SUCCESS {
+        return None;
+    }
+    usize::try_from(count).ok()
+}
+
+/// A descriptor for externally allocated GPU memory
+///
+/// The CUDA device ordinal is validated against the visible devices when the
+/// descriptor is created, so device memory cannot silently be described as
+/// living on the wrong GPU.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct VramDescriptor {
+    ptr: NonNull<u8>,
+    size: usize,
+    device_id: u64,
+}
+
+// SAFETY: The descriptor only carries the address of device memory and never
+// dereferences it on the host
+unsafe impl Send for VramDescriptor {}
+unsafe impl Sync for VramDescriptor {}
+
+impl VramDescriptor {
+    /// Creates a descriptor for `size` bytes of device memory on `cuda_device_id`
+    ///
+    /// # Safety
+    /// The caller must ensure `ptr` points to at least `size` bytes of memory
+    /// allocated on `cuda_device_id`, which outlives the descriptor and any
+    /// registration made from it.
+    ///
+    /// # Errors
+    /// Returns `InvalidParam` if `ptr` is null or `cuda_device_id` is not a
+    /// visible CUDA device
+    pub unsafe fn new(ptr: *const u8, size: usize, cuda_device_id: u64) -> Result<Self, NixlError> {
+        let ptr = NonNull::new(ptr as *mut u8).ok_or(NixlError::InvalidParam)?;
+        if cuda_device_id >= cuda_device_count() as u64 {
+            tracing::error!(
+                target: TRACING_TARGET,
+                device_id = cuda_device_id,
+                visible_devices = cuda_device_count(),
+                "Invalid CUDA device id for VRAM descriptor"
+            );
+            return Err(NixlError::InvalidParam);
+        }
+        Ok(Self {
+            ptr,
+            size,
+            device_id: cuda_device_id,
+        })
+    }
+}
+
+impl MemoryRegion for VramDescriptor {
+    unsafe fn as_ptr(&self) -> *const u8 {
+        self.ptr.as_ptr()
+    }
+
+    fn size(&self) -> usize {
+        self.size
+    }
+}
+
+impl NixlDescriptor for VramDescriptor {
+    fn mem_type(&self) -> MemType {
+        MemType::Vram
+    }
+
+    fn device_id(&self) -> u64 {
+        self.device_id
+    }
+}
+
+/// A descriptor for host memory
+///
+/// Descriptors built with [`DramDescriptor::from_slice`] borrow the buffer
+/// for their whole lifetime, so the buffer cannot be freed while they exist.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct DramDescriptor<'a> {
+    ptr: NonNull<u8>,
+    size: usize,
+    dev_id: u64,
+    _buffer: PhantomData<&'a mut [u8]>,
+}
+
+// SAFETY: The descriptor only carries the address of the memory; access to
+// the memory itself is governed by its owner
+unsafe impl Send for DramDescriptor<'_> {}
+unsafe impl Sync for DramDescriptor<'_> {}
+
+impl<'a> DramDescriptor<'a> {
+    /// Creates a descriptor for `size` bytes of host memory
+    ///
+    /// # Safety
+    /// The caller must ensure `ptr` points to at least `size` bytes of host
+    /// memory, which outlives the descriptor and any registration made from it.
+    ///
+    /// # Errors
+    /// Returns `InvalidParam` if `ptr` is null
+    pub unsafe fn new(ptr: *const u8, size: usize) -> Result<Self, NixlError> {
+        let ptr = NonNull::new(ptr as *mut u8).ok_or(NixlError::InvalidParam)?;
+        Ok(Self {
+            ptr,
+            size,
+            dev_id: 0,
+            _buffer: PhantomData,
+        })
+    }
+
+    /// Creates a descriptor covering `data`, borrowing it for the lifetime
+    /// of the descriptor
+    pub fn from_slice(data: &'a mut [u8], dev_id: u64) -> Self {
+        Self {
+            // A slice pointer is never null, even for empty slices
+            ptr: NonNull::from(&mut *data).cast(),
+            size: data.len(),
+            dev_id,
+            _buffer: PhantomData,
+        }
+    }
+}
+
+impl MemoryRegion for DramDescriptor<'_> {
+    unsafe fn as_ptr(&self) -> *const u8 {
+        self.ptr.as_ptr()
+    }
+
+    fn size(&self) -> usize {
+        self.size
+    }
+}
+
+impl NixlDescriptor for DramDescriptor<'_> {
+    fn mem_type(&self) -> MemType {
+        MemType::Dram
+    }
+
+    fn device_id(&self) -> u64 {
+        self.dev_id
+    }
+}
+
+/// A descriptor for memory obtained as a raw address from another library
+///
+/// Built with `RawDescriptor::try_from((addr, len, dev_id, mem_type))`, which
+/// only checks that the address is not null and the length is not zero.
+///
+/// **Nothing else is checked.** The caller must ensure the range is memory of
+/// `mem_type` on `dev_id` that stays allocated for as long as it is
+/// registered or described in a transfer, and that DRAM ranges are readable
+/// if transfers over them are checksummed. Violating this makes NIXL read or
+/// write arbitrary memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RawDescriptor {
+    addr: usize,
+    len: usize,
+    dev_id: u64,
+    mem_type: MemType,
+}
+
+impl TryFrom<(usize, usize, u64, MemType)> for RawDescriptor {
+    type Error = NixlError;
+
+    /// # Errors
+    /// Returns `InvalidParam` if the address is null or the length is zero
+    fn try_from((addr, len, dev_id, mem_type): (usize, usize, u64, MemType)) -> Result<Self, NixlError> {
+        if addr == 0 || len == 0 {
+            tracing::error!(target: TRACING_TARGET, addr, len, "Raw descriptor needs a non-null address and a non-zero length");
+            return Err(NixlError::InvalidParam);
+        }
+        Ok(Self {
+            addr,
+            len,
+            dev_id,
+            mem_type,
+        })
+    }
+}
+
+impl MemoryRegion for RawDescriptor {
+    unsafe fn as_ptr(&self) -> *const u8 {
+        self.addr as *const u8
+    }
+
+    fn size(&self) -> usize {
+        self.len
+    }
+}
+
+impl NixlDescriptor for RawDescriptor {
+    fn mem_type(&self) -> MemType {
+        self.mem_type
+    }
+
+    fn device_id(&self) -> u64 {
+        self.dev_id
+    }
+}
+
+/// Host memory allocated with a chosen alignment and registered with an agent
+///
+/// Created by [`Agent::alloc_registered`]. The memory is zero-initialized and
+/// can be read and written through `Deref<Target = [u8]>`. Dropping the
+/// buffer deregisters the memory before freeing it.
+#[derive(Debug)]
+pub struct RegisteredBuffer {
+    ptr: NonNull<u8>,
+    layout: Layout,
+    handle: Option<RegistrationHandle>,
+}
+
+// SAFETY: The buffer exclusively owns its allocation
+unsafe impl Send for RegisteredBuffer {}
+unsafe impl Sync for RegisteredBuffer {}
+
+impl RegisteredBuffer {
+    pub(crate) fn new(
+        agent: &Agent,
+        size: usize,
+        align: usize,
+        mem_type: MemType,
+    ) -> Result<Self, NixlError> {
+        if mem_type != MemType::Dram {
+            tracing::error!(target: TRACING_TARGET, mem_type = ?mem_type, "Only DRAM buffers can be allocated");
+            return Err(NixlError::Unsupported);
+        }
+        if size == 0 {
+            return Err(NixlError::InvalidParam);
+        }
+        let layout = Layout::from_size_align(size, align).map_err(|_| {
+            tracing::error!(target: TRACING_TARGET, size, align, "Invalid buffer alignment");
+            NixlError::InvalidParam
+        })?;
+
+        // SAFETY: The layout has a non-zero size
+        let ptr = NonNull::new(unsafe { std::alloc::alloc_zeroed(layout) })
+            .unwrap_or_else(|| std::alloc::handle_alloc_error(layout));
+        let mut buffer = Self {
+            ptr,
+            layout,
+            handle: None,
+        };
+        buffer.handle = Some(agent.register_memory(&buffer, None)?);
+        Ok(buffer)
+    }
+
+    /// Returns the alignment the buffer was allocated with
+    pub fn align(&self) -> usize {
+        self.layout.align()
+    }
+}
+
+impl Deref for RegisteredBuffer {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        // SAFETY: The allocation is initialized and owned by the buffer
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.layout.size()) }
+    }
+}
+
+impl DerefMut for RegisteredBuffer {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        // SAFETY: The allocation is initialized and owned by the buffer
+        unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.layout.size()) }
+    }
+}
+
+impl MemoryRegion for RegisteredBuffer {
+    unsafe fn as_ptr(&self) -> *const u8 {
+        self.ptr.as_ptr()
+    }
+
+    fn size(&self) -> usize {
+        self.layout.size()
+    }
+}
+
+impl NixlDescriptor for RegisteredBuffer {
+    fn mem_type(&self) -> MemType {
+        MemType::Dram
+    }
+
+    fn device_id(&self) -> u64 {
+        0
+    }
+}
+
+impl Drop for RegisteredBuffer {
+    fn drop(&mut self) {
+        // The memory must stay allocated until it is deregistered
+        drop(self.handle.take());
+        // SAFETY: The memory was allocated with this layout in `new`
+        unsafe { std::alloc::dealloc(self.ptr.as_ptr(), self.layout) };
+    }
+}
+
+/// A descriptor for `count` equally spaced elements, such as a column slice
+/// of a tensor
+///
+/// NIXL descriptors are contiguous and no backend accepts strided ones, so
+/// [`XferDescList::add_storage_desc`] expands the view into one descriptor per
+/// element, or a single descriptor when the elements are adjacent. Registering
+/// the descriptor registers its whole span, gaps included.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct StridedDescriptor {
+    base_ptr: NonNull<u8>,
+    elem_size: usize,
+    count: usize,
+    stride: usize,
+    dev_id: u64,
+    mem_type: MemType,
+}
+
+// SAFETY: The descriptor only carries the address of the memory; access to
+// the memory itself is governed by its owner
+unsafe impl Send for StridedDescriptor {}
+unsafe impl Sync for StridedDescriptor {}
+
+impl StridedDescriptor {
+    /// Creates a descriptor for `count` elements of `elem_size` bytes, the
+    /// first at `base_ptr` and each following `stride` bytes after the last
+    ///
+    /// # Safety
+    /// The caller must ensure the span from `base_ptr` to the end of the last
+    /// element is memory of `mem_type` on `dev_id`, which outlives the
+    /// descriptor and any registration made from it.
+    ///
+    /// # Errors
+    /// Returns `InvalidParam` if `base_ptr` is null, elements overlap
+    /// (`stride < elem_size`) or the span overflows the address space
+    pub unsafe fn new(
+        base_ptr: *const u8,
+        elem_size: usize,
+        count: usize,
+        stride: usize,
+        dev_id: u64,
+        mem_type: MemType,
+    ) -> Result<Self, NixlError> {
+        let base_ptr = NonNull::new(base_ptr as *mut u8).ok_or(NixlError::InvalidParam)?;
+        if count > 1 && stride < elem_size {
+            tracing::error!(target: TRACING_TARGET, elem_size, stride, "Strided descriptor elements overlap");
+            return Err(NixlError::InvalidParam);
+        }
+        let span = Self::span_of(elem_size, count, stride).ok_or(NixlError::InvalidParam)?;
+        (base_ptr.as_ptr() as usize)
+            .checked_add(span)
+            .ok_or(NixlError::InvalidParam)?;
+        Ok(Self {
+            base_ptr,
+            elem_size,
+            count,
+            stride,
+            dev_id,
+            mem_type,
+        })
+    }
+
+    /// Returns the size of each element in bytes
+    pub fn elem_size(&self) -> usize {
+        self.elem_size
+    }
+
+    /// Returns the number of elements
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the distance between the starts of consecutive elements in bytes
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Returns true if the elements are adjacent, so the view is one contiguous region
+    pub fn is_contiguous(&self) -> bool {
+        self.count <= 1 || self.stride == self.elem_size
+    }
+
+    fn span_of(elem_size: usize, count: usize, stride: usize) -> Option<usize> {
+        match count {
+            0 => Some(0),
+            _ => stride.checked_mul(count - 1)?.checked_add(elem_size),
+        }
+    }
+}
+
+impl MemoryRegion for StridedDescriptor {
+    unsafe fn as_ptr(&self) -> *const u8 {
+        self.base_ptr.as_ptr()
+    }
+
+    /// Returns the span from the first to the end of the last element
+    fn size(&self) -> usize {
+        // Checked when the descriptor was created
+        Self::span_of(self.elem_size, self.count, self.stride).unwrap()
+    }
+}
+
+impl NixlDescriptor for StridedDescriptor {
+    fn mem_type(&self) -> MemType {
+        self.mem_type
+    }
+
+    fn device_id(&self) -> u64 {
+        self.dev_id
+    }
+
+    fn xfer_segments(&self) -> Option<Vec<XferDescriptor>> {
+        let base = self.base_ptr.as_ptr() as usize;
+        if self.is_contiguous() && self.count > 0 {
+            return Some(vec![XferDescriptor {
+                addr: base,
+                len: self.size(),
+                dev_id: self.dev_id,
+            }]);
+        }
+        Some(
+            (0..self.count)
+                .map(|index| XferDescriptor {
+                    addr: base + index * self.stride,
+                    len: self.elem_size,
+                    dev_id: self.dev_id,
+                })
+                .collect(),
+        )
+    }
+}
@@ -0,0 +1,93 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A registration descriptor list that accepts descriptors of several memory types
+///
+/// Descriptors are grouped into one [`RegDescList`] per memory type. Each
+/// descriptor is checked against the memory types supported by the target
+/// backend when it is added, so unsupported memory is rejected up front
+/// instead of failing at transfer time.
+#[derive(Debug)]
+pub struct MixedRegDescList<'a> {
+    supported: Vec<MemType>,
+    lists: Vec<RegDescList<'a>>,
+    count: usize,
+}
+
+impl<'a> MixedRegDescList<'a> {
+    /// Creates an empty list accepting the memory types supported by `backend`
+    pub fn for_backend(agent: &Agent, backend: &Backend) -> Result<Self, NixlError> {
+        Ok(Self::with_mem_types(backend.supported_mem_types(agent)?))
+    }
+
+    /// Creates an empty list accepting the given memory types
+    pub fn with_mem_types(supported: Vec<MemType>) -> Self {
+        Self {
+            supported,
+            lists: Vec::new(),
+            count: 0,
+        }
+    }
+
+    /// Adds a descriptor from a type implementing NixlDescriptor
+    ///
+    /// # Errors
+    /// Returns `UnsupportedMemType`, with the index the descriptor would have
+    /// had, if its memory type is not supported
+    pub fn add_storage_desc(&mut self, desc: &'a dyn NixlDescriptor) -> Result<(), NixlError> {
+        let mem_type = desc.mem_type();
+        if !self.supported.contains(&mem_type) {
+            tracing::error!(
+                target: TRACING_TARGET,
+                index = self.count,
+                mem_type = ?mem_type,
+                supported = ?self.supported,
+                "Descriptor memory type is not supported by the backend"
+            );
+            return Err(NixlError::UnsupportedMemType {
+                index: self.count,
+                mem_type,
+            });
+        }
+
+        let list = match self.lists.iter().position(|list| list.get_type().ok() == Some(mem_type)) {
+            Some(position) => &mut self.lists[position],
+            None => {
+                self.lists.push(RegDescList::new(mem_type)?);
+                self.lists.last_mut().unwrap()
+            }
+        };
+        list.add_storage_desc(desc)?;
+        self.count += 1;
+        Ok(())
+    }
+
+    /// Returns the number of descriptors across all memory types
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns true if no descriptors have been added
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns the per memory type lists, in the order their types were first added
+    pub fn lists(&self) -> &[RegDescList<'a>] {
+        &self.lists
+    }
+}
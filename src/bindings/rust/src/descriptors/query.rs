@@ -104,6 +104,22 @@ impl<'a> QueryResponse<'a> {
         }
     }
 
+    /// Returns true if the queried descriptor exists
+    ///
+    /// Unlike [`QueryResponse::has_value`], a response that cannot be read
+    /// is reported as absent.
+    pub fn is_present(&self) -> bool {
+        self.has_value().unwrap_or(false)
+    }
+
+    /// Returns the parameters describing the queried descriptor, if it exists
+    ///
+    /// Unlike [`QueryResponse::get_params`], a response that cannot be read
+    /// yields `None`.
+    pub fn params(&self) -> Option<Params> {
+        self.get_params().ok().flatten()
+    }
+
     /// Gets the parameters if this response has a value
     pub fn get_params(&self) -> Result<Option<Params>, NixlError> {
         if !self.has_value()? {
@@ -162,6 +178,20 @@ impl<'a> ExactSizeIterator for QueryResponseIterator<'a> {
     }
 }
 
+impl<'a> IntoIterator for &'a QueryResponseList {
+    type Item = QueryResponse<'a>;
+    type IntoIter = QueryResponseIterator<'a>;
+
+    /// Iterates over the responses, yielding none if the list size cannot be read
+    fn into_iter(self) -> Self::IntoIter {
+        QueryResponseIterator {
+            list: self,
+            index: 0,
+            len: self.len().unwrap_or(0),
+        }
+    }
+}
+
 impl Drop for QueryResponseList {
     fn drop(&mut self) {
         // SAFETY: self.inner is guaranteed to be valid by NonNull
@@ -17,7 +17,7 @@ use super::*;
 use super::sync_manager::{BackendSyncable, SyncManager};
 
 /// Public registration descriptor used for indexing and comparisons
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct RegDescriptor {
     pub addr: usize,
     pub len: usize,
@@ -79,13 +79,13 @@ impl<'a> RegDescList<'a> {
     pub fn new(mem_type: MemType) -> Result<Self, NixlError> {
         let mut dlist = ptr::null_mut();
         let status = unsafe {
-            nixl_capi_create_reg_dlist(mem_type as nixl_capi_mem_type_t, &mut dlist)
+            nixl_capi_create_reg_dlist(mem_type.into(), &mut dlist)
         };
 
         match status {
             NIXL_CAPI_SUCCESS => {
                 if dlist.is_null() {
-                    tracing::error!("Failed to create registration descriptor list");
+                    tracing::error!(target: TRACING_TARGET, "Failed to create registration descriptor list");
                     return Err(NixlError::RegDescListCreationFailed);
                 }
                 let backend = NonNull::new(dlist).ok_or(NixlError::RegDescListCreationFailed)?;
@@ -220,7 +220,24 @@ impl<'a> RegDescList<'a> {
         let dev_id = desc.device_id();
 
         // Add to list
-        self.add_desc(addr, len, dev_id)
+        self.add_desc_with_meta(addr, len, dev_id, desc.meta_info())
+    }
+
+    /// Builds a transfer descriptor list covering the same regions
+    ///
+    /// Addresses, lengths, device IDs and the memory type are copied, so a
+    /// transfer can target exactly what was registered. Per-descriptor
+    /// metadata only applies to registration and is not carried over.
+    pub fn to_xfer_dlist(&self) -> Result<XferDescList<'a>, NixlError> {
+        let mut xfer_dlist = XferDescList::new(self.mem_type)?;
+        for desc in self.descriptors() {
+            xfer_dlist.add_desc(desc.addr, desc.len, desc.dev_id)?;
+        }
+        Ok(xfer_dlist)
+    }
+
+    pub(crate) fn descriptors(&self) -> &[RegDescriptor] {
+        &self.sync_mgr.data().descriptors
     }
 
     pub(crate) fn handle(&self) -> *mut bindings::nixl_capi_reg_dlist_s {
@@ -256,12 +273,12 @@ impl PartialEq for RegDescList<'_> {
 
 impl Drop for RegDescList<'_> {
     fn drop(&mut self) {
-        tracing::trace!("Dropping registration descriptor list");
+        tracing::trace!(target: TRACING_TARGET, "Dropping registration descriptor list");
         if let Ok(backend) = self.sync_mgr.backend() {
             unsafe {
                 nixl_capi_destroy_reg_dlist(backend.as_ptr());
             }
         }
-        tracing::trace!("Registration descriptor list dropped");
+        tracing::trace!(target: TRACING_TARGET, "Registration descriptor list dropped");
     }
 }
@@ -0,0 +1,231 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Descriptors for storage backends
+//!
+//! NIXL describes file and object segments with the same fields as memory:
+//! the address holds the offset into the file or object, the device ID
+//! identifies the file or object, and the registration metadata carries its
+//! path or key. The descriptors here fill those fields in, so
+//! [`MemoryRegion::as_ptr`] returns the offset and must never be dereferenced.
+
+use super::*;
+use std::fs::File;
+use std::os::fd::{AsRawFd, RawFd};
+use std::path::Path;
+
+/// Checks that a segment of `size` bytes at `offset` fits NIXL's address field
+fn segment_end(offset: u64, size: usize) -> Result<usize, NixlError> {
+    usize::try_from(offset)
+        .ok()
+        .and_then(|offset| offset.checked_add(size))
+        .ok_or_else(|| {
+            tracing::error!(target: TRACING_TARGET, offset, size, "Storage segment overflows the address space");
+            NixlError::InvalidParam
+        })
+}
+
+/// A descriptor for `size` bytes of a file starting at `offset`, for
+/// `MemType::File` backends such as POSIX and GDS
+#[derive(Debug)]
+pub struct FileDescriptor {
+    fd: RawFd,
+    /// Keeps a file opened by [`FileDescriptor::open`] open
+    _file: Option<File>,
+    path: String,
+    offset: u64,
+    size: usize,
+}
+
+impl FileDescriptor {
+    /// Opens the file at `path` for reading and writing and describes a segment of it
+    ///
+    /// The file stays open for the lifetime of the descriptor, and its path is
+    /// registered as the segment's metadata.
+    ///
+    /// # Errors
+    /// * `InvalidParam` - If `path` is empty or not UTF-8, or the segment overflows
+    /// * `BackendError` - If the file could not be opened
+    pub fn open(path: impl AsRef<Path>, offset: u64, size: usize) -> Result<Self, NixlError> {
+        let path = path.as_ref();
+        let name = path.to_str().filter(|name| !name.is_empty()).ok_or_else(|| {
+            tracing::error!(target: TRACING_TARGET, path = %path.display(), "File descriptor needs a non-empty UTF-8 path");
+            NixlError::InvalidParam
+        })?;
+        segment_end(offset, size)?;
+        let file = File::options().read(true).write(true).open(path).map_err(|e| {
+            tracing::error!(target: TRACING_TARGET, path = %path.display(), error = %e, "Failed to open file for descriptor");
+            NixlError::BackendError
+        })?;
+        Ok(Self {
+            fd: file.as_raw_fd(),
+            _file: Some(file),
+            path: name.to_string(),
+            offset,
+            size,
+        })
+    }
+
+    /// Describes a segment of a file the caller keeps open
+    ///
+    /// No path is registered with the segment. The caller must keep `fd` open
+    /// for the lifetime of the descriptor and any registration made from it.
+    ///
+    /// # Errors
+    /// * `InvalidParam` - If `fd` is negative or the segment overflows
+    pub fn from_fd(fd: RawFd, offset: u64, size: usize) -> Result<Self, NixlError> {
+        if fd < 0 {
+            tracing::error!(target: TRACING_TARGET, fd, "Invalid file descriptor");
+            return Err(NixlError::InvalidParam);
+        }
+        segment_end(offset, size)?;
+        Ok(Self {
+            fd,
+            _file: None,
+            path: String::new(),
+            offset,
+            size,
+        })
+    }
+
+    /// Returns the file descriptor the segment belongs to
+    pub fn fd(&self) -> RawFd {
+        self.fd
+    }
+
+    /// Returns the path of the file, if it was opened through the descriptor
+    pub fn path(&self) -> Option<&str> {
+        Some(self.path.as_str()).filter(|path| !path.is_empty())
+    }
+
+    /// Returns the offset of the segment in the file
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+}
+
+// The open file is only held to keep `fd` valid, so descriptors of the same
+// segment compare equal whether or not they own it
+impl PartialEq for FileDescriptor {
+    fn eq(&self, other: &Self) -> bool {
+        (self.fd, &self.path, self.offset, self.size) == (other.fd, &other.path, other.offset, other.size)
+    }
+}
+
+impl Eq for FileDescriptor {}
+
+impl std::hash::Hash for FileDescriptor {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        (self.fd, &self.path, self.offset, self.size).hash(state);
+    }
+}
+
+impl MemoryRegion for FileDescriptor {
+    /// Returns the file offset, which NIXL carries in the address field
+    unsafe fn as_ptr(&self) -> *const u8 {
+        self.offset as usize as *const u8
+    }
+
+    fn size(&self) -> usize {
+        self.size
+    }
+}
+
+impl NixlDescriptor for FileDescriptor {
+    fn mem_type(&self) -> MemType {
+        MemType::File
+    }
+
+    /// Returns the file descriptor, which NIXL carries in the device ID
+    fn device_id(&self) -> u64 {
+        self.fd as u64
+    }
+
+    fn meta_info(&self) -> &[u8] {
+        self.path.as_bytes()
+    }
+}
+
+/// A descriptor for `size` bytes of an object starting at `offset`, for
+/// `MemType::Object` backends such as OBJ
+///
+/// The bucket is not part of NIXL descriptors; the OBJ backend takes it from
+/// its `bucket` parameter or the `AWS_DEFAULT_BUCKET` environment variable.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct ObjectDescriptor {
+    key: String,
+    offset: u64,
+    size: usize,
+    dev_id: u64,
+}
+
+impl ObjectDescriptor {
+    /// Describes a segment of the object named `key`
+    ///
+    /// The backend maps `dev_id` to the key when the object is registered,
+    /// and remote agents refer to the object by it, so each object registered
+    /// with an agent needs its own ID.
+    ///
+    /// # Errors
+    /// * `InvalidParam` - If `key` is empty or the segment overflows
+    pub fn new(key: &str, offset: u64, size: usize, dev_id: u64) -> Result<Self, NixlError> {
+        if key.is_empty() {
+            tracing::error!(target: TRACING_TARGET, "Object descriptor needs a non-empty key");
+            return Err(NixlError::InvalidParam);
+        }
+        segment_end(offset, size)?;
+        Ok(Self {
+            key: key.to_string(),
+            offset,
+            size,
+            dev_id,
+        })
+    }
+
+    /// Returns the key of the object
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns the offset of the segment in the object
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+}
+
+impl MemoryRegion for ObjectDescriptor {
+    /// Returns the object offset, which NIXL carries in the address field
+    unsafe fn as_ptr(&self) -> *const u8 {
+        self.offset as usize as *const u8
+    }
+
+    fn size(&self) -> usize {
+        self.size
+    }
+}
+
+impl NixlDescriptor for ObjectDescriptor {
+    fn mem_type(&self) -> MemType {
+        MemType::Object
+    }
+
+    fn device_id(&self) -> u64 {
+        self.dev_id
+    }
+
+    fn meta_info(&self) -> &[u8] {
+        self.key.as_bytes()
+    }
+}
@@ -38,6 +38,16 @@ impl<T: BackendSyncable> SyncManager<T> {
         f(&mut self.data)
     }
 
+    /// Mutates the frontend data with access to the unsynchronized backend
+    /// (marks as dirty)
+    pub fn modify_with_backend<F, R>(&mut self, f: F) -> R
+    where
+        F: FnOnce(&mut T, &T::Backend) -> R,
+    {
+        self.dirty.set(true);
+        f(&mut self.data, &self.backend)
+    }
+
     /// Provides access to both data and backend after ensuring synchronization
     pub fn with_backend<F, R>(&self, f: F) -> Result<R, T::Error>
     where
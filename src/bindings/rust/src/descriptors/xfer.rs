@@ -17,7 +17,7 @@ use super::*;
 use super::sync_manager::{BackendSyncable, SyncManager};
 
 /// Public transfer descriptor used for indexing and comparisons
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct XferDescriptor {
     pub addr: usize,
     pub len: usize,
@@ -71,7 +71,7 @@ impl<'a> XferDescList<'a> {
     pub fn new(mem_type: MemType) -> Result<Self, NixlError> {
         let mut dlist = ptr::null_mut();
         let status = unsafe {
-            nixl_capi_create_xfer_dlist(mem_type as nixl_capi_mem_type_t, &mut dlist)
+            nixl_capi_create_xfer_dlist(mem_type.into(), &mut dlist)
         };
 
         match status {
@@ -94,6 +94,41 @@ impl<'a> XferDescList<'a> {
         }
     }
 
+    /// Creates a new transfer descriptor list with room for `capacity` descriptors
+    ///
+    /// Both the Rust-side list and the underlying NIXL list are preallocated,
+    /// so building a large list one descriptor at a time does not reallocate.
+    pub fn with_capacity(mem_type: MemType, capacity: usize) -> Result<Self, NixlError> {
+        let mut list = Self::new(mem_type)?;
+        list.reserve(capacity)?;
+        Ok(list)
+    }
+
+    /// Reserves room for at least `additional` more descriptors
+    pub fn reserve(&mut self, additional: usize) -> Result<(), NixlError> {
+        self.sync_mgr.modify_with_backend(|data, backend| {
+            data.descriptors.reserve(additional);
+
+            // NIXL has no reserve, but clearing a resized list keeps its
+            // capacity. The list is dirty, so it is refilled on the next sync.
+            let target = data.descriptors.len() + additional;
+            for status in [
+                unsafe { nixl_capi_xfer_dlist_resize(backend.as_ptr(), target) },
+                unsafe { nixl_capi_xfer_dlist_clear(backend.as_ptr()) },
+            ] {
+                match status {
+                    NIXL_CAPI_SUCCESS => {}
+                    NIXL_CAPI_ERROR_INVALID_PARAM => return Err(NixlError::InvalidParam),
+                    _ => return Err(NixlError::BackendError),
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// Returns the number of descriptors the list can hold without reallocating
+    pub fn capacity(&self) -> Result<usize, NixlError> { Ok(self.sync_mgr.data().descriptors.capacity()) }
+
     pub fn as_ptr(&self) -> *mut bindings::nixl_capi_xfer_dlist_s {
         self.sync_mgr.backend().map(|b| b.as_ptr()).unwrap_or(ptr::null_mut())
     }
@@ -190,6 +225,12 @@ impl<'a> XferDescList<'a> {
             return Err(NixlError::InvalidParam);
         }
 
+        // Strided regions are expanded, no backend takes them as a whole
+        if let Some(segments) = desc.xfer_segments() {
+            self.sync_mgr.modify(|data| data.descriptors.extend(segments));
+            return Ok(());
+        }
+
         // Get descriptor details
         let addr = unsafe { desc.as_ptr() } as usize;
         let len = desc.size();
@@ -199,6 +240,68 @@ impl<'a> XferDescList<'a> {
         self.add_desc(addr, len, dev_id)
     }
 
+    /// Adds a descriptor covering a registered memory region
+    ///
+    /// Borrowing the handle keeps the region registered for the lifetime of
+    /// the list. Returns `InvalidParam` if the region's memory type does not
+    /// match the list.
+    pub fn add_registration(&mut self, handle: &'a RegistrationHandle) -> Result<(), NixlError> {
+        if handle.mem_type() != self.mem_type {
+            return Err(NixlError::InvalidParam);
+        }
+
+        let desc = handle.as_xfer_desc();
+        self.add_desc(desc.addr, desc.len, desc.dev_id)
+    }
+
+    /// Checks that no two descriptors on the same device overlap
+    ///
+    /// Overlapping ranges make the outcome of a transfer undefined. Empty
+    /// descriptors never overlap, and a range running past the end of the
+    /// address space extends to its end.
+    ///
+    /// # Errors
+    /// * `OverlappingDescriptors` - With the indices of two overlapping
+    ///   descriptors, the lower one first
+    pub fn validate_no_overlap(&self) -> Result<(), NixlError> {
+        let descriptors = self.descriptors();
+        let mut order: Vec<usize> = (0..descriptors.len())
+            .filter(|&index| descriptors[index].len > 0)
+            .collect();
+        order.sort_by_key(|&index| (descriptors[index].dev_id, descriptors[index].addr));
+
+        // Sorted by start, any overlap shows up between neighbours
+        for pair in order.windows(2) {
+            let (first, second) = (&descriptors[pair[0]], &descriptors[pair[1]]);
+            let overlaps = first
+                .addr
+                .checked_add(first.len)
+                .is_none_or(|end| second.addr < end);
+            if first.dev_id == second.dev_id && overlaps {
+                let (first, second) = (pair[0].min(pair[1]), pair[0].max(pair[1]));
+                tracing::error!(target: TRACING_TARGET, first, second, "Overlapping descriptors in transfer list");
+                return Err(NixlError::OverlappingDescriptors { first, second });
+            }
+        }
+        Ok(())
+    }
+
+    /// Builds a registration descriptor list covering the same regions
+    ///
+    /// The inverse of [`RegDescList::to_xfer_dlist`]; the descriptors are
+    /// registered without metadata.
+    pub fn to_reg_dlist(&self) -> Result<RegDescList<'a>, NixlError> {
+        let mut reg_dlist = RegDescList::new(self.mem_type)?;
+        for desc in self.descriptors() {
+            reg_dlist.add_desc(desc.addr, desc.len, desc.dev_id)?;
+        }
+        Ok(reg_dlist)
+    }
+
+    pub(crate) fn descriptors(&self) -> &[XferDescriptor] {
+        &self.sync_mgr.data().descriptors
+    }
+
     pub(crate) fn handle(&self) -> *mut bindings::nixl_capi_xfer_dlist_s {
         self.sync_mgr.backend().map(|b| b.as_ptr()).unwrap_or(ptr::null_mut())
     }
@@ -38,4 +38,87 @@ impl Drop for XferDlistHandle {
                                                self.handle());
         }
     }
-}
\ No newline at end of file
+}
+/// Identifies a prepared descriptor list by everything that went into preparing it
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct PrepKey {
+    agent_name: String,
+    mem_type: MemType,
+    descriptors: Vec<XferDescriptor>,
+    backends: Vec<String>,
+}
+
+/// Bounded LRU cache of prepared descriptor list handles
+pub(crate) struct PrepDlistCache {
+    capacity: usize,
+    tick: u64,
+    entries: HashMap<PrepKey, (Arc<XferDlistHandle>, u64)>,
+}
+
+impl PrepDlistCache {
+    pub(crate) const DEFAULT_CAPACITY: usize = 64;
+
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            tick: 0,
+            entries: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn get_or_prepare(
+        &mut self,
+        agent_name: &str,
+        descs: &XferDescList,
+        opt_args: Option<&OptArgs>,
+        prepare: impl FnOnce() -> Result<XferDlistHandle, NixlError>,
+    ) -> Result<Arc<XferDlistHandle>, NixlError> {
+        let key = PrepKey {
+            agent_name: agent_name.to_string(),
+            mem_type: descs.get_type()?,
+            descriptors: descs.descriptors().to_vec(),
+            backends: opt_args.map_or_else(Vec::new, |args| args.backend_names().to_vec()),
+        };
+        self.tick += 1;
+
+        if let Some((handle, last_used)) = self.entries.get_mut(&key) {
+            *last_used = self.tick;
+            return Ok(handle.clone());
+        }
+
+        let handle = Arc::new(prepare()?);
+        if self.capacity == 0 {
+            return Ok(handle);
+        }
+        if self.entries.len() >= self.capacity {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                self.entries.remove(&oldest);
+            }
+        }
+        self.entries.insert(key, (handle.clone(), self.tick));
+        Ok(handle)
+    }
+
+    /// Drops the lists prepared against the given agent
+    pub(crate) fn remove_agent(&mut self, agent_name: &str) {
+        self.entries.retain(|key, _| key.agent_name != agent_name);
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+impl std::fmt::Debug for PrepDlistCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PrepDlistCache")
+            .field("capacity", &self.capacity)
+            .field("len", &self.entries.len())
+            .finish()
+    }
+}
@@ -27,7 +27,9 @@ use std::fmt;
 use std::marker::PhantomData;
 use std::ptr;
 use std::ptr::NonNull;
-use std::sync::{Arc, RwLock};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Weak};
+use sync::{Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard};
 use thiserror::Error;
 
 // Include the generated bindings
@@ -43,23 +45,29 @@ mod bindings {
 use bindings::{
     nixl_capi_create_agent, nixl_capi_create_backend, nixl_capi_create_notif_map,
     nixl_capi_create_opt_args, nixl_capi_create_reg_dlist, nixl_capi_create_xfer_dlist,
-    nixl_capi_deregister_mem, nixl_capi_destroy_agent, nixl_capi_destroy_backend,
+    nixl_capi_backend_is_same, nixl_capi_deregister_mem, nixl_capi_destroy_agent,
+    nixl_capi_destroy_backend,
     nixl_capi_destroy_mem_list, nixl_capi_destroy_notif_map, nixl_capi_destroy_opt_args,
     nixl_capi_destroy_params, nixl_capi_destroy_reg_dlist, nixl_capi_destroy_string_list,
     nixl_capi_destroy_xfer_dlist, nixl_capi_get_available_plugins, nixl_capi_get_backend_params,
     nixl_capi_get_local_md, nixl_capi_get_notifs, nixl_capi_get_plugin_params,
-    nixl_capi_get_xfer_status, nixl_capi_invalidate_remote_md, nixl_capi_load_remote_md,
+    nixl_capi_get_xfer_status, nixl_capi_has_plugin, nixl_capi_invalidate_remote_md, nixl_capi_load_remote_md,
     nixl_capi_mem_list_get, nixl_capi_mem_list_is_empty, nixl_capi_mem_list_size,
     nixl_capi_mem_type_t, nixl_capi_mem_type_to_string, nixl_capi_notif_map_clear,
+    nixl_capi_notif_map_append,
+    nixl_capi_release_xfer_req,
     nixl_capi_notif_map_get_agent_at, nixl_capi_notif_map_get_notif,
     nixl_capi_notif_map_get_notifs_size, nixl_capi_notif_map_size, nixl_capi_opt_args_add_backend,
     nixl_capi_opt_args_get_has_notif, nixl_capi_opt_args_get_notif_msg,
     nixl_capi_opt_args_get_skip_desc_merge, nixl_capi_opt_args_set_has_notif,
     nixl_capi_opt_args_set_notif_msg, nixl_capi_opt_args_set_skip_desc_merge,
+    nixl_capi_opt_args_get_include_conn_info, nixl_capi_opt_args_set_include_conn_info,
     nixl_capi_params_create_iterator, nixl_capi_params_destroy_iterator, nixl_capi_params_is_empty,
+    nixl_capi_params_set,
     nixl_capi_params_iterator_next, nixl_capi_post_xfer_req, nixl_capi_reg_dlist_add_desc,
     nixl_capi_reg_dlist_clear, nixl_capi_register_mem, nixl_capi_string_list_get,
     nixl_capi_string_list_size, nixl_capi_xfer_dlist_add_desc, nixl_capi_xfer_dlist_clear,
+    nixl_capi_xfer_dlist_resize,
     nixl_capi_agent_make_connection,
     nixl_capi_reg_dlist_print, nixl_capi_xfer_dlist_print, nixl_capi_gen_notif, nixl_capi_estimate_xfer_cost,
     nixl_capi_query_mem, nixl_capi_create_query_resp_list, nixl_capi_destroy_query_resp_list,
@@ -74,20 +82,46 @@ use bindings::{
 pub use bindings::{
     nixl_capi_status_t_NIXL_CAPI_ERROR_BACKEND as NIXL_CAPI_ERROR_BACKEND,
     nixl_capi_status_t_NIXL_CAPI_ERROR_INVALID_PARAM as NIXL_CAPI_ERROR_INVALID_PARAM,
+    nixl_capi_status_t_NIXL_CAPI_ERROR_INVALID_STATE as NIXL_CAPI_ERROR_INVALID_STATE,
+    nixl_capi_status_t_NIXL_CAPI_ERROR_REMOTE_DISCONNECT as NIXL_CAPI_ERROR_REMOTE_DISCONNECT,
+    nixl_capi_status_t_NIXL_CAPI_ERROR_XFER_FAILED as NIXL_CAPI_ERROR_XFER_FAILED,
+    nixl_capi_status_t_NIXL_CAPI_ERROR_WOULD_BLOCK as NIXL_CAPI_ERROR_WOULD_BLOCK,
+    nixl_capi_status_t_NIXL_CAPI_ERROR_REJECTED as NIXL_CAPI_ERROR_REJECTED,
     nixl_capi_status_t_NIXL_CAPI_IN_PROG as NIXL_CAPI_IN_PROG,
     nixl_capi_status_t_NIXL_CAPI_SUCCESS as NIXL_CAPI_SUCCESS,
     nixl_capi_status_t_NIXL_CAPI_ERROR_NO_TELEMETRY as NIXL_CAPI_ERROR_NO_TELEMETRY
 };
 
+// Re-export opaque handle types returned by the `as_raw` escape hatches
+pub use bindings::{nixl_capi_agent_s, nixl_capi_backend_s, nixl_capi_xfer_req_s};
+
 mod agent;
+mod api;
+mod checksum;
+#[cfg(target_os = "linux")]
+mod completion;
 mod descriptors;
+mod logging;
+mod metadata;
+#[cfg(feature = "mock")]
+mod mock;
 mod notify;
+#[cfg(feature = "async")]
+mod stream;
+mod sync;
 mod utils;
 mod xfer;
 
 pub use agent::*;
+pub use api::*;
 pub use descriptors::*;
+pub use logging::*;
+pub use metadata::*;
+#[cfg(feature = "mock")]
+pub use mock::*;
 pub use notify::*;
+#[cfg(feature = "async")]
+pub use stream::*;
 pub use utils::*;
 pub use xfer::*;
 
@@ -116,6 +150,51 @@ pub enum NixlError {
     FailedToCreateBackend,
     #[error("Telemetry is not enabled or transfer is not complete")]
     NoTelemetry,
+    #[error("Transfer request is still in progress")]
+    XferInProgress,
+    #[error("Backend rejected the transfer request, retrying will not help")]
+    XferRejected,
+    #[error("Descriptors {first} and {second} overlap")]
+    OverlappingDescriptors { first: usize, second: usize },
+    #[error("Descriptor {index} has memory type {mem_type:?}, which the backend does not support")]
+    UnsupportedMemType { index: usize, mem_type: MemType },
+    #[error("Remote agent is disconnected or its metadata was invalidated")]
+    RemoteDisconnected,
+    #[error("Operation is not supported")]
+    Unsupported,
+    #[error("The backend could not abort the transfer, its buffers may still be in use")]
+    CancelFailed,
+    #[error("The agent owning this object has been dropped")]
+    AgentDropped,
+    #[error("Timed out waiting for transfers {pending:?} to complete")]
+    Timeout { pending: Vec<usize> },
+    #[error("Transfer {index} failed with code {code}")]
+    XferFailed { index: usize, code: i32 },
+    #[error("Timed out draining {outstanding} outstanding transfers")]
+    DrainTimeout { outstanding: usize },
+    #[error("Checksum mismatch: local {local:#010x}, remote {remote:#010x}")]
+    ChecksumMismatch { local: u32, remote: u32 },
+    #[error("The remote checksum of the transfer did not arrive in time")]
+    ChecksumNotReceived,
+    #[error("Metadata for remote agent {0} has not been loaded")]
+    RemoteNotLoaded(String),
+    #[error("An agent named {0} already exists in this process")]
+    DuplicateAgentName(String),
+    #[error("Metadata format version {got} is newer than supported version {supported}, upgrade this agent")]
+    IncompatibleMdVersion { got: u32, supported: u32 },
+    #[error("Failed to create backend {plugin} at index {index}: {source}")]
+    BackendAtIndex {
+        index: usize,
+        plugin: String,
+        #[source]
+        source: Box<NixlError>,
+    },
+    #[error("Failed to load metadata of remote agent {remote}: {source}")]
+    RemoteLoadFailed {
+        remote: String,
+        #[source]
+        source: Box<NixlError>,
+    },
 }
 
 /// A safe wrapper around NIXL memory list
@@ -132,42 +211,159 @@ impl Drop for MemList {
     }
 }
 
+/// A registered memory region, as tracked by its agent
+#[derive(Debug, Clone)]
+pub(crate) struct RegistrationRecord {
+    pub(crate) ptr: usize,
+    pub(crate) size: usize,
+    pub(crate) dev_id: u64,
+    pub(crate) mem_type: MemType,
+    /// Backends selected at registration time, used again for deregistration
+    pub(crate) backends: Vec<String>,
+    pub(crate) tag: Option<RegistrationTag>,
+}
+
+/// A memory region currently registered through an agent, see [`Agent::registrations`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegistrationInfo {
+    pub addr: usize,
+    pub size: usize,
+    pub dev_id: u64,
+    pub mem_type: MemType,
+    /// The tag given at registration, if any
+    pub tag: Option<RegistrationTag>,
+}
+
+/// Opaque user tag attached to a registration with [`Agent::register_memory_tagged`]
+///
+/// Tags stay local to the registering process; they are not part of the
+/// metadata exported to remote agents.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RegistrationTag {
+    Id(u64),
+    Name(String),
+}
+
+impl From<u64> for RegistrationTag {
+    fn from(id: u64) -> Self {
+        RegistrationTag::Id(id)
+    }
+}
+
+impl From<String> for RegistrationTag {
+    fn from(name: String) -> Self {
+        RegistrationTag::Name(name)
+    }
+}
+
+impl From<&str> for RegistrationTag {
+    fn from(name: &str) -> Self {
+        RegistrationTag::Name(name.to_string())
+    }
+}
+
+impl fmt::Display for RegistrationTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegistrationTag::Id(id) => write!(f, "{id}"),
+            RegistrationTag::Name(name) => write!(f, "{name}"),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct RegistrationHandle {
     agent: Option<Arc<RwLock<AgentInner>>>,
+    id: u64,
     ptr: usize,
     size: usize,
     dev_id: u64,
     mem_type: MemType,
+    tag: Option<RegistrationTag>,
 }
 
 impl RegistrationHandle {
+    /// Creates a handle for a region just registered with `agent`, tracking it in the agent
+    pub(crate) fn new(
+        agent: &Arc<RwLock<AgentInner>>,
+        descriptor: &impl NixlDescriptor,
+        backends: Vec<String>,
+    ) -> Self {
+        let record = RegistrationRecord {
+            ptr: unsafe { descriptor.as_ptr() } as usize,
+            size: descriptor.size(),
+            dev_id: descriptor.device_id(),
+            mem_type: descriptor.mem_type(),
+            backends,
+            tag: None,
+        };
+        let (ptr, size, dev_id, mem_type) = (record.ptr, record.size, record.dev_id, record.mem_type);
+        Self {
+            agent: Some(agent.clone()),
+            id: agent.write().track_registration(record),
+            ptr,
+            size,
+            dev_id,
+            mem_type,
+            tag: None,
+        }
+    }
+
+    pub(crate) fn with_tag(mut self, tag: RegistrationTag) -> Self {
+        if let Some(agent) = &self.agent {
+            if let Some(record) = agent.write().registrations.get_mut(&self.id) {
+                record.tag = Some(tag.clone());
+            }
+        }
+        self.tag = Some(tag);
+        self
+    }
+
+    /// Returns the tag given at registration, if any
+    pub fn tag(&self) -> Option<&RegistrationTag> {
+        self.tag.as_ref()
+    }
+
     pub fn agent_name(&self) -> Option<String> {
         self.agent
             .as_ref()
-            .map(|agent| agent.read().unwrap().name.clone())
+            .map(|agent| agent.read().name.to_string())
+    }
+
+    /// Returns the memory type of the registered region
+    pub fn mem_type(&self) -> MemType {
+        self.mem_type
+    }
+
+    /// Returns a transfer descriptor covering the whole registered region
+    pub fn as_xfer_desc(&self) -> XferDescriptor {
+        XferDescriptor {
+            addr: self.ptr,
+            len: self.size,
+            dev_id: self.dev_id,
+        }
     }
 
-    pub fn deregister(&mut self) -> Result<(), NixlError> {
+    /// Deregisters the memory region immediately, consuming the handle
+    ///
+    /// The handle is marked as released before the FFI call, so the `Drop`
+    /// implementation becomes a no-op and the region is never deregistered twice.
+    /// Does nothing if the region was already released by [`Agent::deregister_all`].
+    pub fn deregister(mut self) -> Result<(), NixlError> {
+        self.release()
+    }
+
+    fn release(&mut self) -> Result<(), NixlError> {
         if let Some(agent) = self.agent.take() {
             tracing::trace!(
+                target: TRACING_TARGET,
                 ptr = self.ptr,
                 size = self.size,
                 dev_id = self.dev_id,
                 mem_type = ?self.mem_type,
                 "Deregistering memory"
             );
-            let mut reg_dlist = RegDescList::new(self.mem_type)?;
-            unsafe {
-                reg_dlist.add_desc(self.ptr, self.size, self.dev_id)?;
-                let _opt_args = OptArgs::new().unwrap();
-                nixl_capi_deregister_mem(
-                    agent.write().unwrap().handle.as_ptr(),
-                    reg_dlist.handle(),
-                    _opt_args.inner.as_ptr(),
-                );
-            }
-            tracing::trace!("Memory deregistered successfully");
+            agent.write().deregister(self.id)?;
         }
         Ok(())
     }
@@ -176,27 +372,224 @@ impl RegistrationHandle {
 impl Drop for RegistrationHandle {
     fn drop(&mut self) {
         tracing::trace!(
+            target: TRACING_TARGET,
             ptr = self.ptr,
             size = self.size,
             dev_id = self.dev_id,
             mem_type = ?self.mem_type,
             "Dropping registration handle"
         );
-        if let Err(e) = self.deregister() {
-            tracing::debug!(error = ?e, "Failed to deregister memory");
+        if let Err(e) = self.release() {
+            tracing::debug!(target: TRACING_TARGET, error = ?e, "Failed to deregister memory");
         }
     }
 }
 
 /// A NIXL backend that can be used for data transfer
+///
+/// The backend is owned by the agent that created it. Once that agent is
+/// dropped, operations on the backend fail with `AgentDropped`.
 #[derive(Debug)]
 pub struct Backend {
     inner: NonNull<bindings::nixl_capi_backend_s>,
+    plugin: String,
+    agent: Weak<RwLock<AgentInner>>,
 }
 
 unsafe impl Send for Backend {}
 unsafe impl Sync for Backend {}
 
+impl Backend {
+    pub(crate) fn new(
+        inner: NonNull<bindings::nixl_capi_backend_s>,
+        plugin: String,
+        agent: &Arc<RwLock<AgentInner>>,
+    ) -> Self {
+        Self {
+            inner,
+            plugin,
+            agent: Arc::downgrade(agent),
+        }
+    }
+
+    /// Returns the backend handle together with its owning agent, which
+    /// keeps the handle alive for as long as it is held
+    pub(crate) fn handle(
+        &self,
+    ) -> Result<(Arc<RwLock<AgentInner>>, NonNull<bindings::nixl_capi_backend_s>), NixlError> {
+        let agent = self.agent.upgrade().ok_or(NixlError::AgentDropped)?;
+        Ok((agent, self.inner))
+    }
+
+    /// Returns the raw C handle of this backend
+    ///
+    /// # Safety
+    /// The handle is owned by the agent that created the backend: it must not
+    /// be destroyed, and it dangles once that agent is dropped, even while
+    /// this `Backend` is still alive. Calls through it bypass the agent's
+    /// lock, so concurrent use requires an agent created with
+    /// `ThreadSync::Strict` or `ThreadSync::Rw`.
+    pub unsafe fn as_raw(&self) -> *mut nixl_capi_backend_s {
+        self.inner.as_ptr()
+    }
+
+    /// Returns the name of the plugin this backend was created from
+    pub fn plugin_name(&self) -> String {
+        self.plugin.clone()
+    }
+
+    /// Returns the memory types supported by this backend
+    pub fn supported_mem_types(&self, agent: &Agent) -> Result<Vec<MemType>, NixlError> {
+        let (mems, _params) = agent.get_backend_params(self)?;
+        mems.to_vec()
+    }
+
+    /// Returns whether this backend can transfer to remote agents
+    ///
+    /// The C API does not expose the capabilities of a backend, so they are
+    /// looked up by plugin name for the plugins bundled with NIXL. Network
+    /// transports such as UCX are remote capable, while storage plugins such as
+    /// POSIX and GDS only transfer between local memory and storage.
+    ///
+    /// # Errors
+    /// * `AgentDropped` - If the agent that created this backend was dropped
+    /// * `Unsupported` - If the capabilities of the plugin are unknown
+    pub fn is_remote_capable(&self, agent: &Agent) -> Result<bool, NixlError> {
+        // Fails if the backend is no longer usable through `agent`
+        agent.get_backend_params(self)?;
+        match self.plugin.as_str() {
+            "UCX" | "UCX_MO" | "LIBFABRIC" | "MOONCAKE" | "GPUNETIO" => Ok(true),
+            "POSIX" | "GDS" | "GDS_MT" | "OBJ" | "HF3FS" | "GUSLI" => Ok(false),
+            plugin => {
+                tracing::error!(target: TRACING_TARGET, plugin, "Remote capability of plugin is unknown");
+                Err(NixlError::Unsupported)
+            }
+        }
+    }
+
+    /// Returns the transfer limits found in this backend's parameters
+    ///
+    /// See [`BackendLimits`] for the parameters read. Limits that are not
+    /// among the parameters are `None`.
+    ///
+    /// # Errors
+    /// * `AgentDropped` - If the agent that created this backend was dropped
+    /// * `InvalidParam` - If a limit parameter is not a number
+    pub fn limits(&self, agent: &Agent) -> Result<BackendLimits, NixlError> {
+        let (_mems, params) = agent.get_backend_params(self)?;
+        BackendLimits::from_params(&params)
+    }
+
+    /// Returns the cumulative transfer statistics of this backend on `agent`
+    ///
+    /// See [`BackendStats`] for how the counters are maintained.
+    pub fn stats(&self, agent: &Agent) -> Result<BackendStats, NixlError> {
+        agent.backend_stats(&self.plugin)
+    }
+}
+
+/// Transfer limits found in a backend's parameters, see [`Backend::limits`]
+///
+/// NIXL reports a backend's parameters as they were passed to
+/// [`Agent::create_backend`], and no bundled plugin defines the
+/// `max_descriptors`, `max_xfer_bytes` or `max_inflight_requests`
+/// parameters, so these only hold values the application chose to record
+/// there itself. They are not enforced or advertised by any backend. The one
+/// plugin parameter read is GUSLI's `max_num_simultaneous_requests`, which
+/// counts as the in-flight limit when it was set. GDS splits requests larger
+/// than its `max_request_size` itself, so it is not reported as a limit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BackendLimits {
+    /// Maximum number of descriptors in one transfer request
+    pub max_descriptors: Option<usize>,
+    /// Maximum number of bytes moved by one transfer request
+    pub max_xfer_bytes: Option<u64>,
+    /// Maximum number of transfer requests in flight at once
+    pub max_inflight_requests: Option<usize>,
+}
+
+impl BackendLimits {
+    pub(crate) fn from_params(params: &utils::Params) -> Result<Self, NixlError> {
+        fn parse<T: std::str::FromStr>(key: &str, value: &str) -> Result<Option<T>, NixlError> {
+            if value.is_empty() {
+                return Ok(None);
+            }
+            value.parse().map(Some).map_err(|_| {
+                tracing::error!(target: TRACING_TARGET, key, value, "Backend limit is not a number");
+                NixlError::InvalidParam
+            })
+        }
+
+        let mut limits = Self::default();
+        for pair in params.iter()? {
+            let ParamPair { key, value } = pair?;
+            match key {
+                "max_descriptors" => limits.max_descriptors = parse(key, value)?,
+                "max_xfer_bytes" => limits.max_xfer_bytes = parse(key, value)?,
+                "max_inflight_requests" | "max_num_simultaneous_requests" => {
+                    limits.max_inflight_requests = parse(key, value)?;
+                }
+                _ => {}
+            }
+        }
+        Ok(limits)
+    }
+}
+
+/// Cumulative transfer statistics of a backend
+///
+/// The C API does not track these, so they are counted by the agent when a
+/// transfer outcome is observed through [`Agent::post_xfer_req`] or
+/// [`Agent::get_xfer_status`]. Transfers that are dropped before their
+/// completion is observed are not counted, so all counters are lower bounds.
+/// Byte counters only include requests created with
+/// [`Agent::create_xfer_req`], as the size of requests made from prepared
+/// lists is unknown.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BackendStats {
+    /// Bytes written to remote agents by completed transfers
+    pub bytes_sent: u64,
+    /// Bytes read from remote agents by completed transfers
+    pub bytes_received: u64,
+    /// Number of transfers that completed successfully
+    pub completed_xfers: u64,
+    /// Number of transfers that failed after being posted
+    pub failed_xfers: u64,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct BackendCounters {
+    bytes_sent: AtomicU64,
+    bytes_received: AtomicU64,
+    completed_xfers: AtomicU64,
+    failed_xfers: AtomicU64,
+}
+
+impl BackendCounters {
+    pub(crate) fn record(&self, op: XferOp, bytes: Option<u64>, succeeded: bool) {
+        if !succeeded {
+            self.failed_xfers.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+        self.completed_xfers.fetch_add(1, Ordering::Relaxed);
+        let bytes_counter = if op.is_write() {
+            &self.bytes_sent
+        } else {
+            &self.bytes_received
+        };
+        bytes_counter.fetch_add(bytes.unwrap_or(0), Ordering::Relaxed);
+    }
+
+    pub(crate) fn snapshot(&self) -> BackendStats {
+        BackendStats {
+            bytes_sent: self.bytes_sent.load(Ordering::Relaxed),
+            bytes_received: self.bytes_received.load(Ordering::Relaxed),
+            completed_xfers: self.completed_xfers.load(Ordering::Relaxed),
+            failed_xfers: self.failed_xfers.load(Ordering::Relaxed),
+        }
+    }
+}
+
 /// Transfer telemetry data wrapper
 #[derive(Debug)]
 pub struct XferTelemetry {
@@ -243,12 +636,44 @@ impl XferTelemetry {
     }
 }
 
+/// Scheduling priority of a transfer, see [`OptArgs::set_priority`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Priority {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+/// Operands of a remote atomic operation, see [`XferOp::AtomicAdd`] and
+/// [`XferOp::CompareSwap`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AtomicOperands {
+    /// Value added to the remote word
+    Add(u64),
+    /// Value the remote word must hold, and the value stored if it does
+    CompareSwap { expected: u64, desired: u64 },
+}
+
 /// A safe wrapper around NIXL optional arguments
 pub struct OptArgs {
     inner: NonNull<bindings::nixl_capi_opt_args_s>,
+    backends: Vec<String>,
+    checksum: bool,
+    priority: Priority,
+    atomic_operands: Option<AtomicOperands>,
+    strict_validation: bool,
+    deadline: Option<std::time::Duration>,
+    md_compression: Compression,
+    xfer_tag: Option<u64>,
 }
 
 impl OptArgs {
+    /// Returns a builder for constructing optional arguments in one expression
+    pub fn builder<'a>() -> OptArgsBuilder<'a> {
+        OptArgsBuilder::default()
+    }
+
     /// Creates a new empty optional arguments struct
     pub fn new() -> Result<Self, NixlError> {
         let mut args = ptr::null_mut();
@@ -259,19 +684,38 @@ impl OptArgs {
             0 => {
                 // SAFETY: If status is 0, args was successfully created and is non-null
                 let inner = unsafe { NonNull::new_unchecked(args) };
-                Ok(Self { inner })
+                Ok(Self {
+                    inner,
+                    backends: Vec::new(),
+                    checksum: false,
+                    priority: Priority::Normal,
+                    atomic_operands: None,
+                    strict_validation: false,
+                    deadline: None,
+                    md_compression: Compression::None,
+                    xfer_tag: None,
+                })
             }
             -1 => Err(NixlError::InvalidParam),
             _ => Err(NixlError::BackendError),
         }
     }
 
+    /// Returns the plugin names of the backends added so far
+    pub(crate) fn backend_names(&self) -> &[String] {
+        &self.backends
+    }
+
     /// Add a backend to the optional arguments
     pub fn add_backend(&mut self, backend: &Backend) -> Result<(), NixlError> {
+        let (_backend_agent, backend_handle) = backend.handle()?;
         let status =
-            unsafe { nixl_capi_opt_args_add_backend(self.inner.as_ptr(), backend.inner.as_ptr()) };
+            unsafe { nixl_capi_opt_args_add_backend(self.inner.as_ptr(), backend_handle.as_ptr()) };
         match status {
-            NIXL_CAPI_SUCCESS => Ok(()),
+            NIXL_CAPI_SUCCESS => {
+                self.backends.push(backend.plugin.clone());
+                Ok(())
+            }
             NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
             _ => Err(NixlError::BackendError),
         }
@@ -279,6 +723,14 @@ impl OptArgs {
 
     /// Set the notification message
     pub fn set_notification_message(&mut self, message: &[u8]) -> Result<(), NixlError> {
+        let tagged;
+        let message = match self.xfer_tag {
+            Some(tag) => {
+                tagged = notify::encode_xfer_tag(tag, message);
+                tagged.as_slice()
+            }
+            None => message,
+        };
         let status = unsafe {
             nixl_capi_opt_args_set_notif_msg(
                 self.inner.as_ptr(),
@@ -293,6 +745,13 @@ impl OptArgs {
         }
     }
 
+    /// Attaches a notification message to be delivered to the remote agent
+    /// once the transfer completes, and enables notification
+    pub fn set_notification(&mut self, message: &[u8]) -> Result<(), NixlError> {
+        self.set_notification_message(message)?;
+        self.set_has_notification(true)
+    }
+
     /// Get the notification message
     pub fn get_notification_message(&self) -> Result<Vec<u8>, NixlError> {
         let mut data = ptr::null_mut();
@@ -366,10 +825,201 @@ impl OptArgs {
         }
     }
 
+    /// Set whether partial metadata includes the backends' connection info
+    ///
+    /// Used by [`Agent::get_local_partial_md`] and [`Agent::send_local_partial_md`]
+    /// when the descriptor list is non-empty; an empty list always yields
+    /// connection info only. Defaults to `false`, so descriptor metadata can be
+    /// sent over a connection established beforehand without resending the
+    /// connection info.
+    pub fn set_include_conn_info(&mut self, include_conn_info: bool) -> Result<(), NixlError> {
+        let status = unsafe {
+            nixl_capi_opt_args_set_include_conn_info(self.inner.as_ptr(), include_conn_info)
+        };
+        match status {
+            NIXL_CAPI_SUCCESS => Ok(()),
+            NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
+            _ => Err(NixlError::BackendError),
+        }
+    }
+
+    /// Get whether partial metadata includes the backends' connection info
+    pub fn include_conn_info(&self) -> Result<bool, NixlError> {
+        let mut include_conn_info = false;
+        let status = unsafe {
+            nixl_capi_opt_args_get_include_conn_info(self.inner.as_ptr(), &mut include_conn_info)
+        };
+        match status {
+            NIXL_CAPI_SUCCESS => Ok(include_conn_info),
+            NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
+            _ => Err(NixlError::BackendError),
+        }
+    }
+
+    /// Enables software checksum verification for transfers created with these arguments
+    ///
+    /// No backend computes checksums in hardware, so once the transfer
+    /// completed, [`Agent::verify_checksum`] takes a CRC32C of the local
+    /// buffers passed to it and compares it with the CRC32C the remote agent
+    /// returns through [`Agent::send_checksum`]. The transfer's notification
+    /// message identifies the checksum, so one must be set, and only DRAM
+    /// local descriptors are supported.
+    ///
+    /// # Performance
+    /// Every byte is read by the CPU on both sides, at a few GB/s per core,
+    /// which is well below RDMA bandwidth, and verification costs an extra
+    /// notification round trip. Reserve it for correctness-critical data.
+    pub fn enable_checksum(&mut self, enable: bool) {
+        self.checksum = enable;
+    }
+
+    /// Returns whether checksum verification is enabled
+    pub fn checksum_enabled(&self) -> bool {
+        self.checksum
+    }
+
+    /// Sets whether [`Agent::create_xfer_req`] checks memory types up front
+    ///
+    /// When enabled, the memory types of both descriptor lists are checked
+    /// against those supported by the selected backends, or by all of the
+    /// agent's backends if none is selected. A mismatch fails with
+    /// `InvalidParam` and an error log naming the unsupported type, instead
+    /// of a generic backend error. Querying the backends takes time, so
+    /// leave this disabled on hot paths.
+    pub fn set_strict_validation(&mut self, enable: bool) {
+        self.strict_validation = enable;
+    }
+
+    /// Returns whether memory types are checked when creating transfers
+    pub fn strict_validation(&self) -> bool {
+        self.strict_validation
+    }
+
+    /// Sets how long transfers may take from posting to completion
+    ///
+    /// The deadline applies to requests posted with these arguments, or
+    /// created with them and posted without a deadline of their own; the
+    /// clock starts on every post. NIXL has no deadlines, so nothing happens
+    /// when one passes until [`Agent::wait_xfer`] or [`Agent::wait_all`] sees
+    /// it: they cancel the late requests with [`Agent::cancel_xfer_req`] and
+    /// fail with `Timeout`.
+    ///
+    /// Buffers of a late request may only be reused if the cancellation went
+    /// through, i.e. [`Agent::get_xfer_status`] reports it as cancelled, or if
+    /// it finished before it could be cancelled and reports success. On
+    /// backends that cannot cancel, the failed attempt leaves the request
+    /// reporting failure while the transfer may still be running, so its
+    /// buffers stay in use until the agent is dropped, see
+    /// [`Agent::cancel_xfer_req`]. Avoid deadlines on such backends.
+    pub fn set_deadline(&mut self, deadline: std::time::Duration) {
+        self.deadline = Some(deadline);
+    }
+
+    /// Returns the deadline of transfers, see [`OptArgs::set_deadline`]
+    pub fn deadline(&self) -> Option<std::time::Duration> {
+        self.deadline
+    }
+
+    /// Tags the notification of transfers created or posted with these
+    /// arguments, and enables notification
+    ///
+    /// Backends deliver nothing but the notification message, so the tag is
+    /// carried in a text header in front of the message: `NIXL_TAG:`, the tag
+    /// as 16 hex digits, and `:`. The remote agent reads it with
+    /// [`NotificationMap::get_tagged_notification`] or
+    /// [`NotificationMap::iter_tagged`], which also return the message without
+    /// the header, and the initiator finds it on [`XferRequest::xfer_tag`].
+    /// [`OptArgs::get_notification_message`] returns the message with the
+    /// header, as it is sent.
+    ///
+    /// Receivers that do not strip the header see it as part of the message.
+    /// An untagged message that happens to start with a well-formed header is
+    /// read as tagged, so messages of other senders should not start with
+    /// `NIXL_TAG:`.
+    pub fn set_xfer_tag(&mut self, tag: u64) -> Result<(), NixlError> {
+        let message = self.get_notification_message()?;
+        let (_, message) = split_xfer_tag(&message);
+        let message = message.to_vec();
+        self.xfer_tag = Some(tag);
+        self.set_notification(&message)
+    }
+
+    /// Returns the transfer tag, see [`OptArgs::set_xfer_tag`]
+    pub fn xfer_tag(&self) -> Option<u64> {
+        self.xfer_tag
+    }
+
+    /// Sets how metadata returned by [`Agent::get_local_md_with`] and
+    /// [`Agent::get_local_partial_md`] is compressed
+    ///
+    /// Compressed blobs carry a header that [`Agent::load_remote_md`] detects,
+    /// so they can be exchanged with peers that also load uncompressed
+    /// metadata. The NIXL library serializes the metadata it publishes to
+    /// etcd itself, so [`Agent::send_local_md`] and
+    /// [`Agent::send_local_partial_md`] fail with `Unsupported` when
+    /// compression is set. Compressed blobs must be exchanged through the
+    /// application's own channel, as [`Agent::fetch_remote_md`] only reads
+    /// what the NIXL library published.
+    pub fn set_md_compression(&mut self, compression: Compression) {
+        self.md_compression = compression;
+    }
+
+    /// Returns how metadata is compressed, see [`OptArgs::set_md_compression`]
+    pub fn md_compression(&self) -> Compression {
+        self.md_compression
+    }
+
+    /// Sets the priority of transfers created with these arguments
+    ///
+    /// NIXL has no way to pass a priority or traffic class to backends, and
+    /// none of the bundled plugins schedules transfers by priority (GUSLI
+    /// submits every I/O with the same fixed priority). Transfers created
+    /// with a priority other than [`Priority::Normal`] therefore fail with
+    /// `Unsupported`, so callers needing QoS can separate traffic another
+    /// way, e.g. with a dedicated agent or backend for latency-sensitive data.
+    pub fn set_priority(&mut self, priority: Priority) {
+        self.priority = priority;
+    }
+
+    /// Returns the priority of transfers created with these arguments
+    pub fn priority(&self) -> Priority {
+        self.priority
+    }
+
+    /// Fails with `Unsupported` unless the requested priority can be honored
+    pub(crate) fn check_priority(&self) -> Result<(), NixlError> {
+        if self.priority == Priority::Normal {
+            return Ok(());
+        }
+        tracing::error!(target: TRACING_TARGET, priority = ?self.priority, "No backend supports transfer priorities");
+        Err(NixlError::Unsupported)
+    }
+
+    /// Sets the value added by [`XferOp::AtomicAdd`] transfers
+    ///
+    /// Remote atomics are not supported by any backend yet, see
+    /// [`XferOp::AtomicAdd`].
+    pub fn set_atomic_add(&mut self, value: u64) {
+        self.atomic_operands = Some(AtomicOperands::Add(value));
+    }
+
+    /// Sets the operands of [`XferOp::CompareSwap`] transfers
+    ///
+    /// Remote atomics are not supported by any backend yet, see
+    /// [`XferOp::CompareSwap`].
+    pub fn set_compare_swap(&mut self, expected: u64, desired: u64) {
+        self.atomic_operands = Some(AtomicOperands::CompareSwap { expected, desired });
+    }
+
+    /// Returns the operands of remote atomic transfers, if set
+    pub fn atomic_operands(&self) -> Option<AtomicOperands> {
+        self.atomic_operands
+    }
+
     /// Set the IP address
     /// used in sendLocalMD, fetchRemoteMD, invalidateLocalMD, sendLocalPartialMD.
     pub fn set_ip_addr(&mut self, ip_addr: &str) -> Result<(), NixlError> {
-        let c_str = CString::new(ip_addr).expect("Failed to convert string to CString");
+        let c_str = CString::new(ip_addr)?;
         let status = unsafe { nixl_capi_opt_args_set_ip_addr(self.inner.as_ptr(), c_str.as_ptr()) };
         match status {
             NIXL_CAPI_SUCCESS => Ok(()),
@@ -390,13 +1040,142 @@ impl OptArgs {
     }
 }
 
+/// A builder for [`OptArgs`]
+///
+/// Options are validated and applied when [`OptArgsBuilder::build`] is called.
+#[derive(Default)]
+pub struct OptArgsBuilder<'a> {
+    backends: Vec<&'a Backend>,
+    notification_msg: Option<Vec<u8>>,
+    has_notification: Option<bool>,
+    skip_descriptor_merge: Option<bool>,
+    include_conn_info: Option<bool>,
+    checksum: bool,
+    priority: Priority,
+    atomic_operands: Option<AtomicOperands>,
+    strict_validation: bool,
+    deadline: Option<std::time::Duration>,
+    md_compression: Compression,
+    xfer_tag: Option<u64>,
+}
+
+impl<'a> OptArgsBuilder<'a> {
+    /// Adds a backend to use for the operation
+    pub fn backend(mut self, backend: &'a Backend) -> Self {
+        self.backends.push(backend);
+        self
+    }
+
+    /// Sets the notification message, which also enables notification
+    pub fn notification_msg(mut self, message: &[u8]) -> Self {
+        self.notification_msg = Some(message.to_vec());
+        self
+    }
+
+    /// Sets whether notification is enabled
+    pub fn has_notification(mut self, has_notification: bool) -> Self {
+        self.has_notification = Some(has_notification);
+        self
+    }
+
+    /// Sets whether to skip descriptor merging
+    pub fn skip_descriptor_merge(mut self, skip_merge: bool) -> Self {
+        self.skip_descriptor_merge = Some(skip_merge);
+        self
+    }
+
+    /// Sets whether partial metadata includes the backends' connection info
+    pub fn include_conn_info(mut self, include_conn_info: bool) -> Self {
+        self.include_conn_info = Some(include_conn_info);
+        self
+    }
+
+    /// Sets whether transfers are verified with checksums
+    pub fn checksum(mut self, enable: bool) -> Self {
+        self.checksum = enable;
+        self
+    }
+
+    /// Sets the priority of transfers
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Sets the operands of remote atomic transfers
+    pub fn atomic_operands(mut self, operands: AtomicOperands) -> Self {
+        self.atomic_operands = Some(operands);
+        self
+    }
+
+    /// Sets whether memory types are checked when creating transfers
+    pub fn strict_validation(mut self, enable: bool) -> Self {
+        self.strict_validation = enable;
+        self
+    }
+
+    /// Sets how long transfers may take from posting to completion
+    pub fn deadline(mut self, deadline: std::time::Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Sets how metadata is compressed
+    pub fn md_compression(mut self, compression: Compression) -> Self {
+        self.md_compression = compression;
+        self
+    }
+
+    /// Tags the notification of transfers, which also enables notification
+    pub fn xfer_tag(mut self, tag: u64) -> Self {
+        self.xfer_tag = Some(tag);
+        self
+    }
+
+    /// Builds the optional arguments
+    ///
+    /// # Errors
+    /// Returns `InvalidParam` if a notification message is set while
+    /// notification is explicitly disabled
+    pub fn build(self) -> Result<OptArgs, NixlError> {
+        if self.notification_msg.is_some() && self.has_notification == Some(false) {
+            return Err(NixlError::InvalidParam);
+        }
+
+        let mut args = OptArgs::new()?;
+        for backend in self.backends {
+            args.add_backend(backend)?;
+        }
+        if let Some(message) = &self.notification_msg {
+            args.set_notification(message)?;
+        }
+        if let Some(has_notification) = self.has_notification {
+            args.set_has_notification(has_notification)?;
+        }
+        if let Some(skip_merge) = self.skip_descriptor_merge {
+            args.set_skip_descriptor_merge(skip_merge)?;
+        }
+        if let Some(include_conn_info) = self.include_conn_info {
+            args.set_include_conn_info(include_conn_info)?;
+        }
+        if let Some(tag) = self.xfer_tag {
+            args.set_xfer_tag(tag)?;
+        }
+        args.enable_checksum(self.checksum);
+        args.set_priority(self.priority);
+        args.atomic_operands = self.atomic_operands;
+        args.set_strict_validation(self.strict_validation);
+        args.deadline = self.deadline;
+        args.set_md_compression(self.md_compression);
+        Ok(args)
+    }
+}
+
 impl Drop for OptArgs {
     fn drop(&mut self) {
-        tracing::trace!("Dropping optional arguments");
         unsafe {
             nixl_capi_destroy_opt_args(self.inner.as_ptr());
         }
-        tracing::trace!("Optional arguments dropped");
     }
 }
 
@@ -443,7 +1222,19 @@ impl MemList {
         }
     }
 
+    /// Returns true if the list contains the given memory type
+    pub fn contains(&self, mem_type: MemType) -> Result<bool, NixlError> {
+        for item in self.iter() {
+            if item? == mem_type {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
     /// Returns an iterator over the memory types
+    ///
+    /// The iterator only reads from the list, so it can be created any number of times.
     pub fn iter(&self) -> MemListIterator<'_> {
         MemListIterator {
             list: self,
@@ -451,6 +1242,11 @@ impl MemList {
             length: self.len().unwrap_or(0),
         }
     }
+
+    /// Collects the memory types into a vector
+    pub fn to_vec(&self) -> Result<Vec<MemType>, NixlError> {
+        self.iter().collect()
+    }
 }
 
 /// An iterator over memory types in a MemList
@@ -479,6 +1275,8 @@ impl Iterator for MemListIterator<'_> {
     }
 }
 
+impl ExactSizeIterator for MemListIterator<'_> {}
+
 /// A trait for storage types that can be used with NIXL
 pub trait MemoryRegion: std::fmt::Debug + Send + Sync {
     /// Get a raw pointer to the storage
@@ -500,6 +1298,20 @@ pub trait NixlDescriptor: MemoryRegion {
 
     /// Get the device ID for this memory region
     fn device_id(&self) -> u64;
+
+    /// Returns the contiguous pieces of a non-contiguous region
+    ///
+    /// Transfer lists add these instead of the whole region. Contiguous
+    /// regions return `None`, which is the default.
+    fn xfer_segments(&self) -> Option<Vec<XferDescriptor>> {
+        None
+    }
+
+    /// Returns backend specific information registered with the region,
+    /// such as the path of a file or the key of an object
+    fn meta_info(&self) -> &[u8] {
+        &[]
+    }
 }
 
 /// A trait for types that can be registered with NIXL
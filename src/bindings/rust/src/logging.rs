@@ -0,0 +1,43 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Target of every span and event emitted by this crate
+///
+/// Applications with their own subscriber can filter on it, for example
+/// with `RUST_LOG=nixl=debug`, to scope the crate's output independently
+/// of their own modules.
+pub const TRACING_TARGET: &str = "nixl";
+
+/// Installs a global subscriber printing this crate's events up to `level`
+///
+/// Events of other targets are filtered out, so this is meant for tools and
+/// tests that only want NIXL's output; applications that already install a
+/// subscriber should filter on [`TRACING_TARGET`] instead.
+///
+/// # Errors
+/// Fails if a global subscriber has already been set.
+#[cfg(feature = "tracing-subscriber")]
+pub fn init_tracing(
+    level: tracing::level_filters::LevelFilter,
+) -> Result<(), tracing_subscriber::util::TryInitError> {
+    use tracing_subscriber::layer::SubscriberExt;
+    use tracing_subscriber::util::SubscriberInitExt;
+
+    let filter = tracing_subscriber::filter::Targets::new().with_target(TRACING_TARGET, level);
+    tracing_subscriber::registry()
+        .with(tracing_subscriber::fmt::layer())
+        .with(filter)
+        .try_init()
+}
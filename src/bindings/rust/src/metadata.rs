@@ -0,0 +1,678 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use serde::{Deserializer, Serializer};
+
+/// Prefix of raw metadata as serialized by the NIXL library
+const MD_RAW_MAGIC: &[u8] = b"nixlSerDes|";
+
+/// Prefix of compressed metadata, followed by its format version
+const MD_COMPRESSION_MAGIC: &[u8; 8] = b"nixlMdZ|";
+
+/// Format version of raw metadata, which carries no version field
+const MD_RAW_VERSION: u32 = 1;
+
+/// Newest metadata format version this crate reads and writes
+///
+/// Version 1 is the raw NIXL serialization and version 2 adds compression,
+/// see [`md_version`].
+pub const MD_FORMAT_VERSION: u32 = 2;
+
+/// Upper bound on the size a compressed blob may claim to expand to
+const MD_MAX_DECOMPRESSED_LEN: u64 = 1 << 30;
+
+/// Compression of metadata blobs, see [`OptArgs::set_md_compression`]
+///
+/// A compressed blob is the bytes `nixlMdZ|`, the format version as a
+/// little-endian `u32`, one byte naming the codec, the uncompressed length as
+/// a little-endian `u64`, then the payload. The codecs are behind the `lz4`
+/// and `zstd` features.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Compression {
+    /// Metadata is passed through as produced by the NIXL library
+    #[default]
+    None,
+    Lz4,
+    Zstd,
+}
+
+impl Compression {
+    fn tag(self) -> u8 {
+        match self {
+            Compression::None => 0,
+            Compression::Lz4 => 1,
+            Compression::Zstd => 2,
+        }
+    }
+
+    fn from_tag(tag: u8) -> Option<Self> {
+        match tag {
+            0 => Some(Compression::None),
+            1 => Some(Compression::Lz4),
+            2 => Some(Compression::Zstd),
+            _ => None,
+        }
+    }
+}
+
+/// Compresses a metadata blob with `compression`
+///
+/// # Errors
+/// * `Unsupported` - If the codec's feature is not enabled
+pub(crate) fn compress_md(md: Vec<u8>, compression: Compression) -> Result<Vec<u8>, NixlError> {
+    let payload = match compression {
+        Compression::None => return Ok(md),
+        #[cfg(feature = "lz4")]
+        Compression::Lz4 => lz4_flex::block::compress(&md),
+        #[cfg(feature = "zstd")]
+        Compression::Zstd => zstd::bulk::compress(&md, zstd::DEFAULT_COMPRESSION_LEVEL).map_err(|e| {
+            tracing::error!(target: TRACING_TARGET, error = %e, "Failed to compress metadata");
+            NixlError::BackendError
+        })?,
+        #[allow(unreachable_patterns)]
+        _ => {
+            tracing::error!(target: TRACING_TARGET, compression = ?compression, "Metadata compression codec is not enabled");
+            return Err(NixlError::Unsupported);
+        }
+    };
+
+    let mut compressed = Vec::with_capacity(MD_COMPRESSION_MAGIC.len() + 13 + payload.len());
+    compressed.extend_from_slice(MD_COMPRESSION_MAGIC);
+    compressed.extend_from_slice(&MD_FORMAT_VERSION.to_le_bytes());
+    compressed.push(compression.tag());
+    compressed.extend_from_slice(&(md.len() as u64).to_le_bytes());
+    compressed.extend_from_slice(&payload);
+    Ok(compressed)
+}
+
+/// Returns the raw form of a metadata blob, decompressing it if it carries
+/// the compression header
+///
+/// # Errors
+/// * `InvalidParam` - If the compressed blob is malformed
+/// * `IncompatibleMdVersion` - If the blob has a newer format than [`MD_FORMAT_VERSION`]
+/// * `Unsupported` - If the blob's codec feature is not enabled
+pub(crate) fn decompress_md(md: &[u8]) -> Result<std::borrow::Cow<'_, [u8]>, NixlError> {
+    let Some(rest) = md.strip_prefix(MD_COMPRESSION_MAGIC.as_slice()) else {
+        return Ok(std::borrow::Cow::Borrowed(md));
+    };
+    let (version, rest) = rest
+        .split_first_chunk::<4>()
+        .ok_or_else(|| md_error("truncated compression header"))?;
+    let version = u32::from_le_bytes(*version);
+    if version > MD_FORMAT_VERSION {
+        tracing::error!(target: TRACING_TARGET, version, supported = MD_FORMAT_VERSION, "Metadata has a newer format");
+        return Err(NixlError::IncompatibleMdVersion {
+            got: version,
+            supported: MD_FORMAT_VERSION,
+        });
+    }
+    if version <= MD_RAW_VERSION {
+        return Err(md_error("invalid compression header version"));
+    }
+    let (&tag, rest) = rest.split_first().ok_or_else(|| md_error("truncated compression header"))?;
+    let (len, payload) = rest
+        .split_first_chunk::<8>()
+        .ok_or_else(|| md_error("truncated compression header"))?;
+    let len = u64::from_le_bytes(*len);
+    if len > MD_MAX_DECOMPRESSED_LEN {
+        return Err(md_error("compressed metadata is too large"));
+    }
+    let len = len as usize;
+    let compression = Compression::from_tag(tag).ok_or_else(|| md_error("unknown compression"))?;
+
+    let md = match compression {
+        Compression::None => payload.to_vec(),
+        #[cfg(feature = "lz4")]
+        Compression::Lz4 => lz4_flex::block::decompress(payload, len)
+            .map_err(|_| md_error("corrupt lz4 payload"))?,
+        #[cfg(feature = "zstd")]
+        Compression::Zstd => zstd::bulk::decompress(payload, len)
+            .map_err(|_| md_error("corrupt zstd payload"))?,
+        #[allow(unreachable_patterns)]
+        _ => {
+            tracing::error!(target: TRACING_TARGET, compression = ?compression, "Metadata compression codec is not enabled");
+            return Err(NixlError::Unsupported);
+        }
+    };
+    if md.len() != len {
+        return Err(md_error("decompressed metadata has the wrong length"));
+    }
+    Ok(std::borrow::Cow::Owned(md))
+}
+
+/// Returns the format version of a metadata blob
+///
+/// Only compressed metadata (see [`OptArgs::set_md_compression`]) carries a
+/// version, in the header this crate adds. Raw metadata from the NIXL library
+/// has none, so every raw blob reports version 1 whichever NIXL release
+/// produced it. Returns `None` if the blob is in neither format.
+/// [`Agent::load_remote_md`] rejects compressed blobs newer than
+/// [`MD_FORMAT_VERSION`] with `IncompatibleMdVersion`; raw blobs from an
+/// incompatible NIXL release are not detected here and fail to load instead.
+pub fn md_version(md: &[u8]) -> Option<u32> {
+    if md.starts_with(MD_RAW_MAGIC) {
+        return Some(MD_RAW_VERSION);
+    }
+    let (version, _) = md
+        .strip_prefix(MD_COMPRESSION_MAGIC.as_slice())?
+        .split_first_chunk::<4>()?;
+    Some(u32::from_le_bytes(*version))
+}
+
+/// An opaque agent metadata blob, as produced by [`Agent::get_local_md`]
+///
+/// Serializes as a base64 string so it can be embedded in text based
+/// formats such as JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AgentMetadata(Vec<u8>);
+
+impl AgentMetadata {
+    /// Wraps raw metadata bytes
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+
+    /// Decodes metadata from a base64 string
+    pub fn from_base64(encoded: &str) -> Result<Self, NixlError> {
+        STANDARD.decode(encoded).map(Self).map_err(|e| {
+            tracing::error!(target: TRACING_TARGET, error = %e, "Failed to decode base64 metadata");
+            NixlError::InvalidParam
+        })
+    }
+
+    /// Encodes the metadata as a base64 string
+    pub fn to_base64(&self) -> String {
+        STANDARD.encode(&self.0)
+    }
+
+    /// Returns the raw metadata bytes
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Consumes the wrapper and returns the raw metadata bytes
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl From<Vec<u8>> for AgentMetadata {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+}
+
+impl AsRef<[u8]> for AgentMetadata {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Serialize for AgentMetadata {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_base64())
+    }
+}
+
+impl<'de> Deserialize<'de> for AgentMetadata {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        STANDARD
+            .decode(encoded)
+            .map(Self)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// Local agent metadata backed by the buffer allocated by the C API
+///
+/// Created by [`Agent::get_local_md_buf`]. Derefs to the metadata bytes and
+/// frees the underlying allocation when dropped.
+pub struct MdBuffer {
+    data: NonNull<u8>,
+    len: usize,
+}
+
+// SAFETY: The buffer is uniquely owned and only read through shared references
+unsafe impl Send for MdBuffer {}
+unsafe impl Sync for MdBuffer {}
+
+impl MdBuffer {
+    /// # Safety
+    /// `data` must point to `len` bytes allocated with `malloc`, and must not
+    /// be used or freed elsewhere afterwards.
+    pub(crate) unsafe fn from_raw(data: NonNull<u8>, len: usize) -> Self {
+        Self { data, len }
+    }
+}
+
+impl std::ops::Deref for MdBuffer {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self.data.as_ptr(), self.len) }
+    }
+}
+
+impl AsRef<[u8]> for MdBuffer {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl fmt::Debug for MdBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MdBuffer").field("len", &self.len).finish()
+    }
+}
+
+impl Drop for MdBuffer {
+    fn drop(&mut self) {
+        unsafe { libc::free(self.data.as_ptr() as *mut libc::c_void) };
+    }
+}
+
+/// Keeps this agent's metadata published in etcd until dropped
+///
+/// Created by [`Agent::publish_md_scoped`]. Dropping the guard, including
+/// during a panic unwind, invalidates the metadata so peers stop trying to
+/// connect to an agent that has gone away. Nothing runs if the process is
+/// killed outright (e.g. `SIGKILL`), in which case the metadata stays in etcd.
+#[derive(Debug)]
+#[must_use = "the metadata is invalidated as soon as the guard is dropped"]
+pub struct LocalMdGuard {
+    agent: Option<Agent>,
+}
+
+impl LocalMdGuard {
+    pub(crate) fn new(agent: Agent) -> Self {
+        Self { agent: Some(agent) }
+    }
+
+    /// Disarms the guard, leaving the metadata published in etcd
+    pub fn forget(mut self) {
+        self.agent = None;
+    }
+}
+
+impl Drop for LocalMdGuard {
+    fn drop(&mut self) {
+        if let Some(agent) = self.agent.take() {
+            if let Err(e) = agent.invalidate_local_md(None) {
+                tracing::error!(target: TRACING_TARGET, error = %e, "Failed to invalidate local metadata on drop");
+            }
+        }
+    }
+}
+
+/// A memory region advertised in agent metadata
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MdDescriptor {
+    /// Backend the region is registered with
+    pub backend: String,
+    pub mem_type: MemType,
+    pub addr: usize,
+    pub len: usize,
+    pub dev_id: u64,
+    /// Backend specific information remote agents need to access the region
+    pub meta: Vec<u8>,
+}
+
+/// Difference between two metadata blobs of the same agent, see [`md_delta`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MdDelta {
+    /// Regions only advertised by the new metadata
+    pub added: Vec<MdDescriptor>,
+    /// Regions only advertised by the old metadata
+    pub removed: Vec<MdDescriptor>,
+    /// Whether the set of backends or their connection info changed
+    pub conn_info_changed: bool,
+}
+
+impl MdDelta {
+    /// Returns true if the metadata did not change
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && !self.conn_info_changed
+    }
+}
+
+/// Checks whether two metadata blobs describe the same agent state
+///
+/// Blobs are compared by content, so the order in which the NIXL library
+/// serialized backends and regions does not matter. Blobs that cannot be
+/// parsed are compared byte for byte.
+pub fn md_equal(a: &[u8], b: &[u8]) -> bool {
+    match (ParsedMd::parse(a), ParsedMd::parse(b)) {
+        (Ok(a), Ok(b)) => a.agent == b.agent && a.delta(&b).is_empty(),
+        _ => a == b,
+    }
+}
+
+/// Describes how an agent's metadata changed from `old` to `new`
+///
+/// Both blobs can be full or partial metadata, as produced by
+/// [`Agent::get_local_md`] and [`Agent::get_local_partial_md`].
+///
+/// # Errors
+/// * `InvalidParam` - If a blob is malformed or the blobs belong to different agents
+pub fn md_delta(old: &[u8], new: &[u8]) -> Result<MdDelta, NixlError> {
+    let old = ParsedMd::parse(old)?;
+    let new = ParsedMd::parse(new)?;
+    if old.agent != new.agent {
+        tracing::error!(target: TRACING_TARGET, old.agent = %old.agent, new.agent = %new.agent, "Metadata belongs to different agents");
+        return Err(NixlError::InvalidParam);
+    }
+    Ok(old.delta(&new))
+}
+
+/// Readable summary of an agent's metadata, see [`parse_md_summary`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MdSummary {
+    pub agent: String,
+    /// Backends with connection info or advertised regions, sorted by name
+    pub backends: Vec<String>,
+    /// Number of advertised regions per memory type
+    pub desc_counts: HashMap<MemType, usize>,
+    /// The advertised regions, in serialization order
+    pub descs: Vec<MdDescriptor>,
+}
+
+/// Lists the agent, its backends and one line per advertised region
+impl fmt::Display for MdSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "agent {}", self.agent)?;
+        writeln!(f, "backends: {}", self.backends.join(", "))?;
+        for desc in &self.descs {
+            writeln!(
+                f,
+                "  {} {} [{:#x}, {:#x}) dev {} ({} bytes of backend info)",
+                desc.backend,
+                desc.mem_type,
+                desc.addr,
+                desc.addr + desc.len,
+                desc.dev_id,
+                desc.meta.len()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Decodes a metadata blob into a readable summary
+///
+/// Accepts full and partial metadata, as produced by [`Agent::get_local_md`]
+/// and [`Agent::get_local_partial_md`].
+///
+/// # Errors
+/// * `InvalidParam` - If the blob is malformed
+pub fn parse_md_summary(md: &[u8]) -> Result<MdSummary, NixlError> {
+    let parsed = ParsedMd::parse(md)?;
+    let mut backends: Vec<String> = parsed
+        .conns
+        .into_keys()
+        .chain(parsed.descs.iter().map(|desc| desc.backend.clone()))
+        .collect();
+    backends.sort_unstable();
+    backends.dedup();
+
+    let mut desc_counts = HashMap::new();
+    for desc in &parsed.descs {
+        *desc_counts.entry(desc.mem_type).or_insert(0) += 1;
+    }
+    Ok(MdSummary {
+        agent: parsed.agent,
+        backends,
+        desc_counts,
+        descs: parsed.descs,
+    })
+}
+
+/// Agent metadata decoded from the NIXL serialization format
+struct ParsedMd {
+    agent: String,
+    conns: std::collections::BTreeMap<String, Vec<u8>>,
+    descs: Vec<MdDescriptor>,
+}
+
+impl ParsedMd {
+    fn parse(md: &[u8]) -> Result<Self, NixlError> {
+        let md = decompress_md(md)?;
+        let mut reader = MdReader::new(&md)?;
+        let agent = reader.string("Agent")?;
+
+        let mut conns = std::collections::BTreeMap::new();
+        for _ in 0..reader.count("Conns")? {
+            let backend = reader.string("t")?;
+            conns.insert(backend, reader.field("c")?.to_vec());
+        }
+        if reader.field("")? != b"MemSection" {
+            return Err(md_error("missing memory section"));
+        }
+
+        let mut descs = Vec::new();
+        for _ in 0..reader.count("nixlSecElms")? {
+            let backend = reader.string("bknd")?;
+            if reader.field("nixlDList")? != b"nixlSDList" {
+                return Err(md_error("unexpected descriptor list type"));
+            }
+            let mem_type = parse_md_mem_type(reader.field("t")?)?;
+
+            for _ in 0..reader.count("n")? {
+                let (addr, len, dev_id, meta) = split_md_desc(reader.field("")?)?;
+                descs.push(MdDescriptor {
+                    backend: backend.clone(),
+                    mem_type,
+                    addr,
+                    len,
+                    dev_id,
+                    meta: meta.to_vec(),
+                });
+            }
+        }
+
+        Ok(Self {
+            agent,
+            conns,
+            descs,
+        })
+    }
+
+    fn delta(&self, new: &ParsedMd) -> MdDelta {
+        let old_descs: HashSet<&MdDescriptor> = self.descs.iter().collect();
+        let new_descs: HashSet<&MdDescriptor> = new.descs.iter().collect();
+        MdDelta {
+            added: new
+                .descs
+                .iter()
+                .filter(|d| !old_descs.contains(d))
+                .cloned()
+                .collect(),
+            removed: self
+                .descs
+                .iter()
+                .filter(|d| !new_descs.contains(d))
+                .cloned()
+                .collect(),
+            conn_info_changed: self.conns != new.conns,
+        }
+    }
+}
+
+/// Rewrites a metadata blob to advertise only the regions of `mem_type` that
+/// overlap one of `wanted`, see [`Agent::load_remote_partial_md`]
+///
+/// Connection info is kept as is. Returns the name of the agent the
+/// metadata belongs to and the filtered blob, which is never compressed.
+pub(crate) fn filter_md(
+    md: &[u8],
+    mem_type: MemType,
+    wanted: &[XferDescriptor],
+) -> Result<(String, Vec<u8>), NixlError> {
+    let md = decompress_md(md)?;
+    let mut reader = MdReader::new(&md)?;
+    let mut writer = MdWriter::new();
+
+    let agent = reader.field("Agent")?;
+    writer.field("Agent", agent);
+    let conn_count = reader.count("Conns")?;
+    writer.count("Conns", conn_count);
+    for _ in 0..conn_count {
+        writer.field("t", reader.field("t")?);
+        writer.field("c", reader.field("c")?);
+    }
+    if reader.field("")? != b"MemSection" {
+        return Err(md_error("missing memory section"));
+    }
+    writer.field("", b"MemSection");
+
+    let mut sections = Vec::new();
+    for _ in 0..reader.count("nixlSecElms")? {
+        let backend = reader.field("bknd")?;
+        if reader.field("nixlDList")? != b"nixlSDList" {
+            return Err(md_error("unexpected descriptor list type"));
+        }
+        let raw_type = reader.field("t")?;
+        let section_type = parse_md_mem_type(raw_type)?;
+        let mut kept = Vec::new();
+        for _ in 0..reader.count("n")? {
+            let desc = reader.field("")?;
+            let (addr, len, dev_id, _) = split_md_desc(desc)?;
+            let overlaps = wanted.iter().any(|w| {
+                w.dev_id == dev_id && addr < w.addr.saturating_add(w.len) && w.addr < addr.saturating_add(len)
+            });
+            if section_type == mem_type && overlaps {
+                kept.push(desc);
+            }
+        }
+        if !kept.is_empty() {
+            sections.push((backend, raw_type, kept));
+        }
+    }
+
+    writer.count("nixlSecElms", sections.len());
+    for (backend, raw_type, kept) in sections {
+        writer.field("bknd", backend);
+        writer.field("nixlDList", b"nixlSDList");
+        writer.field("t", raw_type);
+        writer.count("n", kept.len());
+        for desc in kept {
+            writer.field("", desc);
+        }
+    }
+    writer.buf.extend_from_slice(reader.buf);
+
+    let agent = String::from_utf8(agent.to_vec()).map_err(|_| md_error("invalid string"))?;
+    Ok((agent, writer.buf))
+}
+
+/// Decodes the memory type of a serialized descriptor list
+fn parse_md_mem_type(field: &[u8]) -> Result<MemType, NixlError> {
+    let mem_type = <[u8; 4]>::try_from(field).map_err(|_| md_error("invalid memory type"))?;
+    Ok(MemType::from(u32::from_ne_bytes(mem_type) as nixl_capi_mem_type_t))
+}
+
+/// Splits a serialized descriptor into its address, length, device and
+/// backend specific part
+fn split_md_desc(desc: &[u8]) -> Result<(usize, usize, u64, &[u8]), NixlError> {
+    let (addr, rest) = desc
+        .split_first_chunk::<8>()
+        .ok_or_else(|| md_error("truncated descriptor"))?;
+    let (len, rest) = rest
+        .split_first_chunk::<8>()
+        .ok_or_else(|| md_error("truncated descriptor"))?;
+    let (dev_id, meta) = rest
+        .split_first_chunk::<8>()
+        .ok_or_else(|| md_error("truncated descriptor"))?;
+    Ok((
+        u64::from_ne_bytes(*addr) as usize,
+        u64::from_ne_bytes(*len) as usize,
+        u64::from_ne_bytes(*dev_id),
+        meta,
+    ))
+}
+
+fn md_error(reason: &str) -> NixlError {
+    tracing::error!(target: TRACING_TARGET, reason, "Failed to parse agent metadata");
+    NixlError::InvalidParam
+}
+
+/// Cursor over the `tag, length, bytes, '|'` fields of a serialized blob
+struct MdReader<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> MdReader<'a> {
+    fn new(md: &'a [u8]) -> Result<Self, NixlError> {
+        let buf = md
+            .strip_prefix(MD_RAW_MAGIC)
+            .ok_or_else(|| md_error("missing serialization header"))?;
+        Ok(Self { buf })
+    }
+
+    fn field(&mut self, tag: &str) -> Result<&'a [u8], NixlError> {
+        let rest = self
+            .buf
+            .strip_prefix(tag.as_bytes())
+            .ok_or_else(|| md_error("unexpected field"))?;
+        let (len, rest) = rest
+            .split_first_chunk::<8>()
+            .ok_or_else(|| md_error("truncated field"))?;
+        let len = usize::try_from(u64::from_ne_bytes(*len))
+            .map_err(|_| md_error("invalid field length"))?;
+        if rest.len() <= len || rest[len] != b'|' {
+            return Err(md_error("truncated field"));
+        }
+        self.buf = &rest[len + 1..];
+        Ok(&rest[..len])
+    }
+
+    fn string(&mut self, tag: &str) -> Result<String, NixlError> {
+        let field = self.field(tag)?;
+        String::from_utf8(field.to_vec()).map_err(|_| md_error("invalid string"))
+    }
+
+    fn count(&mut self, tag: &str) -> Result<usize, NixlError> {
+        let field = self.field(tag)?;
+        let count = <[u8; 8]>::try_from(field).map_err(|_| md_error("invalid count"))?;
+        usize::try_from(u64::from_ne_bytes(count)).map_err(|_| md_error("invalid count"))
+    }
+}
+
+/// Builds a blob of `tag, length, bytes, '|'` fields, the inverse of [`MdReader`]
+struct MdWriter {
+    buf: Vec<u8>,
+}
+
+impl MdWriter {
+    fn new() -> Self {
+        Self {
+            buf: MD_RAW_MAGIC.to_vec(),
+        }
+    }
+
+    fn field(&mut self, tag: &str, value: &[u8]) {
+        self.buf.extend_from_slice(tag.as_bytes());
+        self.buf.extend_from_slice(&(value.len() as u64).to_ne_bytes());
+        self.buf.extend_from_slice(value);
+        self.buf.push(b'|');
+    }
+
+    fn count(&mut self, tag: &str, count: usize) {
+        self.field(tag, &(count as u64).to_ne_bytes());
+    }
+}
@@ -0,0 +1,340 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! In-process stand-in for [`Agent`], enabled by the `mock` feature
+//!
+//! [`MockAgent`] never calls into the NIXL library, so code driving transfers
+//! and notifications can be unit tested without a backend or hardware. Its
+//! methods mirror the corresponding [`Agent`] methods, but take plain
+//! [`XferDescriptor`] slices instead of descriptor lists, which are backed by
+//! C handles.
+
+use super::*;
+
+use std::collections::VecDeque;
+
+/// Scripted outcome of a transfer created by a [`MockAgent`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MockOutcome {
+    /// The transfer completes as soon as it is posted
+    Complete,
+    /// The transfer reports `InProgress` for the given number of status
+    /// checks, then completes
+    CompleteAfter(u32),
+    /// The transfer fails with the given backend code once it is checked
+    Fail(i32),
+}
+
+/// A transfer request created by a [`MockAgent`]
+#[derive(Debug)]
+pub struct MockXferRequest {
+    id: u64,
+    op: XferOp,
+    remote_agent: String,
+    total_bytes: u64,
+}
+
+impl MockXferRequest {
+    /// Returns the operation this request performs
+    pub fn op(&self) -> XferOp {
+        self.op
+    }
+
+    /// Returns the name of the remote agent this request targets
+    pub fn remote_agent(&self) -> &str {
+        &self.remote_agent
+    }
+
+    /// Returns the number of bytes this request transfers
+    pub fn total_bytes(&self) -> u64 {
+        self.total_bytes
+    }
+}
+
+/// A memory registration made through a [`MockAgent`]
+#[derive(Debug)]
+pub struct MockRegistration {
+    mem_type: MemType,
+    size: usize,
+}
+
+impl MockRegistration {
+    /// Returns the memory type of the registered region
+    pub fn mem_type(&self) -> MemType {
+        self.mem_type
+    }
+
+    /// Returns the size of the registered region in bytes
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+#[derive(Debug)]
+struct MockXfer {
+    outcome: MockOutcome,
+    posted: bool,
+    polls: u32,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    next_req_id: u64,
+    remotes: HashSet<String>,
+    outcomes: VecDeque<MockOutcome>,
+    xfers: HashMap<u64, MockXfer>,
+    inbox: Vec<(String, Vec<u8>)>,
+    sent: Vec<(String, Vec<u8>)>,
+}
+
+/// A scriptable agent that simulates transfers and notifications in memory
+///
+/// Clones share state, so a test can keep one handle to script outcomes and
+/// inspect sent notifications while the code under test uses another.
+#[derive(Debug, Clone)]
+pub struct MockAgent {
+    name: Arc<str>,
+    state: Arc<Mutex<MockState>>,
+}
+
+impl MockAgent {
+    /// Creates a new mock agent with no remotes loaded
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: Arc::from(name),
+            state: Arc::default(),
+        }
+    }
+
+    /// Gets the name of the agent
+    pub fn name(&self) -> String {
+        self.name.to_string()
+    }
+
+    /// Marks a remote agent as loaded, as loading its metadata would
+    pub fn add_remote(&self, remote_agent: &str) {
+        self.state().remotes.insert(remote_agent.to_string());
+    }
+
+    /// Checks if metadata for a remote agent is loaded
+    pub fn is_remote_loaded(&self, remote_agent: &str) -> bool {
+        self.state().remotes.contains(remote_agent)
+    }
+
+    /// Queues the outcome of the next transfer request to be created
+    ///
+    /// Outcomes are consumed in order; requests created once the queue is
+    /// empty complete as soon as they are posted.
+    pub fn push_outcome(&self, outcome: MockOutcome) {
+        self.state().outcomes.push_back(outcome);
+    }
+
+    /// Queues a notification as if `remote_agent` had sent it to this agent
+    pub fn inject_notification(&self, remote_agent: &str, message: &[u8]) {
+        self.state()
+            .inbox
+            .push((remote_agent.to_string(), message.to_vec()));
+    }
+
+    /// Returns the `(remote agent, message)` pairs sent so far, oldest first
+    pub fn sent_notifications(&self) -> Vec<(String, Vec<u8>)> {
+        self.state().sent.clone()
+    }
+
+    /// Creates a transfer request between local and remote descriptors
+    ///
+    /// # Errors
+    /// * `Unsupported` - For [`XferOp::WriteDurable`] and remote atomics
+    /// * `RemoteNotLoaded` - If `remote_agent` was not added with [`MockAgent::add_remote`]
+    /// * `InvalidParam` - If the descriptor lists differ in count or lengths
+    pub fn create_xfer_req(
+        &self,
+        operation: XferOp,
+        local_descs: &[XferDescriptor],
+        remote_descs: &[XferDescriptor],
+        remote_agent: &str,
+    ) -> Result<MockXferRequest, NixlError> {
+        operation.to_capi()?;
+        let mut state = self.state();
+        if !state.remotes.contains(remote_agent) {
+            return Err(NixlError::RemoteNotLoaded(remote_agent.to_string()));
+        }
+        if local_descs.len() != remote_descs.len()
+            || local_descs
+                .iter()
+                .zip(remote_descs)
+                .any(|(local, remote)| local.len != remote.len)
+        {
+            return Err(NixlError::InvalidParam);
+        }
+
+        let id = state.next_req_id;
+        state.next_req_id += 1;
+        let outcome = state.outcomes.pop_front().unwrap_or(MockOutcome::Complete);
+        state.xfers.insert(
+            id,
+            MockXfer {
+                outcome,
+                posted: false,
+                polls: 0,
+            },
+        );
+        Ok(MockXferRequest {
+            id,
+            op: operation,
+            remote_agent: remote_agent.to_string(),
+            total_bytes: local_descs.iter().map(|desc| desc.len as u64).sum(),
+        })
+    }
+
+    /// Posts a transfer request
+    ///
+    /// # Returns
+    /// * `Ok(false)` - If the transfer completed immediately
+    /// * `Ok(true)` - If the transfer is in progress
+    ///
+    /// # Errors
+    /// * `InvalidParam` - If the request was not created by this agent
+    /// * `XferInProgress` - If the request was already posted
+    pub fn post_xfer_req(&self, req: &MockXferRequest) -> Result<bool, NixlError> {
+        let mut state = self.state();
+        let xfer = state.xfers.get_mut(&req.id).ok_or(NixlError::InvalidParam)?;
+        if xfer.posted {
+            return Err(NixlError::XferInProgress);
+        }
+        xfer.posted = true;
+        Ok(xfer.outcome != MockOutcome::Complete)
+    }
+
+    /// Checks the status of a transfer request, advancing its scripted outcome
+    ///
+    /// # Errors
+    /// * `InvalidParam` - If the request was not created by this agent or never posted
+    pub fn get_xfer_status(&self, req: &MockXferRequest) -> Result<XferStatus, NixlError> {
+        let mut state = self.state();
+        let xfer = state.xfers.get_mut(&req.id).ok_or(NixlError::InvalidParam)?;
+        if !xfer.posted {
+            return Err(NixlError::InvalidParam);
+        }
+        match xfer.outcome {
+            MockOutcome::Complete => Ok(XferStatus::Success),
+            MockOutcome::CompleteAfter(polls) if xfer.polls < polls => {
+                xfer.polls += 1;
+                Ok(XferStatus::InProgress)
+            }
+            MockOutcome::CompleteAfter(_) => Ok(XferStatus::Success),
+            MockOutcome::Fail(code) => Ok(XferStatus::Failed { code }),
+        }
+    }
+
+    /// Sends a notification to a remote agent
+    ///
+    /// # Errors
+    /// * `RemoteNotLoaded` - If `remote_agent` was not added with [`MockAgent::add_remote`]
+    pub fn send_notification(&self, remote_agent: &str, message: &[u8]) -> Result<(), NixlError> {
+        let mut state = self.state();
+        if !state.remotes.contains(remote_agent) {
+            return Err(NixlError::RemoteNotLoaded(remote_agent.to_string()));
+        }
+        state.sent.push((remote_agent.to_string(), message.to_vec()));
+        Ok(())
+    }
+
+    /// Takes all injected notifications, grouped by sending agent
+    pub fn get_notifications(&self) -> Result<HashMap<String, Vec<Vec<u8>>>, NixlError> {
+        let mut notifs: HashMap<String, Vec<Vec<u8>>> = HashMap::new();
+        for (remote_agent, message) in self.state().inbox.drain(..) {
+            notifs.entry(remote_agent).or_default().push(message);
+        }
+        Ok(notifs)
+    }
+
+    fn state(&self) -> impl std::ops::DerefMut<Target = MockState> + '_ {
+        self.state.lock()
+    }
+}
+
+/// Metadata of a mock agent is its name, so agents can load each other's
+/// metadata as real agents would. Optional arguments are ignored.
+impl AgentApi for MockAgent {
+    type OptArgs = ();
+    type XferDescList<'a> = [XferDescriptor];
+    type XferRequest = MockXferRequest;
+    type Registration = MockRegistration;
+
+    fn name(&self) -> String {
+        MockAgent::name(self)
+    }
+
+    fn register_memory(
+        &self,
+        descriptor: &impl NixlDescriptor,
+        _opt_args: Option<&()>,
+    ) -> Result<MockRegistration, NixlError> {
+        Ok(MockRegistration {
+            mem_type: descriptor.mem_type(),
+            size: descriptor.size(),
+        })
+    }
+
+    fn get_local_md(&self) -> Result<Vec<u8>, NixlError> {
+        Ok(self.name.as_bytes().to_vec())
+    }
+
+    fn load_remote_md(&self, metadata: &[u8]) -> Result<String, NixlError> {
+        let remote_agent = std::str::from_utf8(metadata).map_err(|_| NixlError::InvalidParam)?;
+        self.add_remote(remote_agent);
+        Ok(remote_agent.to_string())
+    }
+
+    fn invalidate_remote_md(&self, remote_agent: &str) -> Result<(), NixlError> {
+        if self.state().remotes.remove(remote_agent) {
+            Ok(())
+        } else {
+            Err(NixlError::InvalidParam)
+        }
+    }
+
+    fn is_remote_loaded(&self, remote_agent: &str) -> bool {
+        MockAgent::is_remote_loaded(self, remote_agent)
+    }
+
+    fn create_xfer_req(
+        &self,
+        operation: XferOp,
+        local_descs: &[XferDescriptor],
+        remote_descs: &[XferDescriptor],
+        remote_agent: &str,
+        _opt_args: Option<&()>,
+    ) -> Result<MockXferRequest, NixlError> {
+        MockAgent::create_xfer_req(self, operation, local_descs, remote_descs, remote_agent)
+    }
+
+    fn post_xfer_req(&self, req: &MockXferRequest, _opt_args: Option<&()>) -> Result<bool, NixlError> {
+        MockAgent::post_xfer_req(self, req)
+    }
+
+    fn get_xfer_status(&self, req: &MockXferRequest) -> Result<XferStatus, NixlError> {
+        MockAgent::get_xfer_status(self, req)
+    }
+
+    fn send_notification(&self, remote_agent: &str, message: &[u8]) -> Result<(), NixlError> {
+        MockAgent::send_notification(self, remote_agent, message)
+    }
+
+    fn take_notifications(&self) -> Result<HashMap<String, Vec<Vec<u8>>>, NixlError> {
+        self.get_notifications()
+    }
+}
@@ -20,8 +20,49 @@ use std::{
     ffi::{CStr, CString},
     os::raw::c_char, // Added for *const c_char
     ptr::{self, NonNull},
+    str::Utf8Error,
+    sync::atomic::{AtomicBool, Ordering},
+    sync::mpsc,
+    thread,
+    time::Duration,
 };
 
+/// Prefix of notification messages carrying a transfer tag, see [`OptArgs::set_xfer_tag`]
+const XFER_TAG_PREFIX: &str = "NIXL_TAG:";
+
+/// Number of hex digits of an encoded transfer tag
+const XFER_TAG_DIGITS: usize = 16;
+
+/// Prepends the transfer tag header to a notification message
+///
+/// The tag is written as fixed-width hex digits followed by `:`, so a tagged
+/// message stays valid UTF-8 if the message is.
+pub(crate) fn encode_xfer_tag(tag: u64, message: &[u8]) -> Vec<u8> {
+    let mut tagged = format!("{XFER_TAG_PREFIX}{tag:016x}:").into_bytes();
+    tagged.extend_from_slice(message);
+    tagged
+}
+
+/// Splits a notification into its transfer tag, if it carries one, and the
+/// message it was sent with
+///
+/// See [`OptArgs::set_xfer_tag`]. Useful for notifications received as bytes,
+/// such as through a [`NotificationReceiver`].
+pub fn split_xfer_tag(notification: &[u8]) -> (Option<u64>, &[u8]) {
+    notification
+        .strip_prefix(XFER_TAG_PREFIX.as_bytes())
+        .and_then(<[u8]>::split_first_chunk::<XFER_TAG_DIGITS>)
+        .and_then(|(digits, rest)| {
+            let message = rest.strip_prefix(b":")?;
+            if !digits.iter().all(u8::is_ascii_hexdigit) {
+                return None;
+            }
+            let tag = u64::from_str_radix(std::str::from_utf8(digits).ok()?, 16).ok()?;
+            Some((tag, message))
+        })
+        .map_or((None, notification), |(tag, message)| (Some(tag), message))
+}
+
 /// A safe wrapper around NIXL notification map
 pub struct NotificationMap {
     pub(crate) inner: NonNull<bindings::nixl_capi_notif_map_s>,
@@ -102,6 +143,56 @@ impl NotificationMap {
         agent_name: &str,
         index: usize,
     ) -> Result<Vec<u8>, NixlError> {
+        self.notification_slice(agent_name, index).map(<[u8]>::to_vec)
+    }
+
+    /// Returns a specific notification for a given agent split into its
+    /// transfer tag and message, see [`split_xfer_tag`]
+    pub fn get_tagged_notification(
+        &self,
+        agent_name: &str,
+        index: usize,
+    ) -> Result<(Option<u64>, &[u8]), NixlError> {
+        self.notification_slice(agent_name, index).map(split_xfer_tag)
+    }
+
+    /// Returns an iterator over all notifications as `(agent, tag, message)`
+    ///
+    /// Notifications sent without a transfer tag yield `None` and their whole
+    /// message. Agents or notifications the map fails to return are skipped.
+    pub fn iter_tagged(&self) -> impl Iterator<Item = (&str, Option<u64>, &[u8])> + '_ {
+        self.agents()
+            .filter_map(Result::ok)
+            .flat_map(move |agent_name| {
+                let count = self.get_notifications_size(agent_name).unwrap_or(0);
+                (0..count).filter_map(move |index| {
+                    let (tag, message) = self.get_tagged_notification(agent_name, index).ok()?;
+                    Some((agent_name, tag, message))
+                })
+            })
+    }
+
+    /// Returns an iterator over all notifications as `(agent, message)` pairs,
+    /// decoding each message as UTF-8
+    ///
+    /// Messages are borrowed from the map without copying. Binary
+    /// notifications yield a `Utf8Error` and can be read with
+    /// [`NotificationMap::get_notification_bytes`]. Agents or notifications
+    /// the map fails to return are skipped.
+    pub fn iter_str(&self) -> impl Iterator<Item = (&str, Result<&str, Utf8Error>)> + '_ {
+        self.agents()
+            .filter_map(Result::ok)
+            .flat_map(move |agent_name| {
+                let count = self.get_notifications_size(agent_name).unwrap_or(0);
+                (0..count).filter_map(move |index| {
+                    let bytes = self.notification_slice(agent_name, index).ok()?;
+                    Some((agent_name, std::str::from_utf8(bytes)))
+                })
+            })
+    }
+
+    /// Borrows a specific notification for a given agent from the C map
+    fn notification_slice(&self, agent_name: &str, index: usize) -> Result<&[u8], NixlError> {
         let c_name = CString::new(agent_name).map_err(|_| NixlError::InvalidParam)?;
         let mut data: *const u8 = ptr::null();
         let mut len = 0;
@@ -117,15 +208,12 @@ impl NotificationMap {
         match status {
             NIXL_CAPI_SUCCESS => {
                 if data.is_null() {
-                    Ok(Vec::new())
+                    Ok(&[])
                 } else {
                     // SAFETY: If status is NIXL_CAPI_SUCCESS, data points to valid memory of size len
-                    // This data is owned by the C side and is valid until the map is cleared or modified.
-                    let bytes = unsafe {
-                        let slice = std::slice::from_raw_parts(data, len);
-                        slice.to_vec()
-                    };
-                    Ok(bytes)
+                    // This data is owned by the C side and is valid until the map is cleared or
+                    // modified, which requires a mutable borrow of the map.
+                    Ok(unsafe { std::slice::from_raw_parts(data, len) })
                 }
             }
             NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
@@ -244,6 +332,131 @@ impl NotificationMap {
     }
 }
 
+impl NotificationMap {
+    /// Takes all notifications from the map as `(agent, bytes)` pairs and
+    /// clears the underlying C map for reuse
+    pub(crate) fn drain_bytes(&mut self) -> Result<Vec<(String, Vec<u8>)>, NixlError> {
+        let mut drained = Vec::new();
+        for agent_name in self.agents() {
+            let agent_name = agent_name?;
+            for notification in self.get_notifications(agent_name)? {
+                drained.push((agent_name.to_string(), notification?));
+            }
+        }
+
+        let status = unsafe { nixl_capi_notif_map_clear(self.inner.as_ptr()) };
+        match status {
+            NIXL_CAPI_SUCCESS => Ok(drained),
+            NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
+            _ => Err(NixlError::BackendError),
+        }
+    }
+
+    /// Adds a notification from `agent_name` after any already in the map
+    pub(crate) fn append(&mut self, agent_name: &str, notification: &[u8]) -> Result<(), NixlError> {
+        let c_name = CString::new(agent_name)?;
+        let status = unsafe {
+            nixl_capi_notif_map_append(
+                self.inner.as_ptr(),
+                c_name.as_ptr(),
+                notification.as_ptr() as *const std::ffi::c_void,
+                notification.len(),
+            )
+        };
+        match status {
+            NIXL_CAPI_SUCCESS => Ok(()),
+            NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
+            _ => Err(NixlError::BackendError),
+        }
+    }
+}
+
+/// Receives notifications pushed by a background polling thread
+///
+/// Created by [`Agent::notification_stream`]. Each item is the name of the
+/// sending agent and the raw notification bytes. Dropping the receiver stops
+/// the polling thread and waits for it to exit.
+pub struct NotificationReceiver {
+    receiver: mpsc::Receiver<(String, Vec<u8>)>,
+    stop: Arc<AtomicBool>,
+    thread: Option<thread::JoinHandle<()>>,
+}
+
+impl NotificationReceiver {
+    pub(crate) fn spawn(agent: Agent, poll_interval: Duration) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = stop.clone();
+
+        let thread = thread::spawn(move || {
+            let mut notifs = match NotificationMap::new() {
+                Ok(notifs) => notifs,
+                Err(e) => {
+                    tracing::error!(target: TRACING_TARGET, error = ?e, "Failed to create notification map for stream");
+                    return;
+                }
+            };
+
+            while !thread_stop.load(Ordering::Acquire) {
+                let drained = agent
+                    .get_notifications(&mut notifs, None)
+                    .and_then(|()| notifs.drain_bytes());
+                match drained {
+                    Ok(drained) => {
+                        for notification in drained {
+                            if sender.send(notification).is_err() {
+                                return;
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        tracing::error!(target: TRACING_TARGET, error = ?e, "Failed to poll notifications, stopping stream");
+                        return;
+                    }
+                }
+                thread::sleep(poll_interval);
+            }
+        });
+
+        Self {
+            receiver,
+            stop,
+            thread: Some(thread),
+        }
+    }
+
+    /// Blocks until the next notification arrives
+    ///
+    /// Returns an error if the polling thread has stopped.
+    pub fn recv(&self) -> Result<(String, Vec<u8>), mpsc::RecvError> {
+        self.receiver.recv()
+    }
+
+    /// Returns the next notification if one is already available
+    pub fn try_recv(&self) -> Result<(String, Vec<u8>), mpsc::TryRecvError> {
+        self.receiver.try_recv()
+    }
+
+    /// Waits up to `timeout` for the next notification
+    pub fn recv_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<(String, Vec<u8>), mpsc::RecvTimeoutError> {
+        self.receiver.recv_timeout(timeout)
+    }
+}
+
+impl Drop for NotificationReceiver {
+    fn drop(&mut self) {
+        tracing::trace!(target: TRACING_TARGET, "Stopping notification stream");
+        self.stop.store(true, Ordering::Release);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+        tracing::trace!(target: TRACING_TARGET, "Notification stream stopped");
+    }
+}
+
 /// An iterator over agent names in a NotificationMap
 pub struct NotificationMapAgentIterator<'a> {
     map: &'a NotificationMap,
@@ -316,10 +529,10 @@ impl Iterator for NotificationIterator<'_> {
 
 impl Drop for NotificationMap {
     fn drop(&mut self) {
-        tracing::trace!("Dropping notification map");
+        tracing::trace!(target: TRACING_TARGET, "Dropping notification map");
         unsafe {
             nixl_capi_destroy_notif_map(self.inner.as_ptr());
         }
-        tracing::trace!("Notification map dropped");
+        tracing::trace!(target: TRACING_TARGET, "Notification map dropped");
     }
 }
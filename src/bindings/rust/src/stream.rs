@@ -0,0 +1,153 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Asynchronous completion of transfer batches, enabled by the `async` feature
+//!
+//! NIXL has no completion events, so [`CompletionStream`] polls its requests.
+//! While any of them is in progress, a helper thread wakes the consuming task
+//! once per poll interval, so the stream works with any executor.
+
+use super::*;
+
+use futures_core::Stream;
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::sync::atomic::AtomicBool;
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+
+/// Stream of transfer completions, see [`Agent::completion_stream`]
+///
+/// Yields the index of each request together with its outcome, in the order
+/// the requests are seen to finish. Finished requests are dropped, which
+/// releases them. Dropping the stream releases the requests still pending.
+#[derive(Debug)]
+pub struct CompletionStream {
+    agent: Agent,
+    reqs: Vec<Option<XferRequest>>,
+    ready: VecDeque<(usize, Result<(), NixlError>)>,
+    poll_interval: Duration,
+    ticker: Option<Ticker>,
+}
+
+impl CompletionStream {
+    pub(crate) fn new(agent: Agent, reqs: Vec<XferRequest>, poll_interval: Duration) -> Self {
+        Self {
+            agent,
+            reqs: reqs.into_iter().map(Some).collect(),
+            ready: VecDeque::new(),
+            poll_interval,
+            ticker: None,
+        }
+    }
+
+    /// Returns the number of requests not yet yielded
+    pub fn remaining(&self) -> usize {
+        self.ready.len() + self.reqs.iter().flatten().count()
+    }
+
+    /// Checks every pending request once, queueing those that finished
+    fn poll_requests(&mut self) {
+        for (index, slot) in self.reqs.iter_mut().enumerate() {
+            let Some(req) = slot else { continue };
+            let result = match self.agent.get_xfer_status(req) {
+                Ok(XferStatus::InProgress) => continue,
+                Ok(XferStatus::Success) => Ok(()),
+                Ok(XferStatus::Failed { code }) => Err(NixlError::XferFailed { index, code }),
+                Err(e) => Err(e),
+            };
+            *slot = None;
+            self.ready.push_back((index, result));
+        }
+    }
+}
+
+impl Stream for CompletionStream {
+    type Item = (usize, Result<(), NixlError>);
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        if this.ready.is_empty() {
+            this.poll_requests();
+        }
+        if let Some(item) = this.ready.pop_front() {
+            return Poll::Ready(Some(item));
+        }
+        if this.reqs.iter().all(Option::is_none) {
+            this.ticker = None;
+            return Poll::Ready(None);
+        }
+
+        if this.ticker.is_none() {
+            this.ticker = Ticker::spawn(this.poll_interval);
+        }
+        match &this.ticker {
+            Some(ticker) => ticker.set_waker(cx.waker()),
+            // Without a ticker, fall back to being polled again right away
+            None => cx.waker().wake_by_ref(),
+        }
+        Poll::Pending
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining();
+        (remaining, Some(remaining))
+    }
+}
+
+/// Helper thread waking the consuming task once per poll interval
+#[derive(Debug)]
+struct Ticker {
+    waker: Arc<Mutex<Option<Waker>>>,
+    stop: Arc<AtomicBool>,
+}
+
+impl Ticker {
+    fn spawn(interval: Duration) -> Option<Self> {
+        let waker: Arc<Mutex<Option<Waker>>> = Arc::default();
+        let stop = Arc::new(AtomicBool::new(false));
+        let spawned = std::thread::Builder::new().name("nixl-completion-stream".to_string()).spawn({
+            let waker = waker.clone();
+            let stop = stop.clone();
+            move || {
+                while !stop.load(Ordering::Acquire) {
+                    std::thread::sleep(interval);
+                    if let Some(waker) = waker.lock().take() {
+                        waker.wake();
+                    }
+                }
+            }
+        });
+        match spawned {
+            Ok(_) => Some(Self { waker, stop }),
+            Err(e) => {
+                tracing::error!(target: TRACING_TARGET, error = %e, "Failed to spawn completion stream ticker");
+                None
+            }
+        }
+    }
+
+    fn set_waker(&self, waker: &Waker) {
+        *self.waker.lock() = Some(waker.clone());
+    }
+}
+
+impl Drop for Ticker {
+    fn drop(&mut self) {
+        // The thread exits after its current sleep; it is not joined so
+        // dropping the stream never blocks the executor
+        self.stop.store(true, Ordering::Release);
+    }
+}
@@ -15,6 +15,9 @@
 
 use super::*;
 
+/// Backend parameter holding the number of dedicated progress threads
+const PROGRESS_THREADS_PARAM: &str = "num_threads";
+
 /// A safe wrapper around NIXL parameters
 pub struct Params {
     inner: NonNull<bindings::nixl_capi_params_s>,
@@ -52,7 +55,8 @@ impl<'a> Iterator for ParamIterator<'a> {
         };
 
         match status {
-            0 if !has_next => None,
+            // The last pair is returned with has_next == false, so only a null key marks the end
+            0 if key_ptr.is_null() => None,
             0 => {
                 // SAFETY: If status is 0, both pointers are valid null-terminated strings
                 let result = unsafe {
@@ -117,6 +121,56 @@ impl Params {
         }
     }
 
+    /// Returns the value for the given key, if present
+    pub fn get(&self, key: &str) -> Result<Option<String>, NixlError> {
+        for pair in self.iter()? {
+            let pair = pair?;
+            if pair.key == key {
+                return Ok(Some(pair.value.to_string()));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Sets the value for the given key, replacing any existing value
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), NixlError> {
+        let c_key = CString::new(key)?;
+        let c_value = CString::new(value)?;
+
+        // SAFETY: self.inner is guaranteed to be valid by NonNull
+        let status =
+            unsafe { nixl_capi_params_set(self.inner.as_ptr(), c_key.as_ptr(), c_value.as_ptr()) };
+
+        match status {
+            NIXL_CAPI_SUCCESS => Ok(()),
+            NIXL_CAPI_ERROR_INVALID_PARAM => Err(NixlError::InvalidParam),
+            _ => Err(NixlError::BackendError),
+        }
+    }
+
+    /// Sets the number of dedicated progress threads the backend starts
+    ///
+    /// Stored as the `num_threads` parameter read by the UCX and object
+    /// backends, so each backend can be tuned independently. UCX raises its
+    /// worker count above the thread count by itself when needed.
+    ///
+    /// # Errors
+    /// * `InvalidParam` - If `threads` is zero
+    pub fn set_progress_threads(&mut self, threads: u32) -> Result<(), NixlError> {
+        if threads == 0 {
+            tracing::error!(target: TRACING_TARGET, "Progress thread count must be at least one");
+            return Err(NixlError::InvalidParam);
+        }
+        self.set(PROGRESS_THREADS_PARAM, &threads.to_string())
+    }
+
+    /// Returns the number of dedicated progress threads, if set
+    pub fn progress_threads(&self) -> Result<Option<u32>, NixlError> {
+        self.get(PROGRESS_THREADS_PARAM)?
+            .map(|threads| threads.parse().map_err(|_| NixlError::InvalidParam))
+            .transpose()
+    }
+
     pub(crate) fn handle(&self) -> *mut bindings::nixl_capi_params_s {
         self.inner.as_ptr()
     }
@@ -68,6 +68,17 @@ impl StringList {
     }
 }
 
+impl<'a> IntoIterator for &'a StringList {
+    type Item = &'a str;
+    type IntoIter = std::iter::FilterMap<StringListIterator<'a>, fn(Result<&'a str, NixlError>) -> Option<&'a str>>;
+
+    /// Iterates over the strings without copying them, skipping entries that
+    /// cannot be read
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter().filter_map(Result::ok as fn(_) -> _)
+    }
+}
+
 impl Drop for StringList {
     fn drop(&mut self) {
         // SAFETY: self.inner is guaranteed to be valid by NonNull
@@ -14,49 +14,494 @@
 // limitations under the License.
 
 use super::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// How often a dropped [`InFlight`] checks whether its transfer completed
+const IN_FLIGHT_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(1);
+
+/// How long a dropped [`InFlight`] waits for its transfer before cancelling it
+const IN_FLIGHT_DROP_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
 
 #[repr(C)]
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum XferOp {
     Read = 0,
     Write = 1,
+    /// A write that completes only once the data is persisted by the target
+    ///
+    /// No backend currently reports durable completion through NIXL, so
+    /// requests using it fail with `Unsupported` instead of silently
+    /// performing a regular write.
+    WriteDurable = 2,
+    /// Atomically adds the operand of [`OptArgs::set_atomic_add`] to a
+    /// registered 64-bit word on the remote side
+    ///
+    /// NIXL only carries reads and writes to backends, so even plugins over
+    /// RDMA-capable transports such as UCX cannot perform remote atomics and
+    /// requests using it fail with `Unsupported`.
+    AtomicAdd = 3,
+    /// Atomically replaces a registered 64-bit word on the remote side if it
+    /// holds the expected operand of [`OptArgs::set_compare_swap`]
+    ///
+    /// Unsupported for the same reason as [`XferOp::AtomicAdd`].
+    CompareSwap = 4,
+}
+
+impl XferOp {
+    /// Returns true for operations that move data to the remote side
+    pub fn is_write(&self) -> bool {
+        matches!(self, XferOp::Write | XferOp::WriteDurable)
+    }
+
+    /// Returns true for remote atomic operations
+    pub fn is_atomic(&self) -> bool {
+        matches!(self, XferOp::AtomicAdd | XferOp::CompareSwap)
+    }
+
+    pub(crate) fn to_capi(self) -> Result<bindings::nixl_capi_xfer_op_t, NixlError> {
+        match self {
+            XferOp::Read => Ok(bindings::nixl_capi_xfer_op_t_NIXL_CAPI_XFER_OP_READ),
+            XferOp::Write => Ok(bindings::nixl_capi_xfer_op_t_NIXL_CAPI_XFER_OP_WRITE),
+            XferOp::WriteDurable => {
+                tracing::error!(target: TRACING_TARGET, operation = ?self, "Durable writes are not supported by any backend");
+                Err(NixlError::Unsupported)
+            }
+            XferOp::AtomicAdd | XferOp::CompareSwap => {
+                tracing::error!(target: TRACING_TARGET, operation = ?self, "Remote atomics are not supported by any backend");
+                Err(NixlError::Unsupported)
+            }
+        }
+    }
 }
 
 /// Methods used for estimating transfer costs
-#[repr(C)]
-#[derive(Debug, Copy, Clone, PartialEq)]
+///
+/// Mirrors the C `nixl_capi_cost_t` values. Values added by newer versions
+/// of the library are preserved as [`CostMethod::Other`].
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum CostMethod {
-    AnalyticalBackend = 0,
-    Unknown = 1,
+    /// Estimated analytically by the backend from its own model
+    AnalyticalBackend,
+    /// A method not known to this version of the bindings
+    Other(u32),
 }
 
 impl From<u32> for CostMethod {
     fn from(value: u32) -> Self {
         match value {
-            0 => CostMethod::AnalyticalBackend,
-            _ => CostMethod::Unknown,
+            bindings::nixl_capi_cost_t_NIXL_CAPI_COST_ANALYTICAL_BACKEND => CostMethod::AnalyticalBackend,
+            other => CostMethod::Other(other),
+        }
+    }
+}
+
+impl fmt::Display for CostMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CostMethod::AnalyticalBackend => write!(f, "analytical backend"),
+            CostMethod::Other(value) => write!(f, "unknown ({value})"),
+        }
+    }
+}
+
+/// Controls how [`Agent::post_xfer_req_retry`] retries failed posts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total number of posts attempted, including the first one
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled for each further retry
+    pub base_delay: std::time::Duration,
+    /// Upper bound for the delay between retries
+    pub max_delay: std::time::Duration,
+}
+
+impl RetryPolicy {
+    /// Returns the delay to wait after the given failed attempt (1-based)
+    pub(crate) fn delay_for(&self, attempt: u32) -> std::time::Duration {
+        let factor = 1u32.checked_shl(attempt.saturating_sub(1)).unwrap_or(u32::MAX);
+        self.base_delay.saturating_mul(factor).min(self.max_delay)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: std::time::Duration::from_millis(10),
+            max_delay: std::time::Duration::from_secs(1),
+        }
+    }
+}
+
+/// Progress of a transfer request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct XferProgress {
+    pub bytes_completed: u64,
+    pub bytes_total: u64,
+}
+
+impl XferProgress {
+    /// Returns the completed fraction of the transfer, between 0.0 and 1.0
+    pub fn fraction(&self) -> f64 {
+        if self.bytes_total == 0 {
+            1.0
+        } else {
+            self.bytes_completed as f64 / self.bytes_total as f64
+        }
+    }
+}
+
+/// Diagnostic snapshot of a transfer request, see [`XferRequest::debug_state`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct XferDebugInfo {
+    /// Backend carrying the transfer, `None` if the agent cannot resolve it
+    pub backend: Option<String>,
+    pub op: XferOp,
+    /// Number of descriptors on each side of the transfer
+    pub desc_count: usize,
+    /// Target agent, `None` for requests created with `make_xfer_req`
+    pub remote_agent: Option<String>,
+    /// Status reported by the NIXL agent, such as `in progress` or
+    /// `failed with status -7`
+    pub backend_status: String,
+}
+
+/// Caller-owned index buffers reused across
+/// [`Agent::make_xfer_req_with_scratch`] calls
+///
+/// Collecting fresh index vectors for every request allocates in loops that
+/// post thousands of transfers per second. Refilling a scratch reuses its
+/// buffers once they have grown to the largest request, so making requests
+/// from prepared lists does not allocate on the Rust side. The NIXL library
+/// still copies the indices into each request it creates.
+#[derive(Debug, Clone, Default)]
+pub struct XferReqScratch {
+    local_indices: Vec<i32>,
+    remote_indices: Vec<i32>,
+}
+
+impl XferReqScratch {
+    /// Creates an empty scratch
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a scratch with room for `capacity` indices per side
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            local_indices: Vec::with_capacity(capacity),
+            remote_indices: Vec::with_capacity(capacity),
         }
     }
+
+    /// Replaces the local and remote indices of the next request
+    pub fn set_indices(
+        &mut self,
+        local_indices: impl IntoIterator<Item = i32>,
+        remote_indices: impl IntoIterator<Item = i32>,
+    ) {
+        self.local_indices.clear();
+        self.local_indices.extend(local_indices);
+        self.remote_indices.clear();
+        self.remote_indices.extend(remote_indices);
+    }
+
+    /// Returns the local indices of the next request
+    pub fn local_indices(&self) -> &[i32] {
+        &self.local_indices
+    }
+
+    /// Returns the remote indices of the next request
+    pub fn remote_indices(&self) -> &[i32] {
+        &self.remote_indices
+    }
 }
 
 /// A handle to a transfer request
+///
+/// Requests are `Send` and `Sync`, so a request posted on one thread can be
+/// moved to a dedicated completion poller or polled from several threads.
+/// Polls only run concurrently on agents created with `ThreadSync::Strict` or
+/// `ThreadSync::Rw`; otherwise the agent serializes them.
+///
+/// A request holds a strong reference to the agent that created it, so the
+/// C agent and its backends are only destroyed once every [`Agent`] handle
+/// and every request is gone. Requests can therefore outlive the handles
+/// used to create them; [`XferRequest::agent`] recovers a handle to keep
+/// polling them.
+#[derive(Debug)]
 pub struct XferRequest {
     inner: NonNull<bindings::nixl_capi_xfer_req_s>,
     agent: Arc<RwLock<AgentInner>>,
+    released: bool,
+    op: XferOp,
+    total_bytes: Option<u64>,
+    /// Set when the request is posted, cleared once its outcome is counted
+    /// in the backend statistics
+    stats_pending: AtomicBool,
+    /// Set once [`Agent::cancel_xfer_req`] aborted the transfer and released
+    /// its NIXL request
+    cancelled: AtomicBool,
+    /// Set once [`Agent::cancel_xfer_req`] failed, leaving the request
+    /// reporting failure while the backend may still use its buffers
+    cancel_failed: AtomicBool,
+    /// Deadline given when the request was created, see [`OptArgs::set_deadline`]
+    default_deadline: Option<std::time::Duration>,
+    /// When the current post is due, set on every post
+    deadline: Mutex<Option<std::time::Instant>>,
+    checksum: Option<checksum::XferChecksum>,
+    desc_count: usize,
+    remote_agent: Option<String>,
+    xfer_tag: Option<u64>,
+    #[cfg(target_os = "linux")]
+    completion: std::sync::OnceLock<Option<completion::CompletionFd>>,
 }
 
 impl XferRequest {
     pub(crate) fn new(
         inner: NonNull<bindings::nixl_capi_xfer_req_s>,
         agent: Arc<RwLock<AgentInner>>,
+        op: XferOp,
+        desc_count: usize,
     ) -> Self {
-        Self { inner, agent }
+        Self {
+            inner,
+            agent,
+            released: false,
+            op,
+            total_bytes: None,
+            stats_pending: AtomicBool::new(false),
+            cancelled: AtomicBool::new(false),
+            cancel_failed: AtomicBool::new(false),
+            default_deadline: None,
+            deadline: Mutex::new(None),
+            checksum: None,
+            desc_count,
+            remote_agent: None,
+            xfer_tag: None,
+            #[cfg(target_os = "linux")]
+            completion: std::sync::OnceLock::new(),
+        }
+    }
+
+    pub(crate) fn with_checksum(mut self, checksum: Option<checksum::XferChecksum>) -> Self {
+        self.checksum = checksum;
+        self
+    }
+
+    pub(crate) fn checksum(&self) -> Option<&checksum::XferChecksum> {
+        self.checksum.as_ref()
+    }
+
+    pub(crate) fn with_deadline(mut self, deadline: Option<std::time::Duration>) -> Self {
+        self.default_deadline = deadline;
+        self
+    }
+
+    pub(crate) fn with_xfer_tag(mut self, tag: Option<u64>) -> Self {
+        self.xfer_tag = tag;
+        self
+    }
+
+    /// Returns the tag its notification carries, if the request was created
+    /// with one, see [`OptArgs::set_xfer_tag`]
+    pub fn xfer_tag(&self) -> Option<u64> {
+        self.xfer_tag
+    }
+
+    /// Returns when the transfer is due to have completed, if it was posted
+    /// with a deadline, see [`OptArgs::set_deadline`]
+    pub fn deadline(&self) -> Option<std::time::Instant> {
+        *self.deadline.lock()
+    }
+
+    /// Starts the deadline clock for a post with `opt_args`
+    pub(crate) fn start_deadline(&self, opt_args: Option<&OptArgs>) {
+        let deadline = opt_args.and_then(OptArgs::deadline).or(self.default_deadline);
+        *self.deadline.lock() = deadline.map(|deadline| std::time::Instant::now() + deadline);
+    }
+
+    pub(crate) fn with_remote_agent(mut self, remote_agent: &str) -> Self {
+        self.remote_agent = Some(remote_agent.to_string());
+        self
+    }
+
+    /// Returns a handle to the agent that created this request
+    ///
+    /// The handle carries no correlation id, even if the request was created
+    /// through a handle that did.
+    pub fn agent(&self) -> Agent {
+        Agent::from_inner(self.agent.clone())
+    }
+
+    /// Returns true if this request was created through `agent`
+    pub(crate) fn belongs_to(&self, agent: &Arc<RwLock<AgentInner>>) -> bool {
+        Arc::ptr_eq(&self.agent, agent)
+    }
+
+    /// Describes the current state of this request for debugging
+    ///
+    /// Unlike [`Agent::get_xfer_status`], this does not count the outcome in
+    /// the backend statistics or otherwise change the request, so it is cheap
+    /// and safe to call repeatedly, including while the transfer is live.
+    /// Backends do not expose their own status strings through NIXL, so
+    /// `backend_status` describes the status reported by the agent.
+    ///
+    /// # Errors
+    /// * `InvalidParam` - If the request was not created through `agent`
+    pub fn debug_state(&self, agent: &Agent) -> Result<XferDebugInfo, NixlError> {
+        agent.xfer_debug_info(self)
+    }
+
+    pub(crate) fn with_total_bytes(mut self, total_bytes: u64) -> Self {
+        self.total_bytes = Some(total_bytes);
+        self
+    }
+
+    pub(crate) fn total_bytes(&self) -> Option<u64> {
+        self.total_bytes
+    }
+
+    pub(crate) fn op(&self) -> XferOp {
+        self.op
+    }
+
+    pub(crate) fn desc_count(&self) -> usize {
+        self.desc_count
+    }
+
+    pub(crate) fn remote_agent(&self) -> Option<&str> {
+        self.remote_agent.as_deref()
+    }
+
+    pub(crate) fn mark_posted(&self) {
+        self.stats_pending.store(true, Ordering::Release);
+    }
+
+    /// Returns true if the outcome of the last post is yet to be counted
+    pub(crate) fn is_stats_pending(&self) -> bool {
+        self.stats_pending.load(Ordering::Acquire)
+    }
+
+    /// Returns true exactly once per post, when the outcome should be counted
+    pub(crate) fn take_stats_pending(&self) -> bool {
+        self.stats_pending.swap(false, Ordering::AcqRel)
+    }
+
+    pub(crate) fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Acquire)
+    }
+
+    pub(crate) fn mark_cancelled(&self) {
+        self.cancelled.store(true, Ordering::Release);
+    }
+
+    pub(crate) fn is_cancel_failed(&self) -> bool {
+        self.cancel_failed.load(Ordering::Acquire)
+    }
+
+    pub(crate) fn mark_cancel_failed(&self) {
+        self.cancel_failed.store(true, Ordering::Release);
     }
 
     pub(crate) fn handle(&self) -> *mut bindings::nixl_capi_xfer_req_s {
         self.inner.as_ptr()
     }
 
+    /// Returns the raw C handle of this request
+    ///
+    /// # Safety
+    /// The handle is valid until the request is released or dropped and must
+    /// not be released or destroyed by the caller. It can only be used
+    /// together with the raw handle of the agent that created it, see
+    /// [`Agent::as_raw`].
+    pub unsafe fn as_raw(&self) -> *mut nixl_capi_xfer_req_s {
+        self.inner.as_ptr()
+    }
+
+    /// Returns a file descriptor that becomes readable once the transfer completes
+    ///
+    /// Backends do not expose completion descriptors, so the first call
+    /// starts a background thread that polls this request and writes to a
+    /// pipe when it leaves the in-progress state, whether it succeeded or
+    /// failed. The descriptor can be registered with `epoll` or any other
+    /// reactor; call [`Agent::get_xfer_status`] once it is readable to get
+    /// the outcome. Call this only after posting the request, since an
+    /// unposted request is not in progress and signals immediately.
+    ///
+    /// The descriptor is non-blocking, stays valid until the request is
+    /// released or dropped and must not be closed by the caller. Only
+    /// available on Linux.
+    ///
+    /// # Returns
+    /// `None` if the pipe or the poller thread could not be created
+    #[cfg(target_os = "linux")]
+    pub fn completion_fd(&self) -> Option<std::os::fd::RawFd> {
+        self.completion
+            .get_or_init(|| completion::CompletionFd::spawn(self.agent.clone(), self.inner).ok())
+            .as_ref()
+            .map(completion::CompletionFd::read_fd)
+    }
+
+    /// Releases the transfer request immediately, consuming it
+    ///
+    /// Requests that are still in flight are not torn down: they are handed
+    /// back together with `XferInProgress`, so they can be polled further.
+    ///
+    /// # Errors
+    /// * `XferInProgress` - If the transfer has not completed yet
+    /// * `BackendError` - If the request could not be released
+    pub fn release(mut self) -> Result<(), (Self, NixlError)> {
+        if self.is_cancelled() {
+            // The NIXL request was already released by the cancellation
+            #[cfg(target_os = "linux")]
+            drop(self.completion.take());
+            unsafe {
+                bindings::nixl_capi_destroy_xfer_req(self.inner.as_ptr());
+            }
+            self.released = true;
+            return Ok(());
+        }
+        let agent = self.agent.clone();
+        let agent_guard = AgentInner::lock_xfer(&agent);
+
+        let status = unsafe {
+            nixl_capi_get_xfer_status(agent_guard.handle.as_ptr(), self.handle(), ptr::null_mut())
+        };
+        match status {
+            NIXL_CAPI_SUCCESS => {}
+            NIXL_CAPI_IN_PROG => {
+                drop(agent_guard);
+                return Err((self, NixlError::XferInProgress));
+            }
+            // A failed transfer has nothing in flight and can be released
+            _ => tracing::debug!(target: TRACING_TARGET, "Releasing transfer request in error state"),
+        }
+
+        // The completion poller uses the handle, so it is stopped first; it
+        // takes the agent lock, so the lock must not be held while joining it
+        #[cfg(target_os = "linux")]
+        let agent_guard = {
+            drop(agent_guard);
+            drop(self.completion.take());
+            agent.read()
+        };
+        agent_guard.untrack_in_flight(&self);
+        let status =
+            unsafe { bindings::nixl_capi_release_xfer_req(agent_guard.handle.as_ptr(), self.handle()) };
+        drop(agent_guard);
+        if status != NIXL_CAPI_SUCCESS {
+            return Err((self, NixlError::BackendError));
+        }
+
+        unsafe {
+            bindings::nixl_capi_destroy_xfer_req(self.inner.as_ptr());
+        }
+        self.released = true;
+        Ok(())
+    }
+
     /// Gets telemetry data for this transfer request
     ///
     /// # Returns
@@ -67,7 +512,7 @@ impl XferRequest {
     /// * `InvalidParam` - If the request handle is invalid
     /// * `BackendError` - If there was an error retrieving telemetry data
     pub fn get_telemetry(&self) -> Result<XferTelemetry, NixlError> {
-        tracing::trace!("Getting transfer telemetry from request");
+        tracing::trace!(target: TRACING_TARGET, "Getting transfer telemetry from request");
         let mut telemetry = bindings::nixl_capi_xfer_telemetry_s {
             start_time_us: 0,
             post_duration_us: 0,
@@ -78,7 +523,7 @@ impl XferRequest {
 
         let status = unsafe {
             nixl_capi_get_xfer_telemetry(
-                self.agent.write().unwrap().handle.as_ptr(),
+                self.agent.write().handle.as_ptr(),
                 self.handle(),
                 &mut telemetry,
             )
@@ -86,7 +531,7 @@ impl XferRequest {
 
         match status {
             NIXL_CAPI_SUCCESS => {
-                tracing::trace!("Successfully retrieved transfer telemetry from request");
+                tracing::trace!(target: TRACING_TARGET, "Successfully retrieved transfer telemetry from request");
                 Ok(XferTelemetry {
                     start_time_us: telemetry.start_time_us,
                     post_duration_us: telemetry.post_duration_us,
@@ -96,35 +541,141 @@ impl XferRequest {
                 })
             },
             NIXL_CAPI_IN_PROG => {
-                tracing::error!(error = "transfer_not_complete", "Transfer not complete");
+                tracing::error!(target: TRACING_TARGET, error = "transfer_not_complete", "Transfer not complete");
                 Err(NixlError::NoTelemetry)
             },
             NIXL_CAPI_ERROR_NO_TELEMETRY => {
-                tracing::error!(error = "telemetry_not_enabled", "Telemetry not enabled");
+                tracing::error!(target: TRACING_TARGET, error = "telemetry_not_enabled", "Telemetry not enabled");
                 Err(NixlError::NoTelemetry)
             },
             _ => {
-                tracing::error!(error = "backend_error", "Failed to get transfer telemetry from request");
+                tracing::error!(target: TRACING_TARGET, error = "backend_error", "Failed to get transfer telemetry from request");
                 Err(NixlError::BackendError)
             }
         }
     }
 }
 
-// SAFETY: XferRequest can be sent between threads safely
+// SAFETY: The request handle is owned by the C++ agent rather than by the
+// thread that created it, and the `Arc` keeps that agent alive for as long
+// as the request exists, so the request can be dropped or polled from any
+// thread.
 unsafe impl Send for XferRequest {}
-// SAFETY: XferRequest can be shared between threads safely
+// SAFETY: Every operation on the handle goes through the owning agent's
+// `RwLock`. Posting and polling only share the read lock when the C++ agent
+// was created with `ThreadSync::Strict` or `ThreadSync::Rw` and serializes
+// them with its own lock; otherwise they take the write lock, see
+// `AgentInner::lock_xfer`. Mutable Rust-side state sits behind atomics and
+// mutexes.
 unsafe impl Sync for XferRequest {}
 
 impl Drop for XferRequest {
     fn drop(&mut self) {
+        if self.released {
+            return;
+        }
+        #[cfg(target_os = "linux")]
+        drop(self.completion.take());
+        let agent_guard = self.agent.write();
+        agent_guard.untrack_in_flight(self);
         unsafe {
-            bindings::nixl_capi_release_xfer_req(
-                self.agent.write().unwrap().handle.as_ptr(),
-                self.inner.as_ptr(),
-            );
+            if !self.is_cancelled() {
+                bindings::nixl_capi_release_xfer_req(agent_guard.handle.as_ptr(), self.inner.as_ptr());
+            }
 
             bindings::nixl_capi_destroy_xfer_req(self.inner.as_ptr());
         }
     }
 }
+
+/// A posted transfer that keeps its buffers borrowed until it completes,
+/// see [`Agent::post_xfer_req_guarded`]
+///
+/// While the guard exists the buffers cannot be accessed, moved or freed.
+/// Dropping it before the transfer completes is best-effort: it waits up to
+/// 10 seconds for the transfer to complete or fail, then cancels it with
+/// [`Agent::cancel_xfer_req`]. If the transfer is still not known to have
+/// ended, because its status could not be checked or it could not be
+/// cancelled, an error is logged and the buffers are handed back although
+/// the backend may still use them. Call [`InFlight::wait`] and check
+/// [`InFlight::is_done`] before dropping the guard to handle that case.
+/// Leaking the guard, e.g. with [`std::mem::forget`], releases the borrow
+/// without waiting and so defeats it.
+#[must_use = "dropping the guard waits for the transfer to complete"]
+#[derive(Debug)]
+pub struct InFlight<'a> {
+    agent: &'a Agent,
+    req: &'a XferRequest,
+    done: bool,
+    _buffers: PhantomData<&'a mut [u8]>,
+}
+
+impl<'a> InFlight<'a> {
+    pub(crate) fn new(agent: &'a Agent, req: &'a XferRequest, done: bool) -> Self {
+        Self {
+            agent,
+            req,
+            done,
+            _buffers: PhantomData,
+        }
+    }
+
+    /// Returns the posted request
+    pub fn request(&self) -> &XferRequest {
+        self.req
+    }
+
+    /// Returns true once the transfer has completed or failed
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    /// Checks the status of the transfer, see [`Agent::get_xfer_status`]
+    pub fn status(&mut self) -> Result<XferStatus, NixlError> {
+        let status = self.agent.get_xfer_status(self.req)?;
+        self.done = status != XferStatus::InProgress && !self.req.is_cancel_failed();
+        Ok(status)
+    }
+
+    /// Waits for the transfer, see [`Agent::wait_xfer`]
+    ///
+    /// The guard can be dropped without blocking once this returns `Ok` or
+    /// `XferFailed`.
+    pub fn wait(
+        &mut self,
+        poll_interval: std::time::Duration,
+        timeout: Option<std::time::Duration>,
+    ) -> Result<(), NixlError> {
+        let result = self.agent.wait_xfer(self.req, poll_interval, timeout);
+        self.done = matches!(result, Ok(()) | Err(NixlError::XferFailed { .. })) && !self.req.is_cancel_failed();
+        result
+    }
+}
+
+impl Drop for InFlight<'_> {
+    fn drop(&mut self) {
+        let deadline = std::time::Instant::now() + IN_FLIGHT_DROP_TIMEOUT;
+        let mut logged = false;
+        while !self.done && !self.req.is_cancel_failed() && std::time::Instant::now() < deadline {
+            match self.status() {
+                Ok(XferStatus::InProgress) => std::thread::sleep(IN_FLIGHT_POLL_INTERVAL),
+                Ok(_) => {}
+                Err(e) => {
+                    if !logged {
+                        tracing::error!(target: TRACING_TARGET, error = ?e, "Failed to check transfer before releasing its buffers");
+                        logged = true;
+                    }
+                    std::thread::sleep(IN_FLIGHT_POLL_INTERVAL);
+                }
+            }
+        }
+        if self.done {
+            return;
+        }
+        if !self.req.is_cancel_failed() && self.agent.cancel_xfer_req(self.req).is_ok() {
+            tracing::warn!(target: TRACING_TARGET, "Cancelled transfer before releasing its buffers");
+            return;
+        }
+        tracing::error!(target: TRACING_TARGET, "Releasing buffers of a transfer that may still be in flight");
+    }
+}
@@ -135,6 +135,12 @@ nixl_capi_get_available_plugins(nixl_capi_agent_t agent, nixl_capi_string_list_t
   return nixl_capi_stub_abort();
 }
 
+nixl_capi_status_t
+nixl_capi_has_plugin(nixl_capi_agent_t agent, const char* plugin_name, bool* has_plugin)
+{
+  return nixl_capi_stub_abort();
+}
+
 nixl_capi_status_t
 nixl_capi_destroy_string_list(nixl_capi_string_list_t list)
 {
@@ -185,6 +191,12 @@ nixl_capi_destroy_backend(nixl_capi_backend_t backend)
   return nixl_capi_stub_abort();
 }
 
+nixl_capi_status_t
+nixl_capi_backend_is_same(nixl_capi_backend_t backend, nixl_capi_backend_t other, bool *same)
+{
+  return nixl_capi_stub_abort();
+}
+
 nixl_capi_status_t
 nixl_capi_create_opt_args(nixl_capi_opt_args_t* args)
 {
@@ -239,6 +251,18 @@ nixl_capi_opt_args_get_skip_desc_merge(nixl_capi_opt_args_t args, bool* skip_mer
   return nixl_capi_stub_abort();
 }
 
+nixl_capi_status_t
+nixl_capi_opt_args_set_include_conn_info(nixl_capi_opt_args_t args, bool include_conn_info)
+{
+  return nixl_capi_stub_abort();
+}
+
+nixl_capi_status_t
+nixl_capi_opt_args_get_include_conn_info(nixl_capi_opt_args_t args, bool* include_conn_info)
+{
+  return nixl_capi_stub_abort();
+}
+
 nixl_capi_status_t
 nixl_capi_opt_args_set_ip_addr(nixl_capi_opt_args_t args, const char *ip_addr) {
     return nixl_capi_stub_abort();
@@ -255,6 +279,12 @@ nixl_capi_params_is_empty(nixl_capi_params_t params, bool* is_empty)
   return nixl_capi_stub_abort();
 }
 
+nixl_capi_status_t
+nixl_capi_params_set(nixl_capi_params_t params, const char* key, const char* value)
+{
+  return nixl_capi_stub_abort();
+}
+
 nixl_capi_status_t
 nixl_capi_params_create_iterator(nixl_capi_params_t params, nixl_capi_param_iter_t* iter)
 {
@@ -404,7 +434,7 @@ nixl_capi_post_xfer_req(nixl_capi_agent_t agent, nixl_capi_xfer_req_t req_hndl,
 }
 
 nixl_capi_status_t
-nixl_capi_get_xfer_status(nixl_capi_agent_t agent, nixl_capi_xfer_req_t req_hndl)
+nixl_capi_get_xfer_status(nixl_capi_agent_t agent, nixl_capi_xfer_req_t req_hndl, int *xfer_err)
 {
   return nixl_capi_stub_abort();
 }
@@ -477,6 +507,13 @@ nixl_capi_notif_map_clear(nixl_capi_notif_map_t map)
   return nixl_capi_stub_abort();
 }
 
+nixl_capi_status_t
+nixl_capi_notif_map_append(
+    nixl_capi_notif_map_t map, const char* agent_name, const void* data, size_t len)
+{
+  return nixl_capi_stub_abort();
+}
+
 nixl_capi_status_t
 nixl_capi_create_query_resp_list(nixl_capi_query_resp_list_t *list) {
     return nixl_capi_stub_abort();
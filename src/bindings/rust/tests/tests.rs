@@ -126,6 +126,20 @@ fn create_posix_backend(agent: &Agent) -> Option<(Backend, OptArgs)> {
     Some((backend, opt_args))
 }
 
+#[test]
+fn test_string_list_into_iter() {
+    let agent = Agent::new("string_list_agent").expect("Failed to create agent");
+    let plugins = agent.get_available_plugins().expect("Failed to get available plugins");
+
+    assert!((&plugins).into_iter().any(|plugin| plugin == "UCX"));
+    let mut count = 0;
+    for plugin in &plugins {
+        assert_eq!(Some(plugin), plugins.get(count).ok());
+        count += 1;
+    }
+    assert_eq!(count, plugins.len().unwrap());
+}
+
 #[test]
 fn create_agent_with_custom_config() {
     // Ensure we can construct with non-default config
@@ -143,12 +157,71 @@ fn create_agent_with_custom_config() {
     let _plugins = agent.get_available_plugins().expect("Failed to get plugins");
 }
 
+#[test]
+fn test_duplicate_agent_name() {
+    // Names may be reused unless the new agent asks for a unique one
+    let agent = Agent::new("duplicate_agent").unwrap();
+    let clone = agent.clone();
+    let twin = Agent::new("duplicate_agent").unwrap();
+    assert_eq!(twin.name(), agent.name());
+    let unique = AgentConfig {
+        unique_name: true,
+        ..Default::default()
+    };
+    assert!(matches!(
+        Agent::new_configured("duplicate_agent", &unique),
+        Err(NixlError::DuplicateAgentName(name)) if name == "duplicate_agent"
+    ));
+
+    // The name is free again once every handle to every such agent is gone
+    drop(agent);
+    drop(twin);
+    assert!(Agent::new_configured("duplicate_agent", &unique).is_err());
+    drop(clone);
+    let _reused = Agent::new_configured("duplicate_agent", &unique).expect("Name should be free after drop");
+}
+
 #[test]
 fn test_agent_creation() {
     let agent = Agent::new("test_agent").expect("Failed to create agent");
     drop(agent);
 }
 
+#[test]
+fn test_agent_name_accessors() {
+    let agent = Agent::new("named_agent").expect("Failed to create agent");
+    assert_eq!(agent.name(), "named_agent");
+
+    let name = agent.name_arc();
+    assert_eq!(&*name, "named_agent");
+    assert!(std::sync::Arc::ptr_eq(&name, &agent.name_arc()));
+
+    assert_eq!(agent.with_name(|name| name.len()), "named_agent".len());
+}
+
+#[test]
+fn test_raw_handles() {
+    let (agent1, opt_args1) = create_agent_with_backend("raw_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("raw_agent2").unwrap();
+    let mut local_storage = create_storage_list(&agent1, &opt_args1, 1);
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 1);
+    exchange_metadata(&agent1, &agent2).unwrap();
+    let local_dlist = create_dlist(&mut local_storage).unwrap();
+    let remote_dlist = create_dlist(&mut remote_storage).unwrap();
+    let req = agent1
+        .create_xfer_req(XferOp::Write, &local_dlist, &remote_dlist, "raw_agent2", None)
+        .unwrap();
+    let backend = agent1.get_backend("UCX").expect("Backend should exist");
+
+    unsafe {
+        assert!(!agent1.as_raw().is_null());
+        assert_eq!(agent1.clone().as_raw(), agent1.as_raw());
+        assert_ne!(agent1.as_raw(), agent2.as_raw());
+        assert!(!backend.as_raw().is_null());
+        assert!(!req.as_raw().is_null());
+    }
+}
+
 #[test]
 fn test_agent_invalid_name() {
     let result = Agent::new("test\0agent");
@@ -168,6 +241,23 @@ fn test_get_available_plugins() {
     }
 }
 
+#[test]
+fn test_has_plugin() {
+    let agent = Agent::new("test_agent").expect("Failed to create agent");
+    let plugins = agent
+        .get_available_plugins()
+        .expect("Failed to get plugins");
+
+    for plugin in plugins.iter() {
+        assert!(agent.has_plugin(plugin.unwrap()).unwrap());
+    }
+    assert!(!agent.has_plugin("NOT_A_PLUGIN").unwrap());
+    assert!(matches!(
+        agent.has_plugin("UC\0X"),
+        Err(NixlError::InvalidParam)
+    ));
+}
+
 #[test]
 fn test_get_plugin_params() {
     let agent = Agent::new("test_agent").expect("Failed to create agent");
@@ -193,6 +283,126 @@ fn test_backend_creation() {
         .expect("Failed to add backend");
 }
 
+#[test]
+fn test_backend_accessors() {
+    let agent = Agent::new("backend_accessor_agent").expect("Failed to create agent");
+    let (_mems, params) = agent
+        .get_plugin_params("UCX")
+        .expect("Failed to get plugin params");
+    let backend = agent
+        .create_backend("UCX", &params)
+        .expect("Failed to create backend");
+    assert_eq!(backend.plugin_name(), "UCX");
+    assert_eq!(agent.get_backend("UCX").unwrap().plugin_name(), "UCX");
+
+    let supported = backend
+        .supported_mem_types(&agent)
+        .expect("Failed to get supported memory types");
+    assert!(supported.contains(&MemType::Dram));
+}
+
+#[test]
+fn test_backend_limits() {
+    let agent = Agent::new("backend_limits_agent").expect("Failed to create agent");
+    let (_mems, params) = agent.get_plugin_params("UCX").expect("Failed to get plugin params");
+    let backend = agent.create_backend("UCX", &params).expect("Failed to create backend");
+    assert_eq!(backend.limits(&agent).unwrap(), BackendLimits::default());
+
+    // Limits are read back from the parameters the backend was created with
+    let other = Agent::new("backend_limits_other").expect("Failed to create agent");
+    let (_mems, mut params) = other.get_plugin_params("UCX").expect("Failed to get plugin params");
+    params.set("max_descriptors", "64").unwrap();
+    params.set("max_xfer_bytes", "1048576").unwrap();
+    let backend = other.create_backend("UCX", &params).expect("Failed to create backend");
+    assert_eq!(
+        backend.limits(&other).unwrap(),
+        BackendLimits {
+            max_descriptors: Some(64),
+            max_xfer_bytes: Some(1 << 20),
+            max_inflight_requests: None,
+        }
+    );
+}
+
+#[test]
+fn test_backend_is_remote_capable() {
+    let agent = Agent::new("remote_capable_agent").expect("Failed to create agent");
+    let (_mems, params) = agent.get_plugin_params("UCX").expect("Failed to get plugin params");
+    let backend = agent.create_backend("UCX", &params).expect("Failed to create backend");
+    assert!(backend.is_remote_capable(&agent).unwrap());
+
+    if let Some((posix, _opt_args)) = create_posix_backend(&agent) {
+        assert!(!posix.is_remote_capable(&agent).unwrap());
+    }
+
+    drop(agent);
+    let other = Agent::new("remote_capable_other").expect("Failed to create agent");
+    assert!(matches!(
+        backend.is_remote_capable(&other),
+        Err(NixlError::AgentDropped)
+    ));
+}
+
+#[test]
+fn test_opt_args_builder() {
+    let agent = Agent::new("test_agent").expect("Failed to create agent");
+    let (_mems, params) = agent
+        .get_plugin_params("UCX")
+        .expect("Failed to get plugin params");
+    let backend = agent
+        .create_backend("UCX", &params)
+        .expect("Failed to create backend");
+
+    let opt_args = OptArgs::builder()
+        .backend(&backend)
+        .notification_msg(b"done")
+        .skip_descriptor_merge(true)
+        .build()
+        .expect("Failed to build opt args");
+    assert!(opt_args.has_notification().unwrap());
+    assert_eq!(opt_args.get_notification_message().unwrap(), b"done");
+    assert!(opt_args.skip_descriptor_merge().unwrap());
+
+    // A message with notification explicitly disabled is contradictory
+    let result = OptArgs::builder()
+        .notification_msg(b"done")
+        .has_notification(false)
+        .build();
+    assert!(matches!(result, Err(NixlError::InvalidParam)));
+}
+
+#[test]
+fn test_xfer_priority() {
+    let (agent1, opt_args1) = create_agent_with_backend("priority_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("priority_agent2").unwrap();
+    let mut local_storage = create_storage_list(&agent1, &opt_args1, 1);
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 1);
+    exchange_metadata(&agent1, &agent2).unwrap();
+    let local_dlist = create_dlist(&mut local_storage).unwrap();
+    let remote_dlist = create_dlist(&mut remote_storage).unwrap();
+
+    let mut opt_args = OptArgs::new().unwrap();
+    assert_eq!(opt_args.priority(), Priority::Normal);
+    agent1
+        .create_xfer_req(XferOp::Write, &local_dlist, &remote_dlist, "priority_agent2", Some(&opt_args))
+        .expect("The default priority should be accepted");
+
+    // No backend honors priorities, so requesting one is rejected
+    opt_args.set_priority(Priority::High);
+    assert!(matches!(
+        agent1.create_xfer_req(XferOp::Write, &local_dlist, &remote_dlist, "priority_agent2", Some(&opt_args)),
+        Err(NixlError::Unsupported)
+    ));
+    let opt_args = OptArgs::builder().priority(Priority::Low).build().unwrap();
+    assert_eq!(opt_args.priority(), Priority::Low);
+    let local_handle = agent1.prepare_xfer_dlist("", &local_dlist, None).unwrap();
+    let remote_handle = agent1.prepare_xfer_dlist("priority_agent2", &remote_dlist, None).unwrap();
+    assert!(matches!(
+        agent1.make_xfer_req(XferOp::Write, &local_handle, &[0], &remote_handle, &[0], Some(&opt_args)),
+        Err(NixlError::Unsupported)
+    ));
+}
+
 #[test]
 fn test_params_iteration() {
     let agent = Agent::new("test_agent").expect("Failed to create agent");
@@ -220,6 +430,93 @@ fn test_params_iteration() {
     }
 }
 
+#[test]
+fn test_params_set_get() {
+    let agent = Agent::new("test_agent").expect("Failed to create agent");
+    let (_mems, mut params) = agent
+        .get_plugin_params("UCX")
+        .expect("Failed to get plugin params");
+
+    assert_eq!(params.get("test_key").unwrap(), None);
+    params.set("test_key", "test_value").unwrap();
+    assert_eq!(params.get("test_key").unwrap(), Some("test_value".to_string()));
+
+    // Overwriting a key keeps a single entry with the new value
+    let count = params.iter().unwrap().count();
+    params.set("test_key", "other_value").unwrap();
+    assert_eq!(params.iter().unwrap().count(), count);
+    assert_eq!(params.get("test_key").unwrap(), Some("other_value".to_string()));
+
+    assert!(matches!(
+        params.set("bad\0key", "value"),
+        Err(NixlError::StringConversionError(_))
+    ));
+}
+
+#[test]
+fn test_params_progress_threads() {
+    let agent1 = Agent::new("progress_threads_agent1").expect("Failed to create agent");
+    let agent2 = Agent::new("progress_threads_agent2").expect("Failed to create agent");
+    let (_mems, mut params1) = agent1.get_plugin_params("UCX").unwrap();
+    let (_mems, mut params2) = agent2.get_plugin_params("UCX").unwrap();
+
+    assert!(matches!(params1.set_progress_threads(0), Err(NixlError::InvalidParam)));
+    params1.set("num_workers", "4").unwrap();
+    params1.set_progress_threads(1).unwrap();
+    assert_eq!(params1.progress_threads().unwrap(), Some(1));
+
+    // UCX raises the worker count above the thread count itself
+    params2.set("num_workers", "4").unwrap();
+    params2.set_progress_threads(4).unwrap();
+    assert_eq!(params2.progress_threads().unwrap(), Some(4));
+
+    // Backends in the same process run with different thread counts
+    agent1.create_backend("UCX", &params1).expect("Failed to create backend");
+    agent2.create_backend("UCX", &params2).expect("Failed to create backend");
+}
+
+#[test]
+fn test_mem_type_conversions() {
+    for mem_type in [
+        MemType::Dram,
+        MemType::Vram,
+        MemType::Block,
+        MemType::Object,
+        MemType::File,
+        MemType::Unknown,
+    ] {
+        let raw: u32 = mem_type.into();
+        assert_eq!(MemType::from(raw), mem_type);
+    }
+    assert_eq!(MemType::from(42u32), MemType::Unknown);
+}
+
+#[test]
+fn test_mem_list_contains() {
+    let agent = Agent::new("test_agent").expect("Failed to create agent");
+    let (mems, _params) = agent
+        .get_plugin_params("UCX")
+        .expect("Failed to get plugin params");
+
+    assert!(mems.contains(MemType::Dram).unwrap());
+    assert!(!mems.contains(MemType::Unknown).unwrap());
+}
+
+#[test]
+fn test_mem_list_to_vec() {
+    let agent = Agent::new("test_agent").expect("Failed to create agent");
+    let (mems, _params) = agent
+        .get_plugin_params("UCX")
+        .expect("Failed to get plugin params");
+
+    let mem_types = mems.to_vec().expect("Failed to collect memory types");
+    assert_eq!(mem_types.len(), mems.len().unwrap());
+
+    // Iterating again yields the same memory types
+    let again: Vec<MemType> = mems.iter().map(Result::unwrap).collect();
+    assert_eq!(mem_types, again);
+}
+
 // #[test]
 // fn test_get_backend_params() {
 //     let agent = Agent::new("test_agent").unwrap();
@@ -295,6 +592,28 @@ fn test_xfer_dlist() {
     dlist.resize(5).unwrap();
 }
 
+#[test]
+fn test_xfer_dlist_capacity() {
+    let mut dlist = XferDescList::with_capacity(MemType::Dram, 64).unwrap();
+    assert!(dlist.is_empty().unwrap());
+    assert!(dlist.capacity().unwrap() >= 64);
+
+    for i in 0..64 {
+        dlist.add_desc(0x1000 * (i + 1), 0x100, 0).unwrap();
+    }
+    assert_eq!(dlist.len().unwrap(), 64);
+
+    dlist.reserve(32).unwrap();
+    assert!(dlist.capacity().unwrap() >= 96);
+
+    // Reserving does not disturb descriptors already in the list
+    let mut expected = XferDescList::new(MemType::Dram).unwrap();
+    for i in 0..64 {
+        expected.add_desc(0x1000 * (i + 1), 0x100, 0).unwrap();
+    }
+    assert_eq!(dlist, expected);
+}
+
 #[test]
 fn test_reg_dlist() {
     let mut dlist = RegDescList::new(MemType::Dram).unwrap();
@@ -314,6 +633,69 @@ fn test_reg_dlist() {
     dlist.resize(5).unwrap();
 }
 
+#[test]
+fn test_dlist_conversions() {
+    let storage = SystemStorage::new(1024).unwrap();
+    let mut reg_dlist = RegDescList::new(MemType::Dram).unwrap();
+    reg_dlist.add_storage_desc(&storage).unwrap();
+    reg_dlist.add_desc_with_meta(0x2000, 0x200, 1, b"meta").unwrap();
+
+    let xfer_dlist = reg_dlist.to_xfer_dlist().unwrap();
+    let mut expected = XferDescList::new(MemType::Dram).unwrap();
+    expected.add_storage_desc(&storage).unwrap();
+    expected.add_desc(0x2000, 0x200, 1).unwrap();
+    assert_eq!(xfer_dlist, expected);
+
+    // Metadata is not carried back from the transfer list
+    let round_trip = xfer_dlist.to_reg_dlist().unwrap();
+    let mut expected = RegDescList::new(MemType::Dram).unwrap();
+    expected.add_storage_desc(&storage).unwrap();
+    expected.add_desc(0x2000, 0x200, 1).unwrap();
+    assert_eq!(round_trip, expected);
+    assert_ne!(round_trip, reg_dlist);
+
+    let empty = RegDescList::new(MemType::Vram).unwrap().to_xfer_dlist().unwrap();
+    assert!(empty.is_empty().unwrap());
+    assert_eq!(empty.get_type().unwrap(), MemType::Vram);
+}
+
+#[test]
+fn test_xfer_dlist_validate_no_overlap() {
+    let mut dlist = XferDescList::new(MemType::Dram).unwrap();
+    dlist.validate_no_overlap().unwrap();
+
+    // Adjacent ranges, the same range on another device and empty descriptors are fine
+    dlist.add_desc(0x2000, 0x100, 0).unwrap();
+    dlist.add_desc(0x1000, 0x1000, 0).unwrap();
+    dlist.add_desc(0x1000, 0x1000, 1).unwrap();
+    dlist.add_desc(0x1800, 0, 0).unwrap();
+    dlist.validate_no_overlap().unwrap();
+
+    dlist.add_desc(0x20ff, 0x10, 0).unwrap();
+    assert!(matches!(
+        dlist.validate_no_overlap(),
+        Err(NixlError::OverlappingDescriptors { first: 0, second: 4 })
+    ));
+
+    // A range nested in another one overlaps as well
+    let mut nested = XferDescList::new(MemType::Dram).unwrap();
+    nested.add_desc(0x1000, 0x1000, 0).unwrap();
+    nested.add_desc(0x1400, 0x10, 0).unwrap();
+    assert!(matches!(
+        nested.validate_no_overlap(),
+        Err(NixlError::OverlappingDescriptors { first: 0, second: 1 })
+    ));
+
+    // A range wrapping around the address space does not overflow the check
+    let mut wrapping = XferDescList::new(MemType::Dram).unwrap();
+    wrapping.add_desc(usize::MAX - 0x10, 0x100, 0).unwrap();
+    wrapping.add_desc(usize::MAX - 0x8, 0x1, 0).unwrap();
+    assert!(matches!(
+        wrapping.validate_no_overlap(),
+        Err(NixlError::OverlappingDescriptors { first: 0, second: 1 })
+    ));
+}
+
 #[test]
 fn test_storage_descriptor_lifetime() {
     // Create storage that outlives the descriptor list
@@ -345,9 +727,178 @@ fn test_multiple_storage_descriptors() {
     assert_eq!(dlist.len().unwrap(), 2);
 }
 
+#[test]
+fn test_dram_descriptor() {
+    let agent = Agent::new("dram_desc_agent").expect("Failed to create agent");
+    let _opt_args = setup_agent_with_backend(&agent).expect("Failed to setup agent with backend");
+
+    let buffer = vec![0u8; 1024];
+    let desc = unsafe { DramDescriptor::new(buffer.as_ptr(), buffer.len()) }.unwrap();
+    assert_eq!(desc.mem_type(), MemType::Dram);
+    assert_eq!(desc.size(), 1024);
+    let _handle = agent
+        .register_memory(&desc, None)
+        .expect("Failed to register DRAM descriptor");
+
+    let result = unsafe { DramDescriptor::new(std::ptr::null(), 1024) };
+    assert!(matches!(result, Err(NixlError::InvalidParam)));
+}
+
+#[test]
+fn test_dram_descriptor_from_slice_transfer() {
+    let agent1 = Agent::new("slice_agent1").expect("Failed to create agent");
+    let agent2 = Agent::new("slice_agent2").expect("Failed to create agent");
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut source = vec![0x5au8; 512];
+    let mut target = vec![0u8; 512];
+    {
+        let source_desc = DramDescriptor::from_slice(&mut source, 0);
+        let target_desc = DramDescriptor::from_slice(&mut target, 0);
+        assert_eq!(source_desc.size(), 512);
+
+        let _source_handle = agent1.register_memory(&source_desc, None).unwrap();
+        let _target_handle = agent2.register_memory(&target_desc, None).unwrap();
+        let remote_name = agent1.load_remote_md(&agent2.get_local_md().unwrap()).unwrap();
+
+        let mut local_dlist = XferDescList::new(MemType::Dram).unwrap();
+        local_dlist.add_storage_desc(&source_desc).unwrap();
+        let mut remote_dlist = XferDescList::new(MemType::Dram).unwrap();
+        remote_dlist.add_storage_desc(&target_desc).unwrap();
+
+        let req = agent1
+            .create_xfer_req(XferOp::Write, &local_dlist, &remote_dlist, &remote_name, None)
+            .unwrap();
+        agent1.post_xfer_req(&req, None).unwrap();
+        while !agent1.get_xfer_status(&req).unwrap().is_success() {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+    }
+
+    // The buffers are usable again once the descriptors are gone
+    assert!(target.iter().all(|&x| x == 0x5a));
+}
+
+#[test]
+fn test_vram_descriptor_device_validation() {
+    let buffer = vec![0u8; 64];
+    let num_devices = cuda_device_count() as u64;
+
+    // A device ordinal past the visible devices must be rejected
+    let result = unsafe { VramDescriptor::new(buffer.as_ptr(), buffer.len(), num_devices) };
+    assert!(matches!(result, Err(NixlError::InvalidParam)));
+
+    if num_devices > 0 {
+        let desc = unsafe { VramDescriptor::new(buffer.as_ptr(), buffer.len(), num_devices - 1) }
+            .expect("Failed to create VRAM descriptor");
+        assert_eq!(desc.mem_type(), MemType::Vram);
+        assert_eq!(desc.device_id(), num_devices - 1);
+    }
+}
+
+#[test]
+fn test_strided_descriptor() {
+    // Column 2 of a 16x16 matrix of u32
+    let matrix = vec![0u8; 16 * 16 * 4];
+    let base = unsafe { matrix.as_ptr().add(2 * 4) };
+    let column = unsafe { StridedDescriptor::new(base, 4, 16, 16 * 4, 0, MemType::Dram) }.unwrap();
+    assert_eq!(column.size(), 15 * 64 + 4);
+    assert!(!column.is_contiguous());
+
+    let mut dlist = XferDescList::new(MemType::Dram).unwrap();
+    dlist.add_storage_desc(&column).unwrap();
+    assert_eq!(dlist.len().unwrap(), 16);
+    for (row, desc) in column.xfer_segments().unwrap().iter().enumerate() {
+        assert_eq!(desc.addr, base as usize + row * 64);
+        assert_eq!(desc.len, 4);
+    }
+
+    // Adjacent elements collapse into a single descriptor
+    let rows = unsafe { StridedDescriptor::new(matrix.as_ptr(), 64, 4, 64, 0, MemType::Dram) }.unwrap();
+    let mut dlist = XferDescList::new(MemType::Dram).unwrap();
+    dlist.add_storage_desc(&rows).unwrap();
+    assert_eq!(dlist.len().unwrap(), 1);
+    assert_eq!(
+        rows.xfer_segments().unwrap(),
+        vec![XferDescriptor { addr: matrix.as_ptr() as usize, len: 256, dev_id: 0 }]
+    );
+
+    // Registration covers the whole span
+    let agent = Agent::new("strided_agent").unwrap();
+    let opt_args = setup_agent_with_backend(&agent).unwrap();
+    let handle = agent.register_memory(&column, Some(&opt_args)).unwrap();
+    assert_eq!(handle.as_xfer_desc().len, column.size());
+
+    let overlapping = unsafe { StridedDescriptor::new(base, 8, 4, 4, 0, MemType::Dram) };
+    assert!(matches!(overlapping, Err(NixlError::InvalidParam)));
+    let null = unsafe { StridedDescriptor::new(std::ptr::null(), 4, 4, 8, 0, MemType::Dram) };
+    assert!(matches!(null, Err(NixlError::InvalidParam)));
+}
+
+#[test]
+fn test_desc_key() {
+    use std::collections::HashMap;
+
+    let mut buffer = vec![0u8; 4096];
+    let ptr = buffer.as_ptr();
+    let dram = unsafe { DramDescriptor::new(ptr, 4096) }.unwrap();
+    let same = unsafe { DramDescriptor::new(ptr, 4096) }.unwrap();
+    let shorter = unsafe { DramDescriptor::new(ptr, 2048) }.unwrap();
+    assert_eq!(dram, same);
+    assert_ne!(dram, shorter);
+    assert_eq!(DescKey::new(&dram), DescKey::from(&same));
+    assert_ne!(DescKey::new(&dram), DescKey::new(&shorter));
+
+    // The same address in another memory type is another region
+    let strided = unsafe { StridedDescriptor::new(ptr, 4096, 1, 4096, 0, MemType::Vram) }.unwrap();
+    assert_eq!(strided.size(), 4096);
+    assert_ne!(DescKey::new(&dram), DescKey::new(&strided));
+
+    let agent = Agent::new("desc_key_agent").unwrap();
+    let opt_args = setup_agent_with_backend(&agent).unwrap();
+    let mut handles: HashMap<DescKey, RegistrationHandle> = HashMap::new();
+    for desc in [&dram, &same] {
+        handles
+            .entry(DescKey::new(desc))
+            .or_insert_with(|| agent.register_memory(desc, Some(&opt_args)).unwrap());
+    }
+    assert_eq!(handles.len(), 1);
+    assert_eq!(agent.registrations().len(), 1);
+
+    let slice = DramDescriptor::from_slice(&mut buffer, 0);
+    assert_eq!(DescKey::new(&slice), handles.keys().next().copied().unwrap());
+    drop(slice);
+    drop(handles);
+}
+
+#[test]
+fn test_raw_descriptor() {
+    let buffer = vec![0u8; 2048];
+    let addr = buffer.as_ptr() as usize;
+    let raw = RawDescriptor::try_from((addr, buffer.len(), 0, MemType::Dram)).unwrap();
+    assert_eq!(raw.size(), 2048);
+    assert_eq!(raw.mem_type(), MemType::Dram);
+    assert_eq!(DescKey::new(&raw), DescKey { addr, size: 2048, dev_id: 0, mem_type: MemType::Dram });
+
+    let agent = Agent::new("raw_desc_agent").unwrap();
+    let opt_args = setup_agent_with_backend(&agent).unwrap();
+    let handle = agent.register_memory(&raw, Some(&opt_args)).unwrap();
+    assert_eq!(handle.as_xfer_desc().addr, addr);
+    let mut dlist = XferDescList::new(MemType::Dram).unwrap();
+    dlist.add_storage_desc(&raw).unwrap();
+    assert_eq!(dlist.len().unwrap(), 1);
+    drop(handle);
+
+    assert!(matches!(RawDescriptor::try_from((0, 2048, 0, MemType::Dram)), Err(NixlError::InvalidParam)));
+    assert!(matches!(RawDescriptor::try_from((addr, 0, 0, MemType::Dram)), Err(NixlError::InvalidParam)));
+}
+
 #[test]
 fn test_memory_registration() {
     let agent = Agent::new("test_agent").unwrap();
+    setup_agent_with_backend(&agent).unwrap();
     let mut storage = SystemStorage::new(1024).unwrap();
 
     // Register memory
@@ -361,6 +912,7 @@ fn test_memory_registration() {
 #[test]
 fn test_registration_handle_drop() {
     let agent = Agent::new("test_agent").unwrap();
+    setup_agent_with_backend(&agent).unwrap();
     let mut storage = SystemStorage::new(1024).unwrap();
 
     // Register memory
@@ -374,9 +926,330 @@ fn test_registration_handle_drop() {
     new_storage.register(&agent, None).unwrap();
 }
 
+#[test]
+fn test_registration_handle_deregister() {
+    let agent = Agent::new("test_agent").unwrap();
+    setup_agent_with_backend(&agent).unwrap();
+    let storage = SystemStorage::new(1024).unwrap();
+
+    // Explicitly deregister, the handle's drop must not deregister again
+    let handle = agent.register_memory(&storage, None).unwrap();
+    handle.deregister().expect("Failed to deregister memory");
+
+    // The same region can be registered again afterwards
+    let handle = agent.register_memory(&storage, None).unwrap();
+    assert_eq!(handle.agent_name(), Some("test_agent".to_string()));
+}
+
+#[test]
+fn test_register_memory_without_backend() {
+    let agent = Agent::new("reg_no_backend_agent").unwrap();
+    let storage = SystemStorage::new(1024).unwrap();
+
+    // NIXL has no backend for the memory type, so nothing is registered
+    assert!(matches!(agent.register_memory(&storage, None), Err(NixlError::BackendError)));
+    assert!(agent.registrations().is_empty());
+}
+
+#[test]
+fn test_register_memory_tagged() {
+    let agent = Agent::new("tagged_agent").unwrap();
+    let opt_args = setup_agent_with_backend(&agent).unwrap();
+    let storage1 = SystemStorage::new(1024).unwrap();
+    let storage2 = SystemStorage::new(1024).unwrap();
+
+    let by_id = agent
+        .register_memory_tagged(&storage1, 42u64, Some(&opt_args))
+        .expect("Failed to register tagged memory");
+    let by_name = agent
+        .register_memory_tagged(&storage2, "kv-cache", Some(&opt_args))
+        .expect("Failed to register tagged memory");
+    assert_eq!(by_id.tag(), Some(&RegistrationTag::Id(42)));
+    assert_eq!(by_name.tag(), Some(&RegistrationTag::Name("kv-cache".to_string())));
+    assert_eq!(by_name.tag().unwrap().to_string(), "kv-cache");
+
+    drop(by_id);
+    let untagged = agent.register_memory(&storage1, Some(&opt_args)).unwrap();
+    assert_eq!(untagged.tag(), None);
+}
+
+#[test]
+fn test_agent_registrations() {
+    let agent = Agent::new("registrations_agent").unwrap();
+    let opt_args = setup_agent_with_backend(&agent).unwrap();
+    let storage1 = SystemStorage::new(1024).unwrap();
+    let storage2 = SystemStorage::new(2048).unwrap();
+    assert!(agent.registrations().is_empty());
+
+    let untagged = agent.register_memory(&storage1, Some(&opt_args)).unwrap();
+    let tagged = agent
+        .register_memory_tagged(&storage2, "kv-cache", Some(&opt_args))
+        .unwrap();
+    let regions = agent.registrations();
+    assert_eq!(
+        regions,
+        vec![
+            RegistrationInfo {
+                addr: storage1.as_slice().as_ptr() as usize,
+                size: 1024,
+                dev_id: 0,
+                mem_type: MemType::Dram,
+                tag: None,
+            },
+            RegistrationInfo {
+                addr: storage2.as_slice().as_ptr() as usize,
+                size: 2048,
+                dev_id: 0,
+                mem_type: MemType::Dram,
+                tag: Some(RegistrationTag::Name("kv-cache".to_string())),
+            },
+        ]
+    );
+
+    drop(untagged);
+    let regions = agent.registrations();
+    assert_eq!(regions.len(), 1);
+    assert_eq!(regions[0].size, 2048);
+
+    agent.deregister_all().unwrap();
+    assert!(agent.registrations().is_empty());
+    drop(tagged);
+}
+
+#[test]
+fn test_alloc_registered() {
+    let agent = Agent::new("alloc_registered_agent").unwrap();
+    setup_agent_with_backend(&agent).unwrap();
+
+    let mut buffer = agent.alloc_registered(8192, 4096, MemType::Dram).unwrap();
+    assert_eq!(buffer.len(), 8192);
+    assert_eq!(buffer.align(), 4096);
+    assert_eq!(buffer[..].as_ptr() as usize % 4096, 0);
+    assert!(buffer.iter().all(|&b| b == 0));
+    buffer.fill(0xab);
+    assert_eq!(buffer[8191], 0xab);
+
+    let regions = agent.registrations();
+    assert_eq!(regions.len(), 1);
+    assert_eq!(regions[0].addr, buffer[..].as_ptr() as usize);
+    assert_eq!(regions[0].size, 8192);
+
+    let mut dlist = XferDescList::new(MemType::Dram).unwrap();
+    dlist.add_storage_desc(&buffer).unwrap();
+    assert_eq!(dlist.len().unwrap(), 1);
+
+    drop(buffer);
+    assert!(agent.registrations().is_empty());
+
+    assert!(matches!(agent.alloc_registered(4096, 3, MemType::Dram), Err(NixlError::InvalidParam)));
+    assert!(matches!(agent.alloc_registered(0, 4096, MemType::Dram), Err(NixlError::InvalidParam)));
+    assert!(matches!(agent.alloc_registered(4096, 4096, MemType::Vram), Err(NixlError::Unsupported)));
+}
+
+#[test]
+fn test_registration_handle_as_xfer_desc() {
+    let agent = Agent::new("xfer_desc_agent").expect("Failed to create agent");
+    let opt_args = setup_agent_with_backend(&agent).expect("Failed to setup agent with backend");
+    let storage = SystemStorage::new(1024).expect("Failed to create storage");
+    let handle = agent
+        .register_memory(&storage, Some(&opt_args))
+        .expect("Failed to register memory");
+
+    let desc = handle.as_xfer_desc();
+    assert_eq!(desc.addr, storage.as_slice().as_ptr() as usize);
+    assert_eq!(desc.len, 1024);
+    assert_eq!(desc.dev_id, 0);
+
+    let mut dlist = XferDescList::new(MemType::Dram).unwrap();
+    dlist.add_registration(&handle).expect("Failed to add registration");
+    assert_eq!(dlist.len().unwrap(), 1);
+
+    // The region's memory type must match the list
+    let mut vram_dlist = XferDescList::new(MemType::Vram).unwrap();
+    assert!(matches!(
+        vram_dlist.add_registration(&handle),
+        Err(NixlError::InvalidParam)
+    ));
+}
+
+#[test]
+fn test_create_backends() {
+    let agent = Agent::new("create_backends_agent").expect("Failed to create agent");
+    let (_mems, ucx_params) = agent.get_plugin_params("UCX").expect("Failed to get plugin params");
+    let (_mems, bogus_params) = agent.get_plugin_params("UCX").expect("Failed to get plugin params");
+    let specs = vec![
+        ("UCX".to_string(), ucx_params),
+        ("NoSuchPlugin".to_string(), bogus_params),
+    ];
+
+    match agent.create_backends(&specs) {
+        Err(NixlError::BackendAtIndex { index, plugin, .. }) => {
+            assert_eq!(index, 1);
+            assert_eq!(plugin, "NoSuchPlugin");
+        }
+        other => panic!("Expected BackendAtIndex, got {other:?}"),
+    }
+
+    let other = Agent::new("create_backends_best_effort").expect("Failed to create agent");
+    let (backends, failures) = other.create_backends_best_effort(&specs);
+    assert_eq!(backends.len(), 1);
+    assert_eq!(backends[0].plugin_name(), "UCX");
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].0, 1);
+}
+
+#[test]
+fn test_backend_outliving_agent() {
+    let agent = Agent::new("backend_owner").expect("Failed to create agent");
+    let (_mems, params) = agent.get_plugin_params("UCX").expect("Failed to get plugin params");
+    let backend = agent.create_backend("UCX", &params).expect("Failed to create backend");
+    drop(agent);
+
+    let mut opt_args = OptArgs::new().expect("Failed to create opt args");
+    assert!(matches!(opt_args.add_backend(&backend), Err(NixlError::AgentDropped)));
+
+    let other = Agent::new("backend_other").expect("Failed to create agent");
+    assert!(matches!(other.get_backend_params(&backend), Err(NixlError::AgentDropped)));
+    assert_eq!(backend.plugin_name(), "UCX");
+}
+
+#[test]
+fn test_list_backends() {
+    let agent = Agent::new("list_backends_agent").expect("Failed to create agent");
+    assert!(agent.backend_names().is_empty());
+    assert!(agent.backends().is_empty());
+
+    let (_mems, params) = agent.get_plugin_params("UCX").expect("Failed to get plugin params");
+    agent.create_backend("UCX", &params).expect("Failed to create backend");
+
+    assert_eq!(agent.backend_names(), vec!["UCX".to_string()]);
+    let backends = agent.backends();
+    assert_eq!(backends.len(), 1);
+    assert_eq!(backends[0].plugin_name(), "UCX");
+    agent.get_backend_params(&backends[0]).expect("Failed to get backend params");
+}
+
+#[test]
+fn test_mixed_reg_desc_list() {
+    let dram = SystemStorage::new(1024).expect("Failed to create storage");
+    let mut host_buf = vec![0u8; 256];
+    let host_desc = DramDescriptor::from_slice(&mut host_buf, 0);
+    let vram_desc = VramDescriptorStub;
+
+    let mut list = MixedRegDescList::with_mem_types(vec![MemType::Dram]);
+    list.add_storage_desc(&dram).expect("Failed to add DRAM descriptor");
+    list.add_storage_desc(&host_desc).expect("Failed to add DRAM descriptor");
+    assert!(matches!(
+        list.add_storage_desc(&vram_desc),
+        Err(NixlError::UnsupportedMemType { index: 2, mem_type: MemType::Vram })
+    ));
+    assert_eq!(list.len(), 2);
+    assert_eq!(list.lists().len(), 1);
+
+    let mut list = MixedRegDescList::with_mem_types(vec![MemType::Dram, MemType::Vram]);
+    list.add_storage_desc(&dram).expect("Failed to add DRAM descriptor");
+    list.add_storage_desc(&vram_desc).expect("Failed to add VRAM descriptor");
+    let types: Vec<MemType> = list.lists().iter().map(|l| l.get_type().unwrap()).collect();
+    assert_eq!(types, vec![MemType::Dram, MemType::Vram]);
+
+    let agent = Agent::new("mixed_list_agent").expect("Failed to create agent");
+    let (_mems, params) = agent.get_plugin_params("UCX").expect("Failed to get plugin params");
+    let backend = agent.create_backend("UCX", &params).expect("Failed to create backend");
+    let list = MixedRegDescList::for_backend(&agent, &backend).expect("Failed to create list");
+    assert!(list.is_empty());
+}
+
+/// A device memory descriptor that is never dereferenced, for type checks only
+#[derive(Debug)]
+struct VramDescriptorStub;
+
+impl MemoryRegion for VramDescriptorStub {
+    unsafe fn as_ptr(&self) -> *const u8 {
+        0x1000 as *const u8
+    }
+
+    fn size(&self) -> usize {
+        64
+    }
+}
+
+impl NixlDescriptor for VramDescriptorStub {
+    fn mem_type(&self) -> MemType {
+        MemType::Vram
+    }
+
+    fn device_id(&self) -> u64 {
+        0
+    }
+}
+
+#[test]
+fn test_register_memory_with_backends() {
+    let agent = Agent::new("reg_with_agent").expect("Failed to create agent");
+    let (_mems, params) = agent.get_plugin_params("UCX").expect("Failed to get plugin params");
+    let backend = agent.create_backend("UCX", &params).expect("Failed to create backend");
+
+    let storage = SystemStorage::new(1024).expect("Failed to create storage");
+    let handle = agent
+        .register_memory_with(&storage, &[&backend], None)
+        .expect("Failed to register memory with backend");
+    handle.deregister().expect("Failed to deregister memory");
+
+    // Backends of the optional arguments are merged with the given ones
+    let opt_args = OptArgs::builder().backend(&backend).build().unwrap();
+    let handle = agent
+        .register_memory_with(&storage, &[&backend], Some(&opt_args))
+        .expect("Failed to register memory with merged backends");
+    handle.deregister().expect("Failed to deregister memory");
+
+    let result = agent.register_memory_with(&storage, &[], None);
+    assert!(matches!(result, Err(NixlError::InvalidParam)));
+}
+
+#[test]
+fn test_deregister_uses_registration_backend() {
+    let agent = Agent::new("dereg_backend_agent").expect("Failed to create agent");
+    let (_mems, params) = agent.get_plugin_params("UCX").expect("Failed to get plugin params");
+    let backend = agent.create_backend("UCX", &params).expect("Failed to create backend");
+    let opt_args = OptArgs::builder().backend(&backend).build().expect("Failed to build opt args");
+
+    let storage = SystemStorage::new(1024).expect("Failed to create storage");
+    let handle = agent
+        .register_memory(&storage, Some(&opt_args))
+        .expect("Failed to register memory");
+    handle.deregister().expect("Failed to deregister memory from its backend");
+
+    // The region is fully released, so it can be registered again
+    let handle = agent
+        .register_memory(&storage, Some(&opt_args))
+        .expect("Failed to re-register memory");
+    drop(handle);
+}
+
+#[test]
+fn test_deregister_all() {
+    let agent = Agent::new("dereg_all_agent").expect("Failed to create agent");
+    let opt_args = setup_agent_with_backend(&agent).expect("Failed to setup agent");
+    let storage1 = SystemStorage::new(1024).expect("Failed to create storage");
+    let storage2 = SystemStorage::new(2048).expect("Failed to create storage");
+
+    let handle1 = agent.register_memory(&storage1, Some(&opt_args)).expect("Failed to register memory");
+    let handle2 = agent.register_memory(&storage2, Some(&opt_args)).expect("Failed to register memory");
+    agent.deregister_all().expect("Failed to deregister all memory");
+
+    // Handles released in bulk are no-ops afterwards
+    handle1.deregister().expect("Deregistering a released handle should be a no-op");
+    drop(handle2);
+    agent.deregister_all().expect("Nothing left to deregister");
+
+    // The regions can be registered again
+    let _handle = agent.register_memory(&storage1, Some(&opt_args)).expect("Failed to re-register memory");
+}
+
 #[test]
 fn test_multiple_registrations() {
     let agent = Agent::new("test_agent").unwrap();
+    setup_agent_with_backend(&agent).unwrap();
     let mut storage1 = SystemStorage::new(1024).unwrap();
     let mut storage2 = SystemStorage::new(2048).unwrap();
 
@@ -391,6 +1264,33 @@ fn test_multiple_registrations() {
     assert!(storage2.as_slice().iter().all(|&x| x == 0xBB));
 }
 
+#[test]
+fn test_make_connection_with_cb() {
+    let agent = Agent::new("conn_cb_agent").expect("Failed to create agent");
+    let remote_agent = Agent::new("conn_cb_remote").expect("Failed to create remote agent");
+    let opt_args = setup_agent_with_backend(&agent).expect("Failed to setup agent");
+    let _opt_args_remote = setup_agent_with_backend(&remote_agent).expect("Failed to setup agent");
+    exchange_metadata(&agent, &remote_agent).expect("Failed to exchange metadata");
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    let handle = agent.make_connection_with_cb("conn_cb_remote", Some(&opt_args), move |result| {
+        tx.send(result).unwrap();
+    });
+    let result = rx
+        .recv_timeout(std::time::Duration::from_secs(10))
+        .expect("Connection callback was not called");
+    assert!(result.is_ok(), "Connection failed: {:?}", result.err());
+    handle.join().unwrap();
+
+    // Errors are reported through the callback as well
+    let (tx, rx) = std::sync::mpsc::channel();
+    agent
+        .make_connection_with_cb("conn_cb_unknown", None, move |result| tx.send(result).unwrap())
+        .join()
+        .unwrap();
+    assert!(rx.recv().unwrap().is_err());
+}
+
 #[test]
 fn test_make_connection_success() {
     let agent = Agent::new("test_agent").expect("Failed to create agent");
@@ -411,7 +1311,22 @@ fn test_make_connection_success() {
 }
 
 #[test]
-fn test_make_connection_invalid_param() {
+fn test_connect_all() {
+    let (agent, opt_args) = create_agent_with_backend("connect_all_local").unwrap();
+    assert!(agent.connect_all(None).connected.is_empty());
+
+    let (remote1, _) = create_agent_with_backend("connect_all_remote1").unwrap();
+    let (remote2, _) = create_agent_with_backend("connect_all_remote2").unwrap();
+    exchange_metadata(&agent, &remote1).unwrap();
+    exchange_metadata(&agent, &remote2).unwrap();
+
+    let summary = agent.connect_all(Some(&opt_args));
+    assert!(summary.all_connected(), "Failed connections: {:?}", summary.failed);
+    assert_eq!(summary.connected, ["connect_all_remote1", "connect_all_remote2"]);
+}
+
+#[test]
+fn test_make_connection_invalid_param() {
     let agent = Agent::new("test_agent").expect("Failed to create agent");
     // Null bytes in the name should trigger InvalidParam or StringConversionError
     let result = agent.make_connection("remote\0agent", None);
@@ -423,6 +1338,43 @@ fn test_make_connection_invalid_param() {
     );
 }
 
+#[test]
+fn test_string_conversion_failure_keeps_agent_usable() {
+    let agent = Agent::new("conversion_agent").expect("Failed to create agent");
+    let opt_args = setup_agent_with_backend(&agent).expect("Failed to setup agent");
+    let mut storage = SystemStorage::new(1024).expect("Failed to create storage");
+    storage.register(&agent, Some(&opt_args)).expect("Failed to register memory");
+    let mut dlist = XferDescList::new(MemType::Dram).expect("Failed to create list");
+    dlist.add_storage_desc(&storage).expect("Failed to add descriptor");
+
+    let bad_name = "bad\0name";
+    assert!(matches!(
+        agent.prepare_xfer_dlist(bad_name, &dlist, None),
+        Err(NixlError::StringConversionError(_))
+    ));
+    assert!(matches!(
+        agent.prepare_xfer_dlist_cached(bad_name, &dlist, None),
+        Err(NixlError::StringConversionError(_))
+    ));
+    assert!(matches!(
+        agent.fetch_remote_md(bad_name, None),
+        Err(NixlError::StringConversionError(_))
+    ));
+    assert!(matches!(
+        agent.send_notification_unchecked(bad_name, b"hello", None),
+        Err(NixlError::StringConversionError(_))
+    ));
+    let mut bad_args = OptArgs::new().expect("Failed to create opt args");
+    assert!(matches!(bad_args.set_ip_addr(bad_name), Err(NixlError::StringConversionError(_))));
+
+    // The agent's lock was never poisoned, so later calls still succeed
+    agent.get_local_md().expect("Failed to get local metadata");
+    agent
+        .prepare_xfer_dlist("", &dlist, None)
+        .expect("Failed to prepare descriptor list");
+    assert_eq!(agent.registrations().len(), 1);
+}
+
 #[test]
 fn test_get_local_md() {
     let agent = Agent::new("test_agent").unwrap();
@@ -488,6 +1440,159 @@ fn test_metadata_exchange() {
     assert_eq!(remote_name, "agent1");
 }
 
+#[test]
+fn test_get_local_md_buf() {
+    let agent = Agent::new("md_buf_agent").expect("Failed to create agent");
+    let remote = Agent::new("md_buf_remote").expect("Failed to create remote agent");
+    let _opt_args = setup_agent_with_backend(&agent).expect("Failed to setup agent");
+    let _opt_args_remote = setup_agent_with_backend(&remote).expect("Failed to setup agent");
+
+    let buf = agent.get_local_md_buf().expect("Failed to get local metadata buffer");
+    assert!(!buf.is_empty());
+    let name = remote.load_remote_md(&buf).expect("Failed to load metadata from buffer");
+    assert_eq!(name, "md_buf_agent");
+}
+
+#[test]
+fn test_md_equal_and_delta() {
+    let (agent, opt_args) = create_agent_with_backend("md_delta_agent").unwrap();
+    let (other, _other_opt_args) = create_agent_with_backend("md_delta_other").unwrap();
+    let before = agent.get_local_md().unwrap();
+    assert!(md_equal(&before, &agent.get_local_md().unwrap()));
+    assert!(md_delta(&before, &before).unwrap().is_empty());
+
+    let storage = SystemStorage::new(1024).unwrap();
+    let handle = agent.register_memory(&storage, Some(&opt_args)).unwrap();
+    let after = agent.get_local_md().unwrap();
+    assert!(!md_equal(&before, &after));
+
+    let delta = md_delta(&before, &after).expect("Failed to diff metadata");
+    assert_eq!(delta.added.len(), 1);
+    assert_eq!(delta.added[0].backend, "UCX");
+    assert_eq!(delta.added[0].mem_type, MemType::Dram);
+    assert_eq!(delta.added[0].addr, storage.as_slice().as_ptr() as usize);
+    assert_eq!(delta.added[0].len, 1024);
+    assert!(delta.removed.is_empty());
+    assert!(!delta.conn_info_changed);
+
+    handle.deregister().unwrap();
+    let delta = md_delta(&after, &agent.get_local_md().unwrap()).unwrap();
+    assert!(delta.added.is_empty());
+    assert_eq!(delta.removed.len(), 1);
+
+    let other_md = other.get_local_md().unwrap();
+    assert!(!md_equal(&before, &other_md));
+    assert!(matches!(md_delta(&before, &other_md), Err(NixlError::InvalidParam)));
+    assert!(matches!(md_delta(&before, b"garbage"), Err(NixlError::InvalidParam)));
+    assert!(md_equal(b"garbage", b"garbage"));
+}
+
+#[test]
+fn test_describe_local_md() {
+    let (agent, opt_args) = create_agent_with_backend("md_summary_agent").unwrap();
+    let storage1 = SystemStorage::new(1024).unwrap();
+    let storage2 = SystemStorage::new(2048).unwrap();
+    let _handle1 = agent.register_memory(&storage1, Some(&opt_args)).unwrap();
+    let _handle2 = agent.register_memory(&storage2, Some(&opt_args)).unwrap();
+
+    let summary = agent.describe_local_md().expect("Failed to describe metadata");
+    assert_eq!(summary.agent, "md_summary_agent");
+    assert_eq!(summary.backends, ["UCX"]);
+    assert_eq!(summary.desc_counts.get(&MemType::Dram), Some(&2));
+    assert_eq!(summary.descs.len(), 2);
+    assert_eq!(summary, parse_md_summary(&agent.get_local_md().unwrap()).unwrap());
+
+    let text = summary.to_string();
+    assert!(text.starts_with("agent md_summary_agent\nbackends: UCX\n"));
+    assert_eq!(text.lines().count(), 4);
+
+    assert!(matches!(parse_md_summary(b"garbage"), Err(NixlError::InvalidParam)));
+}
+
+#[test]
+fn test_md_compression() {
+    let (agent1, _opt_args1) = create_agent_with_backend("md_zip_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("md_zip_agent2").unwrap();
+    let storage = SystemStorage::new(1024).unwrap();
+    let _handle = agent2.register_memory(&storage, Some(&opt_args2)).unwrap();
+
+    let raw = agent2.get_local_md().unwrap();
+    assert_eq!(agent2.get_local_md_with(None).unwrap(), raw);
+
+    for (compression, enabled) in [
+        (Compression::Lz4, cfg!(feature = "lz4")),
+        (Compression::Zstd, cfg!(feature = "zstd")),
+    ] {
+        let opt_args = OptArgs::builder().md_compression(compression).build().unwrap();
+        assert_eq!(opt_args.md_compression(), compression);
+        assert!(matches!(agent2.send_local_md(Some(&opt_args)), Err(NixlError::Unsupported)));
+
+        let compressed = agent2.get_local_md_with(Some(&opt_args));
+        if !enabled {
+            assert!(matches!(compressed, Err(NixlError::Unsupported)));
+            continue;
+        }
+        let compressed = compressed.unwrap();
+        assert_ne!(compressed, raw);
+        assert!(md_equal(&compressed, &raw));
+        assert_eq!(parse_md_summary(&compressed).unwrap().agent, "md_zip_agent2");
+        assert_eq!(agent1.load_remote_md(&compressed).unwrap(), "md_zip_agent2");
+        agent1.invalidate_remote_md("md_zip_agent2").unwrap();
+    }
+
+    // Uncompressed metadata still loads, and a truncated header is rejected
+    assert_eq!(agent1.load_remote_md(&raw).unwrap(), "md_zip_agent2");
+    assert!(matches!(agent1.load_remote_md(b"nixlMdZ|\x01"), Err(NixlError::InvalidParam)));
+}
+
+#[test]
+fn test_md_version() {
+    let (agent1, _opt_args1) = create_agent_with_backend("md_version_agent1").unwrap();
+    let (agent2, _opt_args2) = create_agent_with_backend("md_version_agent2").unwrap();
+    let raw = agent2.get_local_md().unwrap();
+    assert_eq!(md_version(&raw), Some(1));
+    assert_eq!(md_version(b"garbage"), None);
+
+    if cfg!(feature = "lz4") {
+        let opt_args = OptArgs::builder().md_compression(Compression::Lz4).build().unwrap();
+        let compressed = agent2.get_local_md_with(Some(&opt_args)).unwrap();
+        assert_eq!(md_version(&compressed), Some(MD_FORMAT_VERSION));
+    }
+
+    let mut newer = b"nixlMdZ|".to_vec();
+    newer.extend_from_slice(&(MD_FORMAT_VERSION + 1).to_le_bytes());
+    newer.extend_from_slice(&raw);
+    assert_eq!(md_version(&newer), Some(MD_FORMAT_VERSION + 1));
+    match agent1.load_remote_md(&newer) {
+        Err(NixlError::IncompatibleMdVersion { got, supported }) => {
+            assert_eq!(got, MD_FORMAT_VERSION + 1);
+            assert_eq!(supported, MD_FORMAT_VERSION);
+        }
+        other => panic!("Expected IncompatibleMdVersion, got {other:?}"),
+    }
+    assert!(agent1.loaded_remotes().is_empty());
+}
+
+#[test]
+fn test_metadata_base64_exchange() {
+    let (agent1, _opt_args1) = create_agent_with_backend("b64_agent1").unwrap();
+    let (agent2, _opt_args2) = create_agent_with_backend("b64_agent2").unwrap();
+
+    let encoded = agent2.export_md_base64().expect("Failed to export metadata");
+    let metadata = AgentMetadata::from_base64(&encoded).unwrap();
+    assert!(!metadata.as_bytes().is_empty());
+    assert_eq!(metadata.to_base64(), encoded);
+
+    let remote_name = agent1.import_md_base64(&encoded).expect("Failed to import metadata");
+    assert_eq!(remote_name, "b64_agent2");
+    assert!(agent1.is_remote_loaded("b64_agent2"));
+
+    assert!(matches!(
+        agent1.import_md_base64("not valid base64!"),
+        Err(NixlError::InvalidParam)
+    ));
+}
+
 #[test]
 fn test_basic_agent_lifecycle() {
     // Create two agents
@@ -544,58 +1649,1086 @@ fn test_basic_agent_lifecycle() {
     xfer_args.set_has_notification(true).unwrap();
     xfer_args.set_notification_message(b"notification").unwrap();
 
-    let xfer_req = agent1
-        .create_xfer_req(
-            XferOp::Write,
-            &local_xfer_dlist,
-            &remote_xfer_dlist,
-            &remote_name,
-            Some(&xfer_args),
-        )
+    let xfer_req = agent1
+        .create_xfer_req(
+            XferOp::Write,
+            &local_xfer_dlist,
+            &remote_xfer_dlist,
+            &remote_name,
+            Some(&xfer_args),
+        )
+        .unwrap();
+
+    let _status = agent1.post_xfer_req(&xfer_req, None).unwrap();
+
+    println!("Waiting for local completions");
+
+    loop {
+        let status = agent1.get_xfer_status(&xfer_req).unwrap();
+
+        if status.is_success() {
+            println!("Xfer req completed");
+            break;
+        } else {
+            println!("Xfer req not completed");
+        }
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+
+    let mut notifs = NotificationMap::new().unwrap();
+    let notify_map;
+    println!("Waiting for notifications");
+    std::thread::sleep(std::time::Duration::from_millis(100));
+
+    loop {
+        agent2.get_notifications(&mut notifs, None).unwrap();
+        if !notifs.is_empty().unwrap() {
+            notify_map = notifs.take_notifs().unwrap();
+            assert_eq!(notify_map.len(), 1);
+            assert!(notifs.is_empty().unwrap());
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+
+    println!("Got notifications");
+
+    // Get first notification from first agent
+    let vals = notify_map.get("A1").unwrap();
+    assert_eq!(vals.len(), 1);
+    assert_eq!(vals[0], "notification");
+
+    // Verify memory patterns
+    assert!(storage1.as_slice().iter().all(|&x| x == 0xbb));
+    assert!(storage2.as_slice().iter().all(|&x| x == 0xbb));
+}
+
+#[test]
+fn test_xfer_notification_after_data() {
+    let agent1 = Agent::new("NotifWriter").unwrap();
+    let agent2 = Agent::new("NotifTarget").unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let mut storage1 = SystemStorage::new(4096).unwrap();
+    let mut storage2 = SystemStorage::new(4096).unwrap();
+    storage1.memset(0xcd);
+    storage2.memset(0x00);
+    storage1.register(&agent1, None).unwrap();
+    storage2.register(&agent2, None).unwrap();
+
+    let metadata = agent2.get_local_md().unwrap();
+    let remote_name = agent1.load_remote_md(&metadata).unwrap();
+
+    let mut local_xfer_dlist = XferDescList::new(MemType::Dram).unwrap();
+    local_xfer_dlist.add_storage_desc(&storage1).unwrap();
+    let mut remote_xfer_dlist = XferDescList::new(MemType::Dram).unwrap();
+    remote_xfer_dlist.add_storage_desc(&storage2).unwrap();
+
+    let mut xfer_args = OptArgs::new().unwrap();
+    xfer_args.set_notification(b"data-ready").unwrap();
+    assert!(xfer_args.has_notification().unwrap());
+    assert_eq!(xfer_args.get_notification_message().unwrap(), b"data-ready");
+
+    let xfer_req = agent1
+        .create_xfer_req(
+            XferOp::Write,
+            &local_xfer_dlist,
+            &remote_xfer_dlist,
+            &remote_name,
+            Some(&xfer_args),
+        )
+        .unwrap();
+    agent1.post_xfer_req(&xfer_req, None).unwrap();
+
+    // Poll only the remote side: once the notification is visible, the
+    // written data must already be in place
+    let mut notifs = NotificationMap::new().unwrap();
+    let notify_map;
+    loop {
+        agent1.get_xfer_status(&xfer_req).unwrap();
+        agent2.get_notifications(&mut notifs, None).unwrap();
+        if !notifs.is_empty().unwrap() {
+            notify_map = notifs.take_notifs().unwrap();
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+
+    assert!(storage2.as_slice().iter().all(|&x| x == 0xcd));
+    let vals = notify_map.get("NotifWriter").unwrap();
+    assert_eq!(vals.len(), 1);
+    assert_eq!(vals[0], "data-ready");
+}
+
+#[test]
+fn test_xfer_tag_notifications() {
+    let (agent1, opt_args1) = create_agent_with_backend("xfer_tag_writer").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("xfer_tag_reader").unwrap();
+    let mut local_storage = create_storage_list(&agent1, &opt_args1, 1);
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 1);
+    exchange_metadata(&agent1, &agent2).unwrap();
+    let local_dlist = create_dlist(&mut local_storage).unwrap();
+    let remote_dlist = create_dlist(&mut remote_storage).unwrap();
+
+    // The tag can be set before or after the message
+    let mut tagged_first = OptArgs::new().unwrap();
+    tagged_first.set_xfer_tag(7).unwrap();
+    tagged_first.set_notification(b"first").unwrap();
+    let tagged_last = OptArgs::builder().notification_msg(b"second").xfer_tag(8).build().unwrap();
+    assert_eq!(tagged_last.xfer_tag(), Some(8));
+    assert!(tagged_last.has_notification().unwrap());
+    assert_eq!(split_xfer_tag(&tagged_last.get_notification_message().unwrap()), (Some(8), &b"second"[..]));
+
+    let mut reqs = Vec::new();
+    for args in [&tagged_first, &tagged_last] {
+        let req = agent1
+            .create_xfer_req(XferOp::Write, &local_dlist, &remote_dlist, "xfer_tag_reader", Some(args))
+            .unwrap();
+        agent1.post_xfer_req(&req, None).unwrap();
+        agent1.wait_xfer(&req, std::time::Duration::from_millis(1), None).unwrap();
+        reqs.push(req);
+    }
+    assert_eq!(reqs[0].xfer_tag(), Some(7));
+    assert_eq!(reqs[1].xfer_tag(), Some(8));
+
+    let mut received = Vec::new();
+    while received.len() < 2 {
+        let mut notifs = NotificationMap::new().unwrap();
+        agent2.get_notifications(&mut notifs, None).unwrap();
+        for (agent, tag, message) in notifs.iter_tagged() {
+            assert_eq!(agent, "xfer_tag_writer");
+            received.push((tag, message.to_vec()));
+        }
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+    received.sort();
+    assert_eq!(received, [(Some(7), b"first".to_vec()), (Some(8), b"second".to_vec())]);
+
+    assert_eq!(split_xfer_tag(b"untagged"), (None, &b"untagged"[..]));
+
+    // The header is text, and only a complete one counts as a tag
+    let message = tagged_last.get_notification_message().unwrap();
+    assert_eq!(std::str::from_utf8(&message).unwrap(), "NIXL_TAG:0000000000000008:second");
+    assert_eq!(split_xfer_tag(b"NIXL_TAG:done"), (None, &b"NIXL_TAG:done"[..]));
+}
+
+#[test]
+fn test_concurrent_post_xfer_req() {
+    let cfg = AgentConfig {
+        thread_sync: ThreadSync::Rw,
+        ..Default::default()
+    };
+    let agent1 = Agent::new_configured("ConcurrentSender", &cfg).unwrap();
+    let agent2 = Agent::new_configured("ConcurrentReceiver", &cfg).unwrap();
+
+    let (_mems, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    const NUM_THREADS: usize = 4;
+    let mut local_storage: Vec<SystemStorage> = Vec::new();
+    let mut remote_storage: Vec<SystemStorage> = Vec::new();
+    for i in 0..NUM_THREADS {
+        let mut local = SystemStorage::new(1024).unwrap();
+        let mut remote = SystemStorage::new(1024).unwrap();
+        local.memset(i as u8 + 1);
+        remote.memset(0);
+        local.register(&agent1, None).unwrap();
+        remote.register(&agent2, None).unwrap();
+        local_storage.push(local);
+        remote_storage.push(remote);
+    }
+
+    let metadata = agent2.get_local_md().unwrap();
+    let remote_name = agent1.load_remote_md(&metadata).unwrap();
+
+    std::thread::scope(|scope| {
+        for (local, remote) in local_storage.iter().zip(remote_storage.iter()) {
+            let agent = agent1.clone();
+            let remote_name = remote_name.clone();
+            scope.spawn(move || {
+                let mut local_dlist = XferDescList::new(MemType::Dram).unwrap();
+                local_dlist.add_storage_desc(local).unwrap();
+                let mut remote_dlist = XferDescList::new(MemType::Dram).unwrap();
+                remote_dlist.add_storage_desc(remote).unwrap();
+
+                let req = agent
+                    .create_xfer_req(XferOp::Write, &local_dlist, &remote_dlist, &remote_name, None)
+                    .unwrap();
+                agent.post_xfer_req(&req, None).unwrap();
+                while !agent.get_xfer_status(&req).unwrap().is_success() {
+                    std::thread::sleep(std::time::Duration::from_millis(10));
+                }
+            });
+        }
+    });
+
+    for (i, remote) in remote_storage.iter().enumerate() {
+        assert!(remote.as_slice().iter().all(|&x| x == i as u8 + 1));
+    }
+}
+
+#[test]
+fn test_cost_method_conversion() {
+    assert_eq!(CostMethod::from(0), CostMethod::AnalyticalBackend);
+    assert_eq!(CostMethod::from(42), CostMethod::Other(42));
+    assert_eq!(CostMethod::AnalyticalBackend.to_string(), "analytical backend");
+    assert_eq!(CostMethod::Other(42).to_string(), "unknown (42)");
+}
+
+#[test]
+fn test_estimate_xfer_costs() {
+    let (agent1, opt_args1) = create_agent_with_backend("cost_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("cost_agent2").unwrap();
+    let mut local_storage = create_storage_list(&agent1, &opt_args1, 2);
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 2);
+    exchange_metadata(&agent1, &agent2).unwrap();
+
+    let local_dlist = create_dlist(&mut local_storage).unwrap();
+    let remote_dlist = create_dlist(&mut remote_storage).unwrap();
+    let req1 = agent1
+        .create_xfer_req(XferOp::Write, &local_dlist, &remote_dlist, "cost_agent2", None)
+        .unwrap();
+    let req2 = agent1
+        .create_xfer_req(XferOp::Read, &local_dlist, &remote_dlist, "cost_agent2", None)
+        .unwrap();
+
+    assert!(agent1.estimate_xfer_costs(&[], None).unwrap().is_empty());
+    assert_eq!(agent1.cheapest_xfer(&[]).unwrap(), None);
+
+    // Backends without cost estimation report an error for every request
+    match agent1.estimate_xfer_costs(&[&req1, &req2], None) {
+        Ok(costs) => {
+            assert_eq!(costs.len(), 2);
+            let cheapest = agent1.cheapest_xfer(&[&req1, &req2]).unwrap().unwrap();
+            let min_duration = costs.iter().map(|(duration, _, _)| *duration).min().unwrap();
+            assert_eq!(costs[cheapest].0, min_duration);
+        }
+        Err(_) => assert!(agent1.estimate_xfer_cost(&req1, None).is_err()),
+    }
+}
+
+#[test]
+fn test_xfer_request_release() {
+    let (agent1, opt_args1) = create_agent_with_backend("release_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("release_agent2").unwrap();
+    let mut local_storage = create_storage_list(&agent1, &opt_args1, 1);
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 1);
+    exchange_metadata(&agent1, &agent2).unwrap();
+
+    let local_dlist = create_dlist(&mut local_storage).unwrap();
+    let remote_dlist = create_dlist(&mut remote_storage).unwrap();
+    let mut req = agent1
+        .create_xfer_req(XferOp::Write, &local_dlist, &remote_dlist, "release_agent2", None)
+        .unwrap();
+    agent1.post_xfer_req(&req, None).unwrap();
+
+    // An in-flight request is handed back instead of being torn down
+    loop {
+        match req.release() {
+            Ok(()) => break,
+            Err((pending, NixlError::XferInProgress)) => {
+                req = pending;
+                std::thread::sleep(std::time::Duration::from_millis(10));
+            }
+            Err((_, e)) => panic!("Failed to release transfer request: {e}"),
+        }
+    }
+}
+
+#[test]
+fn test_cancel_xfer_req() {
+    let (agent1, opt_args1) = create_agent_with_backend("cancel_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("cancel_agent2").unwrap();
+    let mut local_storage = create_storage_list(&agent1, &opt_args1, 1);
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 1);
+    exchange_metadata(&agent1, &agent2).unwrap();
+    let local_dlist = create_dlist(&mut local_storage).unwrap();
+    let remote_dlist = create_dlist(&mut remote_storage).unwrap();
+
+    let req = agent1
+        .create_xfer_req(XferOp::Write, &local_dlist, &remote_dlist, "cancel_agent2", None)
+        .unwrap();
+
+    // Only the creating agent can cancel, and an unposted request is left alone
+    assert!(matches!(agent2.cancel_xfer_req(&req), Err(NixlError::InvalidParam)));
+    agent1.cancel_xfer_req(&req).unwrap();
+    assert!(matches!(agent1.get_xfer_status(&req), Err(NixlError::InvalidParam)));
+
+    let in_progress = agent1.post_xfer_req(&req, None).unwrap();
+    match agent1.cancel_xfer_req(&req) {
+        Ok(()) if in_progress => {
+            let status = agent1.get_xfer_status(&req).unwrap();
+            assert!(status.is_cancelled() || status.is_success());
+        }
+        Ok(()) => assert!(agent1.get_xfer_status(&req).unwrap().is_success()),
+        // The backend could not abort it, which ends the request as failed
+        Err(NixlError::CancelFailed) => {
+            let status = agent1.get_xfer_status(&req).unwrap();
+            assert!(matches!(status, XferStatus::Failed { .. }) && !status.is_cancelled());
+        }
+        Err(e) => panic!("Failed to cancel transfer request: {e}"),
+    }
+
+    // Cancelling again is a no-op, and the request can still be released
+    agent1.cancel_xfer_req(&req).unwrap();
+    if let Err((_, e)) = req.release() {
+        panic!("Failed to release transfer request: {e}");
+    }
+}
+
+#[test]
+fn test_xfer_status_failed() {
+    assert!(!XferStatus::Failed { code: -3 }.is_success());
+    assert_ne!(XferStatus::Failed { code: -3 }, XferStatus::Failed { code: -4 });
+
+    let (agent1, opt_args1) = create_agent_with_backend("failed_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("failed_agent2").unwrap();
+    let mut local_storage = create_storage_list(&agent1, &opt_args1, 1);
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 1);
+    exchange_metadata(&agent1, &agent2).unwrap();
+
+    let local_dlist = create_dlist(&mut local_storage).unwrap();
+    let remote_dlist = create_dlist(&mut remote_storage).unwrap();
+    let req = agent1
+        .create_xfer_req(XferOp::Write, &local_dlist, &remote_dlist, "failed_agent2", None)
+        .unwrap();
+
+    // Checking a request that was never posted is API misuse, not a failed transfer
+    assert!(matches!(agent1.get_xfer_status(&req), Err(NixlError::InvalidParam)));
+
+    // Invalidating the remote mid-flight fails the transfer unless it already completed
+    agent1.post_xfer_req(&req, None).unwrap();
+    agent1.invalidate_remote_md("failed_agent2").unwrap();
+    loop {
+        match agent1.get_xfer_status(&req) {
+            Ok(XferStatus::Success) | Ok(XferStatus::Failed { .. }) => break,
+            Ok(XferStatus::InProgress) => std::thread::sleep(std::time::Duration::from_millis(10)),
+            Err(e) => panic!("Failed to get transfer status: {e}"),
+        }
+    }
+}
+
+#[test]
+fn test_strict_validation() {
+    let (agent1, opt_args1) = create_agent_with_backend("strict_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("strict_agent2").unwrap();
+    let mut local_storage = create_storage_list(&agent1, &opt_args1, 1);
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 1);
+    exchange_metadata(&agent1, &agent2).unwrap();
+    let local_dlist = create_dlist(&mut local_storage).unwrap();
+    let remote_dlist = create_dlist(&mut remote_storage).unwrap();
+
+    let opt_args = OptArgs::builder().strict_validation(true).build().unwrap();
+    assert!(opt_args.strict_validation());
+    agent1
+        .create_xfer_req(XferOp::Write, &local_dlist, &remote_dlist, "strict_agent2", Some(&opt_args))
+        .expect("DRAM transfers should pass validation");
+
+    // UCX does not support files, so the request is rejected before reaching it
+    let mut file_dlist = XferDescList::new(MemType::File).unwrap();
+    file_dlist.add_desc(0, 1024, 0).unwrap();
+    assert!(matches!(
+        agent1.create_xfer_req(XferOp::Write, &file_dlist, &remote_dlist, "strict_agent2", Some(&opt_args)),
+        Err(NixlError::InvalidParam)
+    ));
+
+    let mut opt_args = OptArgs::new().unwrap();
+    opt_args.add_backend(&agent1.get_backend("UCX").unwrap()).unwrap();
+    opt_args.set_strict_validation(true);
+    assert!(matches!(
+        agent1.create_xfer_req(XferOp::Read, &local_dlist, &file_dlist, "strict_agent2", Some(&opt_args)),
+        Err(NixlError::InvalidParam)
+    ));
+}
+
+#[test]
+fn test_durable_write_unsupported() {
+    let (agent1, opt_args1) = create_agent_with_backend("durable_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("durable_agent2").unwrap();
+    let mut local_storage = create_storage_list(&agent1, &opt_args1, 1);
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 1);
+    exchange_metadata(&agent1, &agent2).unwrap();
+
+    let local_dlist = create_dlist(&mut local_storage).unwrap();
+    let remote_dlist = create_dlist(&mut remote_storage).unwrap();
+    let result = agent1.create_xfer_req(
+        XferOp::WriteDurable,
+        &local_dlist,
+        &remote_dlist,
+        "durable_agent2",
+        None,
+    );
+    assert!(matches!(result, Err(NixlError::Unsupported)));
+    assert!(XferOp::WriteDurable.is_write());
+    assert!(!XferOp::Read.is_write());
+}
+
+#[test]
+fn test_atomic_ops_unsupported() {
+    let (agent1, opt_args1) = create_agent_with_backend("atomic_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("atomic_agent2").unwrap();
+    let mut local_storage = create_storage_list(&agent1, &opt_args1, 1);
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 1);
+    exchange_metadata(&agent1, &agent2).unwrap();
+
+    let local_dlist = create_dlist(&mut local_storage).unwrap();
+    let remote_dlist = create_dlist(&mut remote_storage).unwrap();
+
+    let mut opt_args = OptArgs::new().unwrap();
+    assert_eq!(opt_args.atomic_operands(), None);
+    opt_args.set_atomic_add(1);
+    assert_eq!(opt_args.atomic_operands(), Some(AtomicOperands::Add(1)));
+    let result = agent1.create_xfer_req(
+        XferOp::AtomicAdd,
+        &local_dlist,
+        &remote_dlist,
+        "atomic_agent2",
+        Some(&opt_args),
+    );
+    assert!(matches!(result, Err(NixlError::Unsupported)));
+
+    let opt_args = OptArgs::builder()
+        .atomic_operands(AtomicOperands::CompareSwap {
+            expected: 0,
+            desired: 1,
+        })
+        .build()
+        .unwrap();
+    assert_eq!(
+        opt_args.atomic_operands(),
+        Some(AtomicOperands::CompareSwap {
+            expected: 0,
+            desired: 1
+        })
+    );
+    let result = agent1.create_xfer_req(
+        XferOp::CompareSwap,
+        &local_dlist,
+        &remote_dlist,
+        "atomic_agent2",
+        Some(&opt_args),
+    );
+    assert!(matches!(result, Err(NixlError::Unsupported)));
+    assert!(XferOp::CompareSwap.is_atomic());
+    assert!(!XferOp::Write.is_atomic());
+}
+
+#[test]
+fn test_backend_stats() {
+    let (agent1, opt_args1) = create_agent_with_backend("stats_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("stats_agent2").unwrap();
+    let mut local_storage = create_storage_list(&agent1, &opt_args1, 2);
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 2);
+    exchange_metadata(&agent1, &agent2).unwrap();
+
+    let backend = agent1.get_backend("UCX").unwrap();
+    assert_eq!(backend.stats(&agent1).unwrap(), BackendStats::default());
+
+    let local_dlist = create_dlist(&mut local_storage).unwrap();
+    let remote_dlist = create_dlist(&mut remote_storage).unwrap();
+    let req = agent1
+        .create_xfer_req(XferOp::Write, &local_dlist, &remote_dlist, "stats_agent2", None)
+        .unwrap();
+    agent1.post_xfer_req(&req, None).unwrap();
+    while !agent1.get_xfer_status(&req).unwrap().is_success() {
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    // Polling a completed request again does not count it twice
+    agent1.get_xfer_status(&req).unwrap();
+
+    let stats = backend.stats(&agent1).unwrap();
+    assert_eq!(stats.completed_xfers, 1);
+    assert_eq!(stats.failed_xfers, 0);
+    assert_eq!(stats.bytes_sent, 2 * 1024);
+    assert_eq!(stats.bytes_received, 0);
+}
+
+#[test]
+fn test_wait_xfer() {
+    let (agent1, opt_args1) = create_agent_with_backend("wait_xfer_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("wait_xfer_agent2").unwrap();
+    let mut local_storage = create_storage_list(&agent1, &opt_args1, 1);
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 1);
+    exchange_metadata(&agent1, &agent2).unwrap();
+
+    let local_dlist = create_dlist(&mut local_storage).unwrap();
+    let remote_dlist = create_dlist(&mut remote_storage).unwrap();
+    let req = agent1
+        .create_xfer_req(XferOp::Write, &local_dlist, &remote_dlist, "wait_xfer_agent2", None)
+        .unwrap();
+
+    // Waiting on a request that was never posted is API misuse
+    assert!(matches!(
+        agent1.wait_xfer(&req, std::time::Duration::from_millis(10), None),
+        Err(NixlError::InvalidParam)
+    ));
+
+    agent1.post_xfer_req(&req, None).unwrap();
+    agent1
+        .wait_xfer(
+            &req,
+            std::time::Duration::from_millis(10),
+            Some(std::time::Duration::from_secs(10)),
+        )
+        .expect("Failed to wait for transfer");
+}
+
+#[test]
+fn test_xfer_deadline() {
+    let (agent1, opt_args1) = create_agent_with_backend("deadline_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("deadline_agent2").unwrap();
+    let mut local_storage = create_storage_list(&agent1, &opt_args1, 1);
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 1);
+    exchange_metadata(&agent1, &agent2).unwrap();
+    let local_dlist = create_dlist(&mut local_storage).unwrap();
+    let remote_dlist = create_dlist(&mut remote_storage).unwrap();
+
+    let mut opt_args = OptArgs::new().unwrap();
+    assert_eq!(opt_args.deadline(), None);
+    opt_args.set_deadline(std::time::Duration::ZERO);
+    let generous = OptArgs::builder().deadline(std::time::Duration::from_secs(10)).build().unwrap();
+    assert_eq!(generous.deadline(), Some(std::time::Duration::from_secs(10)));
+
+    // A deadline given at creation applies when the request is posted
+    let req = agent1
+        .create_xfer_req(XferOp::Write, &local_dlist, &remote_dlist, "deadline_agent2", Some(&opt_args))
+        .unwrap();
+    assert_eq!(req.deadline(), None);
+    let before = std::time::Instant::now();
+    agent1.post_xfer_req(&req, None).unwrap();
+    assert!(req.deadline().unwrap() >= before);
+
+    // With a zero deadline, only a transfer that already completed passes
+    match agent1.wait_xfer(&req, std::time::Duration::from_millis(1), None) {
+        Ok(()) => {}
+        Err(NixlError::Timeout { pending }) => {
+            assert_eq!(pending, [0]);
+            // Cancelled, finished before it could be cancelled, or ended by a
+            // failed cancellation; the request is never left in progress
+            let status = agent1.get_xfer_status(&req).unwrap();
+            assert_ne!(status, XferStatus::InProgress);
+        }
+        Err(e) => panic!("Unexpected wait error: {e}"),
+    }
+
+    // A deadline given when posting applies to that post, and a generous one is met
+    let req = agent1
+        .create_xfer_req(XferOp::Write, &local_dlist, &remote_dlist, "deadline_agent2", None)
+        .unwrap();
+    agent1.post_xfer_req(&req, Some(&generous)).unwrap();
+    assert!(req.deadline().is_some());
+    agent1
+        .wait_xfer(&req, std::time::Duration::from_millis(1), None)
+        .expect("Transfer missed a generous deadline");
+}
+
+#[test]
+fn test_with_correlation_id() {
+    let (agent1, opt_args1) = create_agent_with_backend("correlation_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("correlation_agent2").unwrap();
+    assert_eq!(agent1.correlation_id(), None);
+
+    let tagged = agent1.with_correlation_id("req-42");
+    assert_eq!(tagged.correlation_id(), Some("req-42"));
+    assert_eq!(tagged.clone().correlation_id(), Some("req-42"));
+    assert_eq!(agent1.correlation_id(), None);
+    assert_eq!(tagged.name(), agent1.name());
+
+    // The tagged handle drives the same underlying agent
+    let mut local_storage = create_storage_list(&tagged, &opt_args1, 1);
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 1);
+    exchange_metadata(&agent1, &agent2).unwrap();
+
+    let local_dlist = create_dlist(&mut local_storage).unwrap();
+    let remote_dlist = create_dlist(&mut remote_storage).unwrap();
+    let req = tagged
+        .create_xfer_req(XferOp::Write, &local_dlist, &remote_dlist, "correlation_agent2", None)
+        .unwrap();
+    tagged.post_xfer_req(&req, None).unwrap();
+    tagged
+        .wait_xfer(
+            &req,
+            std::time::Duration::from_millis(10),
+            Some(std::time::Duration::from_secs(10)),
+        )
+        .expect("Failed to wait for transfer");
+    assert!(matches!(agent1.get_xfer_status(&req), Ok(XferStatus::Success)));
+}
+
+#[test]
+fn test_drain() {
+    let (agent1, opt_args1) = create_agent_with_backend("drain_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("drain_agent2").unwrap();
+    agent1.drain(Some(std::time::Duration::ZERO)).expect("Idle agent should drain immediately");
+
+    let mut local_storage = create_storage_list(&agent1, &opt_args1, 2);
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 2);
+    exchange_metadata(&agent1, &agent2).unwrap();
+    let local_dlist = create_dlist(&mut local_storage).unwrap();
+    let remote_dlist = create_dlist(&mut remote_storage).unwrap();
+
+    let reqs: Vec<_> = (0..4)
+        .map(|_| {
+            agent1
+                .create_xfer_req(XferOp::Write, &local_dlist, &remote_dlist, "drain_agent2", None)
+                .unwrap()
+        })
+        .collect();
+    for req in &reqs {
+        agent1.post_xfer_req(req, None).unwrap();
+    }
+
+    // Draining through another handle covers requests posted on this one
+    agent1
+        .clone()
+        .drain(Some(std::time::Duration::from_secs(10)))
+        .expect("Failed to drain transfers");
+    for req in &reqs {
+        assert!(agent1.get_xfer_status(req).unwrap().is_success());
+    }
+}
+
+#[test]
+fn test_copy() {
+    let (agent1, opt_args1) = create_agent_with_backend("copy_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("copy_agent2").unwrap();
+    let remote_storage = create_storage_list(&agent2, &opt_args2, 1);
+    exchange_metadata(&agent1, &agent2).unwrap();
+    let remote = XferDescriptor {
+        addr: remote_storage[0].as_slice().as_ptr() as usize,
+        len: 1024,
+        dev_id: 0,
+    };
+
+    // The local buffer is registered only for the duration of the copy
+    let mut local = SystemStorage::new(1024).unwrap();
+    local.memset(0x5a);
+    agent1
+        .copy(XferOp::Write, &local, "copy_agent2", &remote, MemType::Dram, Some(&opt_args1))
+        .expect("Failed to copy to the remote agent");
+    assert!(remote_storage[0].as_slice().iter().all(|&b| b == 0x5a));
+
+    local.memset(0);
+    agent1
+        .copy(XferOp::Read, &local, "copy_agent2", &remote, MemType::Dram, Some(&opt_args1))
+        .expect("Failed to copy from the remote agent");
+    assert!(local.as_slice().iter().all(|&b| b == 0x5a));
+
+    let short = SystemStorage::new(512).unwrap();
+    assert!(matches!(
+        agent1.copy(XferOp::Write, &short, "copy_agent2", &remote, MemType::Dram, Some(&opt_args1)),
+        Err(NixlError::InvalidParam)
+    ));
+}
+
+#[test]
+fn test_create_chunked_xfer() {
+    let (agent1, opt_args1) = create_agent_with_backend("chunked_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("chunked_agent2").unwrap();
+    let mut local_storage = create_storage_list(&agent1, &opt_args1, 3);
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 3);
+    for (i, storage) in local_storage.iter_mut().enumerate() {
+        storage.memset(i as u8 + 1);
+    }
+    exchange_metadata(&agent1, &agent2).unwrap();
+
+    {
+        let local_dlist = create_dlist(&mut local_storage).unwrap();
+        let remote_dlist = create_dlist(&mut remote_storage).unwrap();
+        assert!(matches!(
+            agent1.create_chunked_xfer(XferOp::Write, &local_dlist, &remote_dlist, "chunked_agent2", 0, None),
+            Err(NixlError::InvalidParam)
+        ));
+
+        // 3 KiB in 1000 byte chunks, crossing descriptor boundaries
+        let reqs = agent1
+            .create_chunked_xfer(XferOp::Write, &local_dlist, &remote_dlist, "chunked_agent2", 1000, None)
+            .expect("Failed to create chunked transfer");
+        assert_eq!(reqs.len(), 4);
+        let desc_counts: Vec<usize> =
+            reqs.iter().map(|req| req.debug_state(&agent1).unwrap().desc_count).collect();
+        assert_eq!(desc_counts, [1, 2, 2, 1]);
+
+        for req in &reqs {
+            agent1.post_xfer_req(req, None).unwrap();
+        }
+        let reqs: Vec<&XferRequest> = reqs.iter().collect();
+        agent1
+            .wait_all(&reqs, std::time::Duration::from_millis(1), Some(std::time::Duration::from_secs(10)))
+            .expect("Chunked transfer failed");
+    }
+
+    for (i, storage) in remote_storage.iter().enumerate() {
+        assert!(storage.as_slice().iter().all(|&b| b == i as u8 + 1));
+    }
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn test_completion_stream() {
+    use futures::StreamExt;
+
+    let (agent1, opt_args1) = create_agent_with_backend("stream_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("stream_agent2").unwrap();
+    let mut local_storage = create_storage_list(&agent1, &opt_args1, 3);
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 3);
+    exchange_metadata(&agent1, &agent2).unwrap();
+
+    let local_dlist = create_dlist(&mut local_storage).unwrap();
+    let remote_dlist = create_dlist(&mut remote_storage).unwrap();
+    let reqs = agent1
+        .create_chunked_xfer(XferOp::Write, &local_dlist, &remote_dlist, "stream_agent2", 1024, None)
+        .unwrap();
+    for req in &reqs {
+        agent1.post_xfer_req(req, None).unwrap();
+    }
+
+    let stream = agent1.completion_stream(reqs, std::time::Duration::from_millis(1));
+    assert_eq!(stream.remaining(), 3);
+    let mut completed: Vec<usize> = futures::executor::block_on(stream.collect::<Vec<_>>())
+        .into_iter()
+        .map(|(index, result)| {
+            result.expect("Transfer failed");
+            index
+        })
+        .collect();
+    completed.sort_unstable();
+    assert_eq!(completed, [0, 1, 2]);
+
+    let empty = agent1.completion_stream(Vec::new(), std::time::Duration::from_millis(1));
+    assert!(futures::executor::block_on(empty.collect::<Vec<_>>()).is_empty());
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_xfer_completion_fd() {
+    let (agent1, opt_args1) = create_agent_with_backend("completion_fd_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("completion_fd_agent2").unwrap();
+    let mut local_storage = create_storage_list(&agent1, &opt_args1, 1);
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 1);
+    exchange_metadata(&agent1, &agent2).unwrap();
+    let local_dlist = create_dlist(&mut local_storage).unwrap();
+    let remote_dlist = create_dlist(&mut remote_storage).unwrap();
+
+    let req = agent1
+        .create_xfer_req(XferOp::Write, &local_dlist, &remote_dlist, "completion_fd_agent2", None)
+        .unwrap();
+    agent1.post_xfer_req(&req, None).unwrap();
+
+    let fd = req.completion_fd().expect("Failed to create completion fd");
+    assert_eq!(req.completion_fd(), Some(fd), "The fd should be created once");
+    let mut pollfd = libc::pollfd { fd, events: libc::POLLIN, revents: 0 };
+    let ready = unsafe { libc::poll(&mut pollfd, 1, 10_000) };
+    assert_eq!(ready, 1, "Completion fd did not become readable");
+    assert!(agent1.get_xfer_status(&req).unwrap().is_success());
+}
+
+// Waits until `agent` receives `message` from `remote`, discarding other
+// notifications from `remote`
+fn wait_for_notification(agent: &Agent, remote: &str, message: &[u8]) {
+    let mut notifs = NotificationMap::new().unwrap();
+    loop {
+        agent.get_notifications_from(remote, &mut notifs, None).unwrap();
+        let received = notifs.take_notifs().unwrap();
+        if received
+            .get(remote)
+            .is_some_and(|messages| messages.iter().any(|m| m.as_bytes() == message))
+        {
+            return;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+}
+
+#[test]
+fn test_xfer_checksum() {
+    let (agent1, opt_args1) = create_agent_with_backend("checksum_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("checksum_agent2").unwrap();
+    let mut local_storage = create_storage_list(&agent1, &opt_args1, 1);
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 1);
+    exchange_metadata(&agent1, &agent2).unwrap();
+    local_storage[0].memset(0x5a);
+
+    let local_dlist = create_dlist(&mut local_storage).unwrap();
+    let remote_dlist = create_dlist(&mut remote_storage).unwrap();
+
+    // The checksum is identified by the transfer's notification message
+    let untagged = OptArgs::builder().checksum(true).build().unwrap();
+    assert!(untagged.checksum_enabled());
+    assert!(matches!(
+        agent1.create_xfer_req(XferOp::Write, &local_dlist, &remote_dlist, "checksum_agent2", Some(&untagged)),
+        Err(NixlError::InvalidParam)
+    ));
+
+    let mut reqs = Vec::new();
+    for tag in [b"ck-1", b"ck-2"] {
+        let mut args = OptArgs::new().unwrap();
+        args.set_notification(tag).unwrap();
+        args.enable_checksum(true);
+        let req = agent1
+            .create_xfer_req(XferOp::Write, &local_dlist, &remote_dlist, "checksum_agent2", Some(&args))
+            .unwrap();
+        agent1.post_xfer_req(&req, None).unwrap();
+        agent1
+            .wait_xfer(&req, std::time::Duration::from_millis(10), Some(std::time::Duration::from_secs(10)))
+            .unwrap();
+        wait_for_notification(&agent2, "checksum_agent1", tag);
+        reqs.push(req);
+    }
+    drop(local_dlist);
+    drop(remote_dlist);
+    let local_regions = [&local_storage[0] as &dyn NixlDescriptor];
+
+    // Unrelated notifications are kept while waiting for the checksum
+    agent2.send_notification("checksum_agent1", b"unrelated", None).unwrap();
+    agent2
+        .send_checksum("checksum_agent1", b"ck-1", &[&remote_storage[0] as &dyn NixlDescriptor])
+        .unwrap();
+    agent1
+        .verify_checksum(&reqs[0], &local_regions, Some(std::time::Duration::from_secs(10)))
+        .expect("Checksums of identical data should match");
+    wait_for_notification(&agent1, "checksum_agent2", b"unrelated");
+
+    // Data changed on the remote side is detected
+    remote_storage[0].memset(0);
+    agent2
+        .send_checksum("checksum_agent1", b"ck-2", &[&remote_storage[0] as &dyn NixlDescriptor])
+        .unwrap();
+    assert!(matches!(
+        agent1.verify_checksum(&reqs[1], &local_regions, Some(std::time::Duration::from_secs(10))),
+        Err(NixlError::ChecksumMismatch { .. })
+    ));
+
+    // A checksum that is never sent is reported as such
+    assert!(matches!(
+        agent1.verify_checksum(&reqs[1], &local_regions, Some(std::time::Duration::from_millis(50))),
+        Err(NixlError::ChecksumNotReceived)
+    ));
+}
+
+#[test]
+fn test_wait_all() {
+    let (agent1, opt_args1) = create_agent_with_backend("wait_all_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("wait_all_agent2").unwrap();
+    let mut local_a = create_storage_list(&agent1, &opt_args1, 2);
+    let mut local_b = create_storage_list(&agent1, &opt_args1, 2);
+    let mut remote_a = create_storage_list(&agent2, &opt_args2, 2);
+    let mut remote_b = create_storage_list(&agent2, &opt_args2, 2);
+    exchange_metadata(&agent1, &agent2).unwrap();
+
+    let local_dlists = [create_dlist(&mut local_a).unwrap(), create_dlist(&mut local_b).unwrap()];
+    let remote_dlists = [create_dlist(&mut remote_a).unwrap(), create_dlist(&mut remote_b).unwrap()];
+    let reqs: Vec<XferRequest> = local_dlists
+        .iter()
+        .zip(&remote_dlists)
+        .map(|(local, remote)| {
+            agent1
+                .create_xfer_req(XferOp::Write, local, remote, "wait_all_agent2", None)
+                .unwrap()
+        })
+        .collect();
+    for req in &reqs {
+        agent1.post_xfer_req(req, None).unwrap();
+    }
+
+    let req_refs: Vec<&XferRequest> = reqs.iter().collect();
+    agent1
+        .wait_all(
+            &req_refs,
+            std::time::Duration::from_millis(10),
+            Some(std::time::Duration::from_secs(10)),
+        )
+        .expect("Failed to wait for transfers");
+    assert!(agent1.wait_all(&[], std::time::Duration::ZERO, Some(std::time::Duration::ZERO)).is_ok());
+}
+
+#[test]
+fn test_poll_xfer_request_on_other_thread() {
+    let (agent1, opt_args1) = create_agent_with_backend("poller_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("poller_agent2").unwrap();
+    let mut local_storage = create_storage_list(&agent1, &opt_args1, 1);
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 1);
+    exchange_metadata(&agent1, &agent2).unwrap();
+
+    let local_dlist = create_dlist(&mut local_storage).unwrap();
+    let remote_dlist = create_dlist(&mut remote_storage).unwrap();
+    let req = agent1
+        .create_xfer_req(XferOp::Write, &local_dlist, &remote_dlist, "poller_agent2", None)
+        .unwrap();
+    agent1.post_xfer_req(&req, None).unwrap();
+
+    let poller_agent = agent1.clone();
+    let poller = std::thread::spawn(move || {
+        while !poller_agent.get_xfer_status(&req).unwrap().is_success() {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+    });
+    poller.join().expect("Poller thread panicked");
+}
+
+#[test]
+fn test_get_xfer_progress() {
+    let (agent1, opt_args1) = create_agent_with_backend("progress_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("progress_agent2").unwrap();
+    let mut local_storage = create_storage_list(&agent1, &opt_args1, 2);
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 2);
+    exchange_metadata(&agent1, &agent2).unwrap();
+
+    let local_dlist = create_dlist(&mut local_storage).unwrap();
+    let remote_dlist = create_dlist(&mut remote_storage).unwrap();
+    let req = agent1
+        .create_xfer_req(XferOp::Write, &local_dlist, &remote_dlist, "progress_agent2", None)
+        .unwrap();
+    agent1.post_xfer_req(&req, None).unwrap();
+
+    // Byte-level progress is not reported while the transfer is in flight
+    let progress = loop {
+        match agent1.get_xfer_progress(&req) {
+            Ok(progress) => break progress,
+            Err(NixlError::Unsupported) => std::thread::sleep(std::time::Duration::from_millis(10)),
+            Err(e) => panic!("Failed to get transfer progress: {e}"),
+        }
+    };
+    assert_eq!(progress.bytes_total, 2 * 1024);
+    assert_eq!(progress.bytes_completed, progress.bytes_total);
+    assert_eq!(progress.fraction(), 1.0);
+}
+
+#[test]
+fn test_post_xfer_req_retry() {
+    let (agent1, opt_args1) = create_agent_with_backend("retry_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("retry_agent2").unwrap();
+    let mut local_storage = create_storage_list(&agent1, &opt_args1, 1);
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 1);
+    exchange_metadata(&agent1, &agent2).unwrap();
+
+    let local_dlist = create_dlist(&mut local_storage).unwrap();
+    let remote_dlist = create_dlist(&mut remote_storage).unwrap();
+    let req = agent1
+        .create_xfer_req(XferOp::Write, &local_dlist, &remote_dlist, "retry_agent2", None)
         .unwrap();
 
-    let _status = agent1.post_xfer_req(&xfer_req, None).unwrap();
+    let policy = RetryPolicy {
+        max_attempts: 5,
+        base_delay: std::time::Duration::from_millis(1),
+        max_delay: std::time::Duration::from_millis(10),
+    };
+    agent1
+        .post_xfer_req_retry(&req, None, policy)
+        .expect("Failed to post transfer request");
+    while !agent1.get_xfer_status(&req).unwrap().is_success() {
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
 
-    println!("Waiting for local completions");
+    // A vanished remote is a permanent error and is not retried
+    agent1.invalidate_remote_md("retry_agent2").unwrap();
+    assert!(matches!(
+        agent1.post_xfer_req_retry(&req, None, policy),
+        Err(NixlError::RemoteDisconnected)
+    ));
+}
 
-    loop {
-        let status = agent1.get_xfer_status(&xfer_req).unwrap();
+#[test]
+fn test_try_post_xfer_req() {
+    let (agent1, opt_args1) = create_agent_with_backend("try_post_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("try_post_agent2").unwrap();
+    let mut local_storage = create_storage_list(&agent1, &opt_args1, 1);
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 1);
+    exchange_metadata(&agent1, &agent2).unwrap();
+    let local_dlist = create_dlist(&mut local_storage).unwrap();
+    let remote_dlist = create_dlist(&mut remote_storage).unwrap();
+    let req = agent1
+        .create_xfer_req(XferOp::Write, &local_dlist, &remote_dlist, "try_post_agent2", None)
+        .unwrap();
 
-        if status.is_success() {
-            println!("Xfer req completed");
-            break;
-        } else {
-            println!("Xfer req not completed");
+    // A full queue leaves the request unposted, so it is simply posted again
+    let mut posted = false;
+    for _ in 0..1000 {
+        match agent1.try_post_xfer_req(&req, None).expect("Failed to post transfer request") {
+            PostOutcome::WouldBlock => std::thread::sleep(std::time::Duration::from_millis(1)),
+            PostOutcome::InProgress => {
+                agent1.wait_xfer(&req, std::time::Duration::from_millis(1), None).unwrap();
+                posted = true;
+                break;
+            }
+            PostOutcome::Completed => {
+                posted = true;
+                break;
+            }
         }
-        std::thread::sleep(std::time::Duration::from_millis(100));
     }
+    assert!(posted, "Backend queue should drain");
+    assert!(agent1.get_xfer_status(&req).unwrap().is_success());
+
+    agent1.invalidate_remote_md("try_post_agent2").unwrap();
+    assert!(matches!(
+        agent1.try_post_xfer_req(&req, None),
+        Err(NixlError::RemoteDisconnected)
+    ));
+}
 
-    let mut notifs = NotificationMap::new().unwrap();
-    let notify_map;
-    println!("Waiting for notifications");
-    std::thread::sleep(std::time::Duration::from_millis(100));
+#[test]
+fn test_post_xfer_req2() {
+    let (agent1, opt_args1) = create_agent_with_backend("post_state_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("post_state_agent2").unwrap();
+    let mut local_storage = create_storage_list(&agent1, &opt_args1, 1);
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 1);
+    exchange_metadata(&agent1, &agent2).unwrap();
+    let local_dlist = create_dlist(&mut local_storage).unwrap();
+    let remote_dlist = create_dlist(&mut remote_storage).unwrap();
+    let req = agent1
+        .create_xfer_req(XferOp::Write, &local_dlist, &remote_dlist, "post_state_agent2", None)
+        .unwrap();
 
-    loop {
-        agent2.get_notifications(&mut notifs, None).unwrap();
-        if !notifs.is_empty().unwrap() {
-            notify_map = notifs.take_notifs().unwrap();
-            assert_eq!(notify_map.len(), 1);
-            assert!(notifs.is_empty().unwrap());
-            break;
+    match agent1.post_xfer_req2(&req, None).expect("Failed to post transfer request") {
+        PostState::InProgress => {
+            agent1.wait_xfer(&req, std::time::Duration::from_millis(1), None).unwrap();
         }
-        std::thread::sleep(std::time::Duration::from_millis(100));
+        PostState::Completed => {}
     }
+    assert!(agent1.get_xfer_status(&req).unwrap().is_success());
+    assert!(PostState::InProgress.is_in_progress());
+    assert!(!PostState::Completed.is_in_progress());
+}
 
-    println!("Got notifications");
-
-    // Get first notification from first agent
-    let vals = notify_map.get("A1").unwrap();
-    assert_eq!(vals.len(), 1);
-    assert_eq!(vals[0], "notification");
+#[test]
+fn test_post_xfer_req_guarded() {
+    let (agent1, opt_args1) = create_agent_with_backend("in_flight_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("in_flight_agent2").unwrap();
+    let mut local_storage = create_storage_list(&agent1, &opt_args1, 1);
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 1);
+    exchange_metadata(&agent1, &agent2).unwrap();
+    local_storage[0].memset(0x5a);
+    let req = {
+        let local_dlist = create_dlist(&mut local_storage).unwrap();
+        let remote_dlist = create_dlist(&mut remote_storage).unwrap();
+        agent1
+            .create_xfer_req(XferOp::Write, &local_dlist, &remote_dlist, "in_flight_agent2", None)
+            .unwrap()
+    };
 
-    // Verify memory patterns
-    assert!(storage1.as_slice().iter().all(|&x| x == 0xbb));
-    assert!(storage2.as_slice().iter().all(|&x| x == 0xbb));
+    {
+        let mut in_flight = agent1
+            .post_xfer_req_guarded(&req, &mut [&mut local_storage[0]], None)
+            .expect("Failed to post transfer request");
+        in_flight.wait(std::time::Duration::from_millis(1), None).unwrap();
+        assert!(in_flight.is_done());
+    }
+    assert!(remote_storage[0].as_slice().iter().all(|&b| b == 0x5a));
+
+    // Dropping the guard early still waits for the transfer to be done
+    local_storage[0].memset(0xa5);
+    let in_flight = agent1
+        .post_xfer_req_guarded(&req, &mut [&mut local_storage[0]], None)
+        .expect("Failed to repost transfer request");
+    drop(in_flight);
+    assert!(agent1.get_xfer_status(&req).unwrap().is_success());
+    local_storage[0].memset(0);
+    assert!(remote_storage[0].as_slice().iter().all(|&b| b == 0xa5));
 }
 
 #[test]
@@ -635,6 +2768,263 @@ fn test_etcd_metadata_exchange() -> Result<(), NixlError> {
     Ok(())
 }
 
+#[test]
+fn test_etcd_namespace_isolation() -> Result<(), NixlError> {
+    let endpoints = match env::var("NIXL_ETCD_ENDPOINTS") {
+        Ok(endpoints) => endpoints,
+        Err(_) => {
+            println!("Skipping etcd test - NIXL_ETCD_ENDPOINTS not set");
+            return Ok(());
+        }
+    };
+
+    let config_for = |namespace: &str| AgentConfig {
+        etcd_endpoints: endpoints.split(',').map(ToString::to_string).collect(),
+        etcd_namespace: Some(namespace.to_string()),
+        ..Default::default()
+    };
+    let publisher = Agent::new_configured("EtcdNsPublisher", &config_for("/nixl/tenant_a"))?;
+    let same_tenant = Agent::new_configured("EtcdNsSameTenant", &config_for("/nixl/tenant_a"))?;
+    let other_tenant = Agent::new_configured("EtcdNsOtherTenant", &config_for("/nixl/tenant_b"))?;
+    for agent in [&publisher, &same_tenant, &other_tenant] {
+        let (_mems, params) = agent.get_plugin_params("UCX")?;
+        agent.create_backend("UCX", &params)?;
+    }
+
+    publisher.send_local_md(None)?;
+    same_tenant.fetch_remote_md("EtcdNsPublisher", None)?;
+    other_tenant.fetch_remote_md("EtcdNsPublisher", None)?;
+
+    // Fetching happens asynchronously, wait for the agent sharing the namespace
+    let mut found = false;
+    for _ in 0..50 {
+        if same_tenant.check_remote_metadata("EtcdNsPublisher", None) {
+            found = true;
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+    assert!(found, "Agent in the same namespace should see the metadata");
+    assert!(!other_tenant.check_remote_metadata("EtcdNsPublisher", None));
+
+    publisher.invalidate_local_md(None)?;
+    Ok(())
+}
+
+#[test]
+fn test_publish_md_scoped() -> Result<(), NixlError> {
+    let endpoints = match env::var("NIXL_ETCD_ENDPOINTS") {
+        Ok(endpoints) => endpoints,
+        Err(_) => {
+            println!("Skipping etcd test - NIXL_ETCD_ENDPOINTS not set");
+            return Ok(());
+        }
+    };
+
+    let config = AgentConfig {
+        etcd_endpoints: endpoints.split(',').map(ToString::to_string).collect(),
+        etcd_namespace: Some("/nixl/scoped_md".to_string()),
+        ..Default::default()
+    };
+    let publisher = Agent::new_configured("ScopedMdPublisher", &config)?;
+    let (_mems, params) = publisher.get_plugin_params("UCX")?;
+    publisher.create_backend("UCX", &params)?;
+
+    // Dropping the guard removes the metadata from etcd
+    drop(publisher.publish_md_scoped(None)?);
+    let watcher = Agent::new_configured("ScopedMdWatcher", &config)?;
+    let (_mems, params) = watcher.get_plugin_params("UCX")?;
+    watcher.create_backend("UCX", &params)?;
+    watcher.fetch_remote_md("ScopedMdPublisher", None)?;
+    std::thread::sleep(std::time::Duration::from_millis(500));
+    assert!(!watcher.check_remote_metadata("ScopedMdPublisher", None));
+
+    // A forgotten guard leaves the metadata published
+    publisher.publish_md_scoped(None)?.forget();
+    watcher.fetch_remote_md("ScopedMdPublisher", None)?;
+    let mut found = false;
+    for _ in 0..50 {
+        if watcher.check_remote_metadata("ScopedMdPublisher", None) {
+            found = true;
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+    assert!(found, "Forgotten guard should leave the metadata in etcd");
+
+    publisher.invalidate_local_md(None)?;
+    Ok(())
+}
+
+#[test]
+fn test_on_remote_invalidated() -> Result<(), NixlError> {
+    let (agent1, _) = create_agent_with_backend("invalidated_cb_agent1")?;
+    let (agent2, _) = create_agent_with_backend("invalidated_cb_agent2")?;
+    exchange_metadata(&agent1, &agent2)?;
+
+    let (sender, receiver) = std::sync::mpsc::channel();
+    agent1.on_remote_invalidated(move |remote| sender.send(remote.to_string()).unwrap());
+
+    // Remotes invalidated through the agent itself are not reported
+    std::thread::sleep(std::time::Duration::from_millis(300));
+    agent1.invalidate_remote_md("invalidated_cb_agent2")?;
+    assert!(receiver.recv_timeout(std::time::Duration::from_millis(300)).is_err());
+
+    let endpoints = match env::var("NIXL_ETCD_ENDPOINTS") {
+        Ok(endpoints) => endpoints,
+        Err(_) => {
+            println!("Skipping etcd part - NIXL_ETCD_ENDPOINTS not set");
+            return Ok(());
+        }
+    };
+    let config = AgentConfig {
+        etcd_endpoints: endpoints.split(',').map(ToString::to_string).collect(),
+        etcd_namespace: Some("/nixl/invalidation_cb".to_string()),
+        ..Default::default()
+    };
+    let publisher = Agent::new_configured("InvalidationCbPublisher", &config)?;
+    let watcher = Agent::new_configured("InvalidationCbWatcher", &config)?;
+    for agent in [&publisher, &watcher] {
+        let (_mems, params) = agent.get_plugin_params("UCX")?;
+        agent.create_backend("UCX", &params)?;
+    }
+
+    // A panicking callback does not keep the others from running, and
+    // callbacks may register further callbacks without deadlocking the watch
+    watcher.on_remote_invalidated(|_| panic!("Invalidation callback panic"));
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let registrar = watcher.clone();
+    watcher.on_remote_invalidated(move |remote| {
+        registrar.on_remote_invalidated(|_| {});
+        sender.send(remote.to_string()).unwrap();
+    });
+    publisher.send_local_md(None)?;
+    watcher.fetch_remote_md("InvalidationCbPublisher", None)?;
+    let mut found = false;
+    for _ in 0..50 {
+        if watcher.check_remote_metadata("InvalidationCbPublisher", None) {
+            found = true;
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+    assert!(found, "Watcher should fetch the published metadata");
+
+    publisher.invalidate_local_md(None)?;
+    let departed = receiver
+        .recv_timeout(std::time::Duration::from_secs(10))
+        .expect("Invalidation should be reported");
+    assert_eq!(departed, "InvalidationCbPublisher");
+    assert!(!watcher.is_remote_loaded("InvalidationCbPublisher"));
+    Ok(())
+}
+
+#[test]
+fn test_get_notifications_from() {
+    let (sender1, _) = create_agent_with_backend("notif_from_sender1").unwrap();
+    let (sender2, _) = create_agent_with_backend("notif_from_sender2").unwrap();
+    let (receiver, _) = create_agent_with_backend("notif_from_receiver").unwrap();
+    exchange_metadata(&sender1, &receiver).unwrap();
+    exchange_metadata(&sender2, &receiver).unwrap();
+
+    sender2.send_notification("notif_from_receiver", b"from sender2", None).unwrap();
+    sender1.send_notification("notif_from_receiver", b"from sender1", None).unwrap();
+
+    let mut notifs = NotificationMap::new().unwrap();
+    let from_sender1 = loop {
+        receiver
+            .get_notifications_from("notif_from_sender1", &mut notifs, None)
+            .unwrap();
+        if !notifs.is_empty().unwrap() {
+            break notifs.take_notifs().unwrap();
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    };
+    assert_eq!(from_sender1.len(), 1);
+    assert_eq!(from_sender1["notif_from_sender1"], vec!["from sender1".to_string()]);
+
+    // Notifications from other agents are kept for later calls
+    let rest = loop {
+        receiver.get_notifications(&mut notifs, None).unwrap();
+        if !notifs.is_empty().unwrap() {
+            break notifs.take_notifs().unwrap();
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    };
+    assert_eq!(rest.len(), 1);
+    assert_eq!(rest["notif_from_sender2"], vec!["from sender2".to_string()]);
+}
+
+#[test]
+fn test_get_notifications_limited() {
+    let (sender, _) = create_agent_with_backend("notif_limit_sender").unwrap();
+    let (receiver, _) = create_agent_with_backend("notif_limit_receiver").unwrap();
+    exchange_metadata(&sender, &receiver).unwrap();
+
+    for i in 0..5 {
+        sender.send_notification("notif_limit_receiver", format!("msg{i}").as_bytes(), None).unwrap();
+    }
+
+    let mut received = Vec::new();
+    let mut more = true;
+    while received.len() < 5 || more {
+        let mut notifs = NotificationMap::new().unwrap();
+        more = receiver.get_notifications_limited(&mut notifs, 2, None).unwrap();
+        let batch = notifs.take_notifs().unwrap();
+        let count: usize = batch.values().map(Vec::len).sum();
+        assert!(count <= 2);
+        if count == 0 {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        received.extend(batch.into_values().flatten());
+    }
+    let expected: Vec<String> = (0..5).map(|i| format!("msg{i}")).collect();
+    assert_eq!(received, expected);
+
+    let mut notifs = NotificationMap::new().unwrap();
+    assert!(!receiver.get_notifications_limited(&mut notifs, 0, None).unwrap());
+    assert!(notifs.is_empty().unwrap());
+}
+
+#[test]
+fn test_notifications_str() {
+    let (sender, _) = create_agent_with_backend("notif_str_sender").unwrap();
+    let (receiver, _) = create_agent_with_backend("notif_str_receiver").unwrap();
+    exchange_metadata(&sender, &receiver).unwrap();
+
+    sender.send_notification_str("notif_str_receiver", "héllo", None).unwrap();
+    sender.send_notification("notif_str_receiver", &[0xff, 0xfe], None).unwrap();
+
+    let mut notifs = NotificationMap::new().unwrap();
+    let mut received = Vec::new();
+    while received.len() < 2 {
+        receiver.get_notifications(&mut notifs, None).unwrap();
+        received = notifs
+            .iter_str()
+            .map(|(agent, message)| (agent.to_string(), message.map(str::to_string)))
+            .collect();
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    assert!(received.iter().all(|(agent, _)| agent == "notif_str_sender"));
+    assert!(received.iter().any(|(_, message)| message.as_deref() == Ok("héllo")));
+    // Binary notifications are reported as invalid UTF-8 instead of being dropped
+    let binary = received.iter().position(|(_, message)| message.is_err()).unwrap();
+    assert_eq!(notifs.get_notification_bytes("notif_str_sender", binary).unwrap(), [0xff, 0xfe]);
+}
+
+#[test]
+fn test_send_notification_remote_not_loaded() {
+    let agent = Agent::new("NotifUnloadedSender").expect("Failed to create agent");
+    let (_mem_list, params) = agent.get_plugin_params("UCX").expect("Failed to get plugin params");
+    agent.create_backend("UCX", &params).expect("Failed to create backend");
+
+    match agent.send_notification("NotifNobody", b"hello", None) {
+        Err(NixlError::RemoteNotLoaded(name)) => assert_eq!(name, "NotifNobody"),
+        other => panic!("Expected RemoteNotLoaded, got {other:?}"),
+    }
+    assert!(agent.send_notification_unchecked("NotifNobody", b"hello", None).is_err());
+}
+
 #[test]
 fn test_send_notification() -> Result<(), NixlError> {
     // Create two agents for notification exchange
@@ -695,6 +3085,33 @@ fn test_send_notification() -> Result<(), NixlError> {
     Ok(())
 }
 
+#[test]
+fn test_notification_stream() {
+    let agent1 = Agent::new("StreamSender").unwrap();
+    let agent2 = Agent::new("StreamReceiver").unwrap();
+
+    let (_mem_list, params) = agent1.get_plugin_params("UCX").unwrap();
+    let _backend1 = agent1.create_backend("UCX", &params).unwrap();
+    let _backend2 = agent2.create_backend("UCX", &params).unwrap();
+
+    let metadata = agent2.get_local_md().unwrap();
+    agent1.load_remote_md(&metadata).unwrap();
+
+    let stream = agent2.notification_stream(std::time::Duration::from_millis(10));
+    agent1.send_notification("StreamReceiver", b"first", None).unwrap();
+    agent1.send_notification("StreamReceiver", b"second", None).unwrap();
+
+    let timeout = std::time::Duration::from_secs(5);
+    let (sender, message) = stream.recv_timeout(timeout).expect("Missing first notification");
+    assert_eq!(sender, "StreamSender");
+    assert_eq!(message, b"first");
+    let (_, message) = stream.recv_timeout(timeout).expect("Missing second notification");
+    assert_eq!(message, b"second");
+
+    // Dropping the stream stops the background thread
+    drop(stream);
+}
+
 #[test]
 fn test_check_remote_metadata() {
     // Create two agents
@@ -773,6 +3190,153 @@ fn test_check_remote_metadata() {
     assert!(!agent1.check_remote_metadata(invalid_name, None));
 }
 
+#[test]
+fn test_count_remote_metadata() {
+    let (agent1, _opt_args1) = create_agent_with_backend("count_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("count_agent2").unwrap();
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 2);
+    exchange_metadata(&agent1, &agent2).unwrap();
+
+    let mut descs = create_dlist(&mut remote_storage).unwrap();
+    assert_eq!(agent1.count_remote_metadata("count_agent2", &descs).unwrap(), 2);
+
+    // An unregistered region is not covered
+    descs.add_desc(0xdeadbeef, 1024, 0).unwrap();
+    assert_eq!(agent1.count_remote_metadata("count_agent2", &descs).unwrap(), 2);
+    assert!(!agent1.check_remote_metadata("count_agent2", Some(&descs)));
+
+    assert_eq!(agent1.count_remote_metadata("unknown_agent", &descs).unwrap(), 0);
+}
+
+#[test]
+fn test_invalidate_remotes() {
+    let (agent1, _opt_args1) = create_agent_with_backend("bulk_agent1").unwrap();
+    let (agent2, _opt_args2) = create_agent_with_backend("bulk_agent2").unwrap();
+    let (agent3, _opt_args3) = create_agent_with_backend("bulk_agent3").unwrap();
+
+    agent1.load_remote_md(&agent2.get_local_md().unwrap()).unwrap();
+    agent1.load_remote_md(&agent3.get_local_md().unwrap()).unwrap();
+
+    let not_found = agent1
+        .invalidate_remotes(&["bulk_agent2", "missing_agent"])
+        .expect("Failed to invalidate remotes");
+    assert_eq!(not_found, vec!["missing_agent".to_string()]);
+
+    // The invalidated remote is gone, the other one is untouched
+    assert!(matches!(
+        agent1.invalidate_remote_md("bulk_agent2"),
+        Err(NixlError::InvalidParam)
+    ));
+    assert!(agent1.check_remote_metadata("bulk_agent3", None));
+}
+
+#[test]
+fn test_xfer_request_outliving_agent() {
+    let (agent1, opt_args1) = create_agent_with_backend("outlived_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("outlived_agent2").unwrap();
+    let mut local_storage = create_storage_list(&agent1, &opt_args1, 1);
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 1);
+    local_storage[0].memset(0x3c);
+    exchange_metadata(&agent1, &agent2).unwrap();
+
+    let req = {
+        let local_dlist = create_dlist(&mut local_storage).unwrap();
+        let remote_dlist = create_dlist(&mut remote_storage).unwrap();
+        let req = agent1
+            .create_xfer_req(XferOp::Write, &local_dlist, &remote_dlist, "outlived_agent2", None)
+            .unwrap();
+        agent1.post_xfer_req(&req, None).unwrap();
+        req
+    };
+
+    // The request keeps the C agent alive after the last handle is gone
+    drop(opt_args1);
+    drop(agent1);
+    let agent = req.agent();
+    assert_eq!(agent.name(), "outlived_agent1");
+    while agent.get_xfer_status(&req).unwrap() == XferStatus::InProgress {
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+    assert!(remote_storage[0].as_slice().iter().all(|&b| b == 0x3c));
+
+    drop(agent);
+    drop(req);
+}
+
+#[test]
+fn test_invalidate_remote_twice_then_drop() {
+    let (agent1, _opt_args1) = create_agent_with_backend("twice_agent1").unwrap();
+    let (agent2, _opt_args2) = create_agent_with_backend("twice_agent2").unwrap();
+    exchange_metadata(&agent1, &agent2).unwrap();
+
+    agent1.invalidate_remote_md("twice_agent2").unwrap();
+    assert!(matches!(
+        agent1.invalidate_remote_md("twice_agent2"),
+        Err(NixlError::InvalidParam)
+    ));
+    agent1.invalidate_all_remotes().unwrap();
+
+    // Dropping must not invalidate the remote on the C side again
+    drop(agent1);
+    drop(agent2);
+}
+
+#[test]
+fn test_loaded_remotes() {
+    let (agent1, _opt_args1) = create_agent_with_backend("loaded_agent1").unwrap();
+    let (agent2, _opt_args2) = create_agent_with_backend("loaded_agent2").unwrap();
+    let (agent3, _opt_args3) = create_agent_with_backend("loaded_agent3").unwrap();
+    assert!(agent1.loaded_remotes().is_empty());
+
+    agent1.load_remote_md(&agent3.get_local_md().unwrap()).unwrap();
+    agent1.load_remote_md(&agent2.get_local_md().unwrap()).unwrap();
+    assert_eq!(agent1.loaded_remotes(), vec!["loaded_agent2", "loaded_agent3"]);
+
+    assert!(agent1.is_remote_loaded("loaded_agent2"));
+
+    agent1.invalidate_remote_md("loaded_agent2").unwrap();
+    assert_eq!(agent1.loaded_remotes(), vec!["loaded_agent3"]);
+    assert!(!agent1.is_remote_loaded("loaded_agent2"));
+    assert!(agent1.is_remote_loaded("loaded_agent3"));
+
+    agent1.invalidate_all_remotes().unwrap();
+    assert!(agent1.loaded_remotes().is_empty());
+}
+
+#[test]
+fn test_load_remotes() {
+    let (agent1, _opt_args1) = create_agent_with_backend("seeded_agent1").unwrap();
+    let (agent2, _opt_args2) = create_agent_with_backend("seeded_agent2").unwrap();
+    let (agent3, _opt_args3) = create_agent_with_backend("seeded_agent3").unwrap();
+    let md2 = agent2.get_local_md().unwrap();
+    let md3 = agent3.get_local_md().unwrap();
+
+    // A malformed blob names its peer and rolls back the peers loaded before it
+    let result = agent1.load_remotes(&[
+        ("seeded_agent2".to_string(), md2.clone()),
+        ("bad_peer".to_string(), b"not metadata".to_vec()),
+    ]);
+    assert!(matches!(result, Err(NixlError::RemoteLoadFailed { ref remote, .. }) if remote == "bad_peer"));
+    assert!(agent1.loaded_remotes().is_empty());
+
+    // So does a blob of another agent
+    let result = agent1.load_remotes(&[("seeded_agent2".to_string(), md3.clone())]);
+    assert!(matches!(result, Err(NixlError::RemoteLoadFailed { ref remote, .. }) if remote == "seeded_agent2"));
+    assert!(agent1.loaded_remotes().is_empty());
+
+    agent1
+        .load_remotes(&[("seeded_agent2".to_string(), md2), ("seeded_agent3".to_string(), md3)])
+        .expect("Failed to load remotes");
+    assert_eq!(agent1.loaded_remotes(), vec!["seeded_agent2", "seeded_agent3"]);
+
+    let agent = Agent::with_remotes("seeded_agent4", &[]).expect("Failed to create agent");
+    assert!(agent.loaded_remotes().is_empty());
+    assert!(matches!(
+        Agent::with_remotes("seeded_agent5", &[("bad_peer".to_string(), Vec::new())]),
+        Err(NixlError::RemoteLoadFailed { ref remote, .. }) if remote == "bad_peer"
+    ));
+}
+
 #[test]
 fn test_xfer_desc_list_new() {
     let dlist = XferDescList::new(MemType::Dram).unwrap();
@@ -1074,6 +3638,11 @@ fn test_query_mem_with_files() {
         "Third file should not exist"
     );
 
+    let present: Vec<bool> = (&resp).into_iter().map(|response| response.is_present()).collect();
+    assert_eq!(present, vec![true, true, false]);
+    assert!(responses[0].params().is_some());
+    assert!(responses[2].params().is_none());
+
     // Print parameters for existing files
     for (i, response) in responses.iter().enumerate() {
         if response.has_value().unwrap() {
@@ -1125,6 +3694,53 @@ fn test_query_mem_empty_list() {
     );
 }
 
+#[test]
+fn test_file_and_object_descriptors() {
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let path = temp_dir.path().join("segment.bin");
+    std::fs::write(&path, vec![0u8; 4096]).unwrap();
+
+    let file = FileDescriptor::open(&path, 1024, 2048).expect("Failed to open file descriptor");
+    assert_eq!(file.mem_type(), MemType::File);
+    assert_eq!(file.device_id(), file.fd() as u64);
+    assert_eq!(file.offset(), 1024);
+    assert_eq!(file.path(), path.to_str());
+    assert_eq!(file.meta_info(), path.to_str().unwrap().as_bytes());
+
+    let borrowed = FileDescriptor::from_fd(file.fd(), 0, 512).unwrap();
+    assert_eq!(borrowed.path(), None);
+    assert!(borrowed.meta_info().is_empty());
+
+    assert!(matches!(FileDescriptor::open("", 0, 1), Err(NixlError::InvalidParam)));
+    assert!(matches!(FileDescriptor::from_fd(-1, 0, 1), Err(NixlError::InvalidParam)));
+    assert!(matches!(FileDescriptor::from_fd(0, u64::MAX, 1), Err(NixlError::InvalidParam)));
+    assert!(matches!(
+        FileDescriptor::open(temp_dir.path().join("missing"), 0, 1),
+        Err(NixlError::BackendError)
+    ));
+
+    let object = ObjectDescriptor::new("checkpoints/shard-0", 0, 4096, 7).unwrap();
+    assert_eq!(object.mem_type(), MemType::Object);
+    assert_eq!(object.device_id(), 7);
+    assert_eq!(object.meta_info(), b"checkpoints/shard-0");
+    assert!(matches!(ObjectDescriptor::new("", 0, 1, 0), Err(NixlError::InvalidParam)));
+    assert!(matches!(ObjectDescriptor::new("key", u64::MAX, 2, 0), Err(NixlError::InvalidParam)));
+
+    // The path is registered as metadata, so the POSIX backend can find the file
+    let agent = Agent::new("file_desc_agent").expect("Failed to create agent");
+    let Some((_backend, opt_args)) = create_posix_backend(&agent) else {
+        return;
+    };
+    let mut descs = RegDescList::new(MemType::File).unwrap();
+    descs.add_storage_desc(&file).unwrap();
+    let resp = agent.query_mem(&descs, Some(&opt_args)).expect("Failed to query mem");
+    assert!(resp.iter().unwrap().next().unwrap().is_present());
+
+    let _handle = agent
+        .register_memory(&file, Some(&opt_args))
+        .expect("Failed to register file descriptor");
+}
+
 // Tests for prep_xfer_dlist API
 #[test]
 fn test_prep_xfer_dlist_success() {
@@ -1171,6 +3787,90 @@ fn test_prep_xfer_dlist_invalid_agent() {
 }
 
 // Tests for make_xfer_req API
+#[test]
+fn test_register_and_prepare() {
+    let (agent1, opt_args1) = create_agent_with_backend("reg_prep_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("reg_prep_agent2").unwrap();
+    let mut local = [SystemStorage::new(1024).unwrap(), SystemStorage::new(1024).unwrap()];
+    let remote = [SystemStorage::new(1024).unwrap(), SystemStorage::new(1024).unwrap()];
+    local[0].memset(0x11);
+    local[1].memset(0x22);
+
+    let mut local_regs = RegDescList::new(MemType::Dram).unwrap();
+    let mut remote_regs = RegDescList::new(MemType::Dram).unwrap();
+    for (l, r) in local.iter().zip(&remote) {
+        local_regs.add_storage_desc(l).unwrap();
+        remote_regs.add_storage_desc(r).unwrap();
+    }
+
+    let (local_handles, local_prepped) = agent1
+        .register_and_prepare("", &local_regs, Some(&opt_args1))
+        .expect("Failed to register and prepare");
+    let (remote_handles, _) = agent2
+        .register_and_prepare("", &remote_regs, Some(&opt_args2))
+        .expect("Failed to register and prepare");
+    assert_eq!(local_handles.len(), 2);
+    assert_eq!(remote_handles.len(), 2);
+    assert_eq!(agent1.registrations().len(), 2);
+
+    exchange_metadata(&agent1, &agent2).unwrap();
+    let remote_prepped = agent1
+        .prepare_xfer_dlist("reg_prep_agent2", &remote_regs.to_xfer_dlist().unwrap(), Some(&opt_args1))
+        .unwrap();
+    let req = agent1
+        .make_xfer_req(XferOp::Write, &local_prepped, &[0, 1], &remote_prepped, &[1, 0], None)
+        .unwrap();
+    agent1.post_xfer_req(&req, None).unwrap();
+    agent1.wait_xfer(&req, std::time::Duration::from_millis(1), None).unwrap();
+    assert!(remote[0].as_slice().iter().all(|&b| b == 0x22));
+    assert!(remote[1].as_slice().iter().all(|&b| b == 0x11));
+
+    drop(local_handles);
+    assert!(agent1.registrations().is_empty());
+
+    let empty = RegDescList::new(MemType::Dram).unwrap();
+    assert!(matches!(agent1.register_and_prepare("", &empty, None), Err(NixlError::InvalidParam)));
+}
+
+#[test]
+fn test_prepare_xfer_dlist_cached() {
+    let (local_agent, opt_args) = create_agent_with_backend("prep_cache_local").expect("Failed to create agent");
+    let (remote_agent, opt_args_remote) = create_agent_with_backend("prep_cache_remote").expect("Failed to create agent");
+    let mut storage_list = create_storage_list(&local_agent, &opt_args, 2);
+    let mut remote_storage_list = create_storage_list(&remote_agent, &opt_args_remote, 2);
+    exchange_metadata(&local_agent, &remote_agent).expect("Failed to exchange metadata");
+
+    let remote_dlist = create_dlist(&mut remote_storage_list).expect("Failed to create XferDescList");
+    let first = local_agent
+        .prepare_xfer_dlist_cached("prep_cache_remote", &remote_dlist, Some(&opt_args))
+        .expect("Failed to prepare remote descriptor list");
+    let second = local_agent
+        .prepare_xfer_dlist_cached("prep_cache_remote", &remote_dlist, Some(&opt_args))
+        .expect("Failed to prepare remote descriptor list");
+    assert!(std::sync::Arc::ptr_eq(&first, &second), "Same descriptors should reuse the prepared list");
+
+    // A different descriptor set is prepared separately
+    let local_dlist = create_dlist(&mut storage_list).expect("Failed to create XferDescList");
+    let local = local_agent
+        .prepare_xfer_dlist_cached("", &local_dlist, Some(&opt_args))
+        .expect("Failed to prepare local descriptor list");
+    assert!(!std::sync::Arc::ptr_eq(&first, &local));
+
+    // Invalidating the remote drops its cached lists
+    local_agent.invalidate_remote_md("prep_cache_remote").expect("Failed to invalidate remote");
+    exchange_metadata(&local_agent, &remote_agent).expect("Failed to exchange metadata");
+    let third = local_agent
+        .prepare_xfer_dlist_cached("prep_cache_remote", &remote_dlist, Some(&opt_args))
+        .expect("Failed to prepare remote descriptor list");
+    assert!(!std::sync::Arc::ptr_eq(&first, &third));
+
+    local_agent.clear_prep_cache();
+    let fourth = local_agent
+        .prepare_xfer_dlist_cached("prep_cache_remote", &remote_dlist, Some(&opt_args))
+        .expect("Failed to prepare remote descriptor list");
+    assert!(!std::sync::Arc::ptr_eq(&third, &fourth));
+}
+
 #[test]
 fn test_make_xfer_req_success() {
     const DLIST_SIZE: usize = 10;
@@ -1215,6 +3915,37 @@ fn test_make_xfer_req_success() {
     }
 }
 
+#[test]
+fn test_make_xfer_req_with_scratch() {
+    const DLIST_SIZE: usize = 8;
+    let (agent1, opt_args1) = create_agent_with_backend("scratch_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("scratch_agent2").unwrap();
+    let mut local_storage = create_storage_list(&agent1, &opt_args1, DLIST_SIZE);
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, DLIST_SIZE);
+    exchange_metadata(&agent1, &agent2).unwrap();
+    let local_dlist = create_dlist(&mut local_storage).unwrap();
+    let remote_dlist = create_dlist(&mut remote_storage).unwrap();
+    let local_handle = agent1.prepare_xfer_dlist("", &local_dlist, None).unwrap();
+    let remote_handle = agent1.prepare_xfer_dlist("scratch_agent2", &remote_dlist, None).unwrap();
+
+    let mut scratch = XferReqScratch::with_capacity(DLIST_SIZE);
+    for count in [DLIST_SIZE as i32, 2] {
+        scratch.set_indices(0..count, (0..count).rev());
+        assert_eq!(scratch.local_indices().len(), count as usize);
+        assert_eq!(scratch.remote_indices()[0], count - 1);
+        let req = agent1
+            .make_xfer_req_with_scratch(XferOp::Write, &local_handle, &remote_handle, &scratch, None)
+            .expect("Failed to make transfer request");
+        assert_eq!(req.debug_state(&agent1).unwrap().desc_count, count as usize);
+    }
+
+    scratch.set_indices([999], [999]);
+    assert!(matches!(
+        agent1.make_xfer_req_with_scratch(XferOp::Write, &local_handle, &remote_handle, &scratch, None),
+        Err(NixlError::BackendError)
+    ));
+}
+
 #[test]
 fn test_make_xfer_req_invalid_indices() {
     const DLIST_SIZE: usize = 10;
@@ -1279,6 +4010,120 @@ fn test_get_local_partial_md_success() {
     }
 }
 
+#[test]
+fn test_load_remote_partial_md() {
+    let (agent1, _opt_args1) = create_agent_with_backend("partial_load_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("partial_load_agent2").unwrap();
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 2);
+    let metadata = agent2.get_local_md().unwrap();
+
+    // Descriptors covered by the metadata are accepted
+    let covered = create_dlist(&mut remote_storage).unwrap();
+    let name = agent1
+        .load_remote_partial_md(&metadata, &covered)
+        .expect("Failed to load partial metadata");
+    assert_eq!(name, "partial_load_agent2");
+    agent1.invalidate_remote_md(&name).unwrap();
+
+    // Regions that were not asked for are left out
+    let mut first = XferDescList::new(MemType::Dram).unwrap();
+    first.add_storage_desc(&remote_storage[0]).unwrap();
+    let mut second = XferDescList::new(MemType::Dram).unwrap();
+    second.add_storage_desc(&remote_storage[1]).unwrap();
+    agent1
+        .load_remote_partial_md(&metadata, &first)
+        .expect("Failed to load partial metadata");
+    assert!(agent1.check_remote_metadata("partial_load_agent2", Some(&first)));
+    assert!(!agent1.check_remote_metadata("partial_load_agent2", Some(&second)));
+    agent1.invalidate_remote_md("partial_load_agent2").unwrap();
+
+    // A descriptor missing from the metadata rolls back the load
+    let mut missing = XferDescList::new(MemType::Dram).unwrap();
+    missing.add_desc(0xdeadbeef, 1024, 0).unwrap();
+    assert!(matches!(
+        agent1.load_remote_partial_md(&metadata, &missing),
+        Err(NixlError::InvalidParam)
+    ));
+    assert!(!agent1.is_remote_loaded("partial_load_agent2"));
+}
+
+#[test]
+fn test_reload_remote_md() {
+    let (agent1, _opt_args1) = create_agent_with_backend("reload_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("reload_agent2").unwrap();
+    let (agent3, _opt_args3) = create_agent_with_backend("reload_agent3").unwrap();
+    agent1.load_remote_md(&agent2.get_local_md().unwrap()).unwrap();
+
+    // Buffers registered after the first load are only visible after a reload
+    let mut new_storage = create_storage_list(&agent2, &opt_args2, 1);
+    let new_descs = create_dlist(&mut new_storage).unwrap();
+    assert!(!agent1.check_remote_metadata("reload_agent2", Some(&new_descs)));
+
+    let name = agent1
+        .reload_remote_md("reload_agent2", &agent2.get_local_md().unwrap())
+        .expect("Failed to reload remote metadata");
+    assert_eq!(name, "reload_agent2");
+    assert!(agent1.is_remote_loaded("reload_agent2"));
+    assert!(agent1.check_remote_metadata("reload_agent2", Some(&new_descs)));
+
+    // Metadata of another agent is rejected and not left loaded
+    assert!(matches!(
+        agent1.reload_remote_md("reload_agent2", &agent3.get_local_md().unwrap()),
+        Err(NixlError::InvalidParam)
+    ));
+    assert!(!agent1.is_remote_loaded("reload_agent3"));
+}
+
+#[test]
+fn test_partial_md_without_conn_info() {
+    let (agent1, opt_args1) = create_agent_with_backend("partial_conn_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("partial_conn_agent2").unwrap();
+    let mut local_storage = create_storage_list(&agent1, &opt_args1, 1);
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 1);
+
+    // Connection info only, used to set up the connection ahead of time
+    let no_descs = RegDescList::new(MemType::Dram).unwrap();
+    let conn_md = agent2.get_local_partial_md(&no_descs, None).unwrap();
+    agent1.load_remote_md(&conn_md).unwrap();
+    agent1.make_connection("partial_conn_agent2", None).unwrap();
+
+    // Descriptor metadata only, sent over the existing connection
+    let desc_opt_args = OptArgs::builder()
+        .backend(&agent2.get_backend("UCX").unwrap())
+        .include_conn_info(false)
+        .build()
+        .unwrap();
+    assert!(!desc_opt_args.include_conn_info().unwrap());
+    let mut reg_descs = RegDescList::new(MemType::Dram).unwrap();
+    reg_descs.add_storage_desc(&remote_storage[0]).unwrap();
+    let desc_md = agent2.get_local_partial_md(&reg_descs, Some(&desc_opt_args)).unwrap();
+    agent1.load_remote_md(&desc_md).unwrap();
+
+    let mut with_conn_args = OptArgs::new().unwrap();
+    with_conn_args.set_include_conn_info(true).unwrap();
+    assert!(with_conn_args.include_conn_info().unwrap());
+    let full_md = agent2.get_local_partial_md(&reg_descs, Some(&with_conn_args)).unwrap();
+    assert!(desc_md.len() < full_md.len());
+    drop(reg_descs);
+
+    local_storage[0].memset(0xab);
+    let local_dlist = create_dlist(&mut local_storage).unwrap();
+    let remote_dlist = create_dlist(&mut remote_storage).unwrap();
+    let req = agent1
+        .create_xfer_req(XferOp::Write, &local_dlist, &remote_dlist, "partial_conn_agent2", None)
+        .unwrap();
+    agent1.post_xfer_req(&req, None).unwrap();
+    agent1
+        .wait_xfer(
+            &req,
+            std::time::Duration::from_millis(10),
+            Some(std::time::Duration::from_secs(10)),
+        )
+        .expect("Transfer over pre-made connection failed");
+    drop(remote_dlist);
+    assert!(remote_storage[0].as_slice().iter().all(|&x| x == 0xab));
+}
+
 #[test]
 fn test_get_local_partial_md_empty_descs() {
     let (agent, _) = create_agent_with_backend("test_agent")
@@ -1345,6 +4190,7 @@ fn test_query_xfer_backend_success() {
         assert!(result.is_ok(), "query_xfer_backend failed with error: {:?}", result.err());
         let backend = result.unwrap();
         println!("Transfer will use backend: {:?}", backend);
+        assert_eq!(backend.plugin_name(), "UCX");
    }
 }
 #[test]
@@ -1369,6 +4215,57 @@ fn test_query_xfer_backend_invalid_request() {
  }
 }
 
+#[test]
+fn test_query_xfer_backend_before_post() {
+    let (agent1, opt_args1) = create_agent_with_backend("backend_pre_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("backend_pre_agent2").unwrap();
+    let mut local_storage = create_storage_list(&agent1, &opt_args1, 1);
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 1);
+    exchange_metadata(&agent1, &agent2).unwrap();
+    let local_dlist = create_dlist(&mut local_storage).unwrap();
+    let remote_dlist = create_dlist(&mut remote_storage).unwrap();
+    let req = agent1
+        .create_xfer_req(XferOp::Write, &local_dlist, &remote_dlist, "backend_pre_agent2", None)
+        .unwrap();
+
+    let backend = agent1.query_xfer_backend(&req).expect("Failed to query backend before posting");
+    assert_eq!(backend.plugin_name(), "UCX");
+    assert!(matches!(agent2.query_xfer_backend(&req), Err(NixlError::InvalidParam)));
+
+    agent1.post_xfer_req(&req, None).unwrap();
+    agent1.wait_xfer(&req, std::time::Duration::from_millis(1), None).unwrap();
+    assert_eq!(agent1.query_xfer_backend(&req).unwrap().plugin_name(), "UCX");
+}
+
+#[test]
+fn test_xfer_debug_state() {
+    let (agent1, opt_args1) = create_agent_with_backend("debug_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("debug_agent2").unwrap();
+    let mut local_storage = create_storage_list(&agent1, &opt_args1, 2);
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 2);
+    exchange_metadata(&agent1, &agent2).unwrap();
+    let local_dlist = create_dlist(&mut local_storage).unwrap();
+    let remote_dlist = create_dlist(&mut remote_storage).unwrap();
+    let req = agent1
+        .create_xfer_req(XferOp::Write, &local_dlist, &remote_dlist, "debug_agent2", None)
+        .unwrap();
+
+    let info = req.debug_state(&agent1).expect("Failed to get debug state");
+    assert_eq!(info.op, XferOp::Write);
+    assert_eq!(info.desc_count, 2);
+    assert_eq!(info.remote_agent.as_deref(), Some("debug_agent2"));
+    assert_eq!(info.backend.as_deref(), Some("UCX"));
+    assert_eq!(info.backend_status, "not posted");
+
+    agent1.post_xfer_req(&req, None).unwrap();
+    agent1.wait_xfer(&req, std::time::Duration::from_millis(1), None).unwrap();
+    assert_eq!(req.debug_state(&agent1).unwrap().backend_status, "complete");
+    // Repeated calls do not change the request
+    assert_eq!(req.debug_state(&agent1).unwrap().backend_status, "complete");
+
+    assert!(matches!(req.debug_state(&agent2), Err(NixlError::InvalidParam)));
+}
+
 // Tests for get_xfer_telemetry API
 #[test]
 fn test_get_xfer_telemetry_success() {
@@ -1405,6 +4302,7 @@ fn test_get_xfer_telemetry_success() {
                     std::thread::sleep(std::time::Duration::from_millis(10));
                     continue;
                 }
+                Ok(XferStatus::Failed { code }) => panic!("Transfer failed with code {code}"),
                 Err(e) => panic!("Failed to get transfer status: {:?}", e),
             }
         }
@@ -1476,6 +4374,7 @@ fn test_get_xfer_telemetry_from_request() {
                     std::thread::sleep(std::time::Duration::from_millis(10));
                     continue;
                 }
+                Ok(XferStatus::Failed { code }) => panic!("Transfer failed with code {code}"),
                 Err(e) => panic!("Failed to get transfer status: {:?}", e),
             }
         }
@@ -1528,6 +4427,7 @@ fn test_get_xfer_telemetry_without_telemetry_enabled() {
                     std::thread::sleep(std::time::Duration::from_millis(10));
                     continue;
                 }
+                Ok(XferStatus::Failed { code }) => panic!("Transfer failed with code {code}"),
                 Err(e) => panic!("Failed to get transfer status: {:?}", e),
             }
         }
@@ -1717,3 +4617,109 @@ fn test_descriptor_list_equality() {
         assert!(!(xfer_list9 == xfer_list10));
     }
 }
+
+// Loads the remote's metadata and runs one write, written only against the trait
+fn write_through_api<A: AgentApi>(
+    local: &A,
+    remote: &A,
+    local_descs: &A::XferDescList<'_>,
+    remote_descs: &A::XferDescList<'_>,
+) -> Result<XferStatus, NixlError> {
+    local.load_remote_md(&remote.get_local_md()?)?;
+    let req = local.create_xfer_req(XferOp::Write, local_descs, remote_descs, &remote.name(), None)?;
+    local.post_xfer_req(&req, None)?;
+    loop {
+        match local.get_xfer_status(&req)? {
+            XferStatus::InProgress => std::thread::sleep(std::time::Duration::from_millis(10)),
+            status => return Ok(status),
+        }
+    }
+}
+
+#[test]
+fn test_agent_api() {
+    let (agent1, opt_args1) = create_agent_with_backend("agent_api_agent1").unwrap();
+    let (agent2, opt_args2) = create_agent_with_backend("agent_api_agent2").unwrap();
+    let mut local_storage = create_storage_list(&agent1, &opt_args1, 1);
+    let mut remote_storage = create_storage_list(&agent2, &opt_args2, 1);
+    let local_dlist = create_dlist(&mut local_storage).unwrap();
+    let remote_dlist = create_dlist(&mut remote_storage).unwrap();
+
+    let status = write_through_api(&agent1, &agent2, &local_dlist, &remote_dlist).unwrap();
+    assert_eq!(status, XferStatus::Success);
+    assert!(AgentApi::is_remote_loaded(&agent1, "agent_api_agent2"));
+
+    AgentApi::load_remote_md(&agent2, &AgentApi::get_local_md(&agent1).unwrap()).unwrap();
+    AgentApi::send_notification(&agent1, "agent_api_agent2", b"over the trait").unwrap();
+    let notifs = loop {
+        let notifs = AgentApi::take_notifications(&agent2).unwrap();
+        if !notifs.is_empty() {
+            break notifs;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    };
+    assert_eq!(notifs["agent_api_agent1"], vec![b"over the trait".to_vec()]);
+
+    AgentApi::invalidate_remote_md(&agent1, "agent_api_agent2").unwrap();
+    assert!(!AgentApi::is_remote_loaded(&agent1, "agent_api_agent2"));
+}
+
+#[cfg(feature = "mock")]
+#[test]
+fn test_mock_agent() {
+    let agent = MockAgent::new("mock_agent");
+    let desc = XferDescriptor { addr: 0x1000, len: 64, dev_id: 0 };
+
+    assert!(matches!(
+        agent.create_xfer_req(XferOp::Write, &[desc], &[desc], "mock_remote"),
+        Err(NixlError::RemoteNotLoaded(_))
+    ));
+    agent.add_remote("mock_remote");
+
+    // Requests with no scripted outcome complete when posted
+    let req = agent
+        .create_xfer_req(XferOp::Write, &[desc], &[desc], "mock_remote")
+        .unwrap();
+    assert_eq!(req.total_bytes(), 64);
+    assert!(matches!(agent.get_xfer_status(&req), Err(NixlError::InvalidParam)));
+    assert!(!agent.post_xfer_req(&req).unwrap());
+    assert!(matches!(agent.post_xfer_req(&req), Err(NixlError::XferInProgress)));
+    assert_eq!(agent.get_xfer_status(&req).unwrap(), XferStatus::Success);
+
+    agent.push_outcome(MockOutcome::CompleteAfter(2));
+    agent.push_outcome(MockOutcome::Fail(-3));
+    let slow = agent
+        .create_xfer_req(XferOp::Read, &[desc], &[desc], "mock_remote")
+        .unwrap();
+    let failing = agent
+        .create_xfer_req(XferOp::Read, &[desc], &[desc], "mock_remote")
+        .unwrap();
+    assert!(agent.post_xfer_req(&slow).unwrap());
+    assert_eq!(agent.get_xfer_status(&slow).unwrap(), XferStatus::InProgress);
+    assert_eq!(agent.get_xfer_status(&slow).unwrap(), XferStatus::InProgress);
+    assert_eq!(agent.get_xfer_status(&slow).unwrap(), XferStatus::Success);
+    agent.post_xfer_req(&failing).unwrap();
+    assert_eq!(
+        agent.get_xfer_status(&failing).unwrap(),
+        XferStatus::Failed { code: -3 }
+    );
+
+    // Notifications are recorded and injected through a shared clone
+    let handle = agent.clone();
+    agent.send_notification("mock_remote", b"done").unwrap();
+    assert_eq!(
+        handle.sent_notifications(),
+        vec![("mock_remote".to_string(), b"done".to_vec())]
+    );
+    handle.inject_notification("mock_remote", b"ack");
+    let notifs = agent.get_notifications().unwrap();
+    assert_eq!(notifs["mock_remote"], vec![b"ack".to_vec()]);
+    assert!(agent.get_notifications().unwrap().is_empty());
+
+    // The same trait-generic logic runs against the mock
+    let peer = MockAgent::new("mock_peer");
+    agent.push_outcome(MockOutcome::Fail(-1));
+    let status = write_through_api(&agent, &peer, &[desc][..], &[desc][..]).unwrap();
+    assert_eq!(status, XferStatus::Failed { code: -1 });
+    assert!(AgentApi::is_remote_loaded(&agent, "mock_peer"));
+}
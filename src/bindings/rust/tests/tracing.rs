@@ -0,0 +1,37 @@
+// SPDX-FileCopyrightText: Copyright (c) 2025 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests of the global tracing subscriber
+//!
+//! Kept in their own test binary, since the subscriber is installed once per
+//! process and would otherwise capture the output of every other test.
+
+#![cfg(feature = "tracing-subscriber")]
+
+use nixl_sys::*;
+use tracing::level_filters::LevelFilter;
+
+#[test]
+fn test_init_tracing() {
+    assert_eq!(TRACING_TARGET, "nixl");
+    init_tracing(LevelFilter::TRACE).expect("Failed to install subscriber");
+    assert!(init_tracing(LevelFilter::DEBUG).is_err());
+
+    // Events of the crate now go through the installed subscriber
+    let agent = Agent::new("tracing_agent").expect("Failed to create agent");
+    let (_mems, params) = agent.get_plugin_params("UCX").expect("Failed to get plugin params");
+    agent.create_backend("UCX", &params).expect("Failed to create backend");
+    assert!(agent.get_local_md().is_ok());
+}
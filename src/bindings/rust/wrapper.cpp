@@ -19,6 +19,7 @@
 #include "nixl.h"
 #include "nixl_types.h"
 
+#include <algorithm>
 #include <cstdlib>
 #include <cstring>
 #include <exception>
@@ -41,10 +42,25 @@ nixl_capi_thread_sync_to_nixl(nixl_capi_thread_sync_t sync) {
     }
 }
 
+// Plugins that report a temporarily full submission queue as
+// NIXL_ERR_NOT_ALLOWED, which nixl_capi_post_xfer_req maps to
+// NIXL_CAPI_ERROR_WOULD_BLOCK for their backends only. GUSLI returns it for
+// E_THROTTLE_RETRY_LATER, see src/plugins/gusli/gusli_backend.cpp.
+static const char *const nixl_capi_throttling_plugins[] = {"GUSLI"};
+
+static bool
+nixl_capi_is_throttling_plugin(const char *plugin_name) {
+    return std::any_of(std::begin(nixl_capi_throttling_plugins),
+                       std::end(nixl_capi_throttling_plugins),
+                       [plugin_name](const char *name) { return std::strcmp(name, plugin_name) == 0; });
+}
+
 extern "C" {
 // Internal struct definitions to match our opaque types
 struct nixl_capi_agent_s {
   nixlAgent* inner;
+  // Backends whose NIXL_ERR_NOT_ALLOWED on post means a full queue
+  std::vector<nixlBackendH*> throttling_backends;
 };
 
 struct nixl_capi_string_list_s {
@@ -139,6 +155,12 @@ nixl_capi_create_configured_agent(const char *name,
                                     cfg->pthr_delay_us,
                                     cfg->lthr_delay_us,
                                     cfg->capture_telemetry);
+        if (cfg->etcd_endpoints) {
+            nixl_config.etcdEndpoints = cfg->etcd_endpoints;
+        }
+        if (cfg->etcd_namespace) {
+            nixl_config.etcdNamespace = cfg->etcd_namespace;
+        }
 
         auto agent_handle = new nixl_capi_agent_s;
         agent_handle->inner = new nixlAgent(name, nixl_config);
@@ -395,6 +417,30 @@ nixl_capi_get_available_plugins(nixl_capi_agent_t agent, nixl_capi_string_list_t
   }
 }
 
+nixl_capi_status_t
+nixl_capi_has_plugin(nixl_capi_agent_t agent, const char* plugin_name, bool* has_plugin)
+{
+  if (!agent || !plugin_name || !has_plugin) {
+    return NIXL_CAPI_ERROR_INVALID_PARAM;
+  }
+
+  try {
+    std::vector<nixl_backend_t> backend_plugins;
+    nixl_status_t ret = agent->inner->getAvailPlugins(backend_plugins);
+
+    if (ret != NIXL_SUCCESS) {
+      return NIXL_CAPI_ERROR_BACKEND;
+    }
+
+    *has_plugin = std::find(backend_plugins.begin(), backend_plugins.end(), plugin_name) != backend_plugins.end();
+
+    return NIXL_CAPI_SUCCESS;
+  }
+  catch (...) {
+    return NIXL_CAPI_ERROR_BACKEND;
+  }
+}
+
 nixl_capi_status_t
 nixl_capi_destroy_string_list(nixl_capi_string_list_t list)
 {
@@ -522,6 +568,12 @@ nixl_capi_create_backend(
       return NIXL_CAPI_ERROR_BACKEND;
     }
 
+    // Other plugins such as HF3FS use NIXL_ERR_NOT_ALLOWED for requests
+    // that can never be posted
+    if (nixl_capi_is_throttling_plugin(plugin_name)) {
+      agent->throttling_backends.push_back(backend_handle->backend);
+    }
+
     *backend = backend_handle;
     return NIXL_CAPI_SUCCESS;
   }
@@ -546,6 +598,17 @@ nixl_capi_destroy_backend(nixl_capi_backend_t backend)
   }
 }
 
+nixl_capi_status_t
+nixl_capi_backend_is_same(nixl_capi_backend_t backend, nixl_capi_backend_t other, bool *same)
+{
+  if (!backend || !other || !same) {
+    return NIXL_CAPI_ERROR_INVALID_PARAM;
+  }
+
+  *same = backend->backend == other->backend;
+  return NIXL_CAPI_SUCCESS;
+}
+
 nixl_capi_status_t
 nixl_capi_create_opt_args(nixl_capi_opt_args_t* args)
 {
@@ -705,6 +768,38 @@ nixl_capi_opt_args_get_skip_desc_merge(nixl_capi_opt_args_t args, bool* skip_mer
   }
 }
 
+nixl_capi_status_t
+nixl_capi_opt_args_set_include_conn_info(nixl_capi_opt_args_t args, bool include_conn_info)
+{
+  if (!args) {
+    return NIXL_CAPI_ERROR_INVALID_PARAM;
+  }
+
+  try {
+    args->args.includeConnInfo = include_conn_info;
+    return NIXL_CAPI_SUCCESS;
+  }
+  catch (...) {
+    return NIXL_CAPI_ERROR_BACKEND;
+  }
+}
+
+nixl_capi_status_t
+nixl_capi_opt_args_get_include_conn_info(nixl_capi_opt_args_t args, bool* include_conn_info)
+{
+  if (!args || !include_conn_info) {
+    return NIXL_CAPI_ERROR_INVALID_PARAM;
+  }
+
+  try {
+    *include_conn_info = args->args.includeConnInfo;
+    return NIXL_CAPI_SUCCESS;
+  }
+  catch (...) {
+    return NIXL_CAPI_ERROR_BACKEND;
+  }
+}
+
 nixl_capi_status_t
 nixl_capi_opt_args_set_ip_addr(nixl_capi_opt_args_t args, const char *ip_addr) {
     if (!args || !ip_addr) {
@@ -746,6 +841,22 @@ nixl_capi_params_is_empty(nixl_capi_params_t params, bool* is_empty)
   }
 }
 
+nixl_capi_status_t
+nixl_capi_params_set(nixl_capi_params_t params, const char* key, const char* value)
+{
+  if (!params || !key || !value) {
+    return NIXL_CAPI_ERROR_INVALID_PARAM;
+  }
+
+  try {
+    params->params[key] = value;
+    return NIXL_CAPI_SUCCESS;
+  }
+  catch (...) {
+    return NIXL_CAPI_ERROR_BACKEND;
+  }
+}
+
 nixl_capi_status_t
 nixl_capi_params_create_iterator(nixl_capi_params_t params, nixl_capi_param_iter_t* iter)
 {
@@ -1467,7 +1578,7 @@ nixl_capi_estimate_xfer_cost(
     nixl_capi_agent_t agent, nixl_capi_xfer_req_t req_hndl, nixl_capi_opt_args_t opt_args,
     int64_t *duration_us, int64_t *err_margin_us, nixl_capi_cost_t *method)
 {
-  if (!agent || !req_hndl || !duration_us || !err_margin_us || !method) {
+  if (!agent || !req_hndl || !req_hndl->req || !duration_us || !err_margin_us || !method) {
     return NIXL_CAPI_ERROR_INVALID_PARAM;
   }
 
@@ -1489,14 +1600,42 @@ nixl_capi_estimate_xfer_cost(
 nixl_capi_status_t
 nixl_capi_post_xfer_req(nixl_capi_agent_t agent, nixl_capi_xfer_req_t req_hndl, nixl_capi_opt_args_t opt_args)
 {
-  if (!agent || !req_hndl) {
+  if (!agent || !req_hndl || !req_hndl->req) {
     return NIXL_CAPI_ERROR_INVALID_PARAM;
   }
 
   try {
     nixl_status_t ret = agent->inner->postXferReq(req_hndl->req, opt_args ? &opt_args->args : nullptr);
 
-    return ret == NIXL_SUCCESS ? NIXL_CAPI_SUCCESS : ret == NIXL_IN_PROG ? NIXL_CAPI_IN_PROG : NIXL_CAPI_ERROR_BACKEND;
+    switch (ret) {
+      case NIXL_SUCCESS:
+        return NIXL_CAPI_SUCCESS;
+      case NIXL_IN_PROG:
+        return NIXL_CAPI_IN_PROG;
+      case NIXL_ERR_REPOST_ACTIVE:
+        return NIXL_CAPI_ERROR_INVALID_STATE;
+      case NIXL_ERR_NOT_FOUND:
+      case NIXL_ERR_REMOTE_DISCONNECT:
+        return NIXL_CAPI_ERROR_REMOTE_DISCONNECT;
+      case NIXL_ERR_NOT_ALLOWED: {
+        // Only backends of nixl_capi_throttling_plugins report a temporarily
+        // full queue this way
+        nixlBackendH* backend = nullptr;
+        if (agent->inner->queryXferBackend(req_hndl->req, backend) == NIXL_SUCCESS &&
+            std::find(agent->throttling_backends.begin(), agent->throttling_backends.end(), backend) !=
+                agent->throttling_backends.end()) {
+          return NIXL_CAPI_ERROR_WOULD_BLOCK;
+        }
+        return NIXL_CAPI_ERROR_REJECTED;
+      }
+      case NIXL_ERR_INVALID_PARAM:
+        return NIXL_CAPI_ERROR_INVALID_PARAM;
+      case NIXL_ERR_BACKEND:
+        return NIXL_CAPI_ERROR_BACKEND;
+      default:
+        // Mismatched, unsupported or unknown requests fail the same way again
+        return NIXL_CAPI_ERROR_REJECTED;
+    }
   }
   catch (...) {
     return NIXL_CAPI_ERROR_BACKEND;
@@ -1504,15 +1643,30 @@ nixl_capi_post_xfer_req(nixl_capi_agent_t agent, nixl_capi_xfer_req_t req_hndl,
 }
 
 nixl_capi_status_t
-nixl_capi_get_xfer_status(nixl_capi_agent_t agent, nixl_capi_xfer_req_t req_hndl)
+nixl_capi_get_xfer_status(nixl_capi_agent_t agent, nixl_capi_xfer_req_t req_hndl, int *xfer_err)
 {
-  if (!agent || !req_hndl) {
+  if (!agent || !req_hndl || !req_hndl->req) {
     return NIXL_CAPI_ERROR_INVALID_PARAM;
   }
 
   try {
     nixl_status_t ret = agent->inner->getXferStatus(req_hndl->req);
-    return ret == NIXL_SUCCESS ? NIXL_CAPI_SUCCESS : ret == NIXL_IN_PROG ? NIXL_CAPI_IN_PROG : NIXL_CAPI_ERROR_BACKEND;
+    switch (ret) {
+      case NIXL_SUCCESS:
+        return NIXL_CAPI_SUCCESS;
+      case NIXL_IN_PROG:
+        return NIXL_CAPI_IN_PROG;
+      case NIXL_ERR_INVALID_PARAM:
+        return NIXL_CAPI_ERROR_INVALID_PARAM;
+      case NIXL_ERR_NOT_POSTED:
+        return NIXL_CAPI_ERROR_INVALID_STATE;
+      default:
+        // Any other error was reported by the transfer itself
+        if (xfer_err) {
+          *xfer_err = ret;
+        }
+        return NIXL_CAPI_ERROR_XFER_FAILED;
+    }
   }
   catch (...) {
     return NIXL_CAPI_ERROR_BACKEND;
@@ -1523,7 +1677,7 @@ nixl_capi_status_t
 nixl_capi_query_xfer_backend(nixl_capi_agent_t agent,
                              nixl_capi_xfer_req_t req_hndl,
                              nixl_capi_backend_t *backend) {
-    if (!agent || !req_hndl || !backend) {
+    if (!agent || !req_hndl || !req_hndl->req || !backend) {
         return NIXL_CAPI_ERROR_INVALID_PARAM;
     }
     try {
@@ -1572,8 +1726,10 @@ nixl_capi_release_xfer_req(nixl_capi_agent_t agent, nixl_capi_xfer_req_t req)
     nixl_status_t ret = agent->inner->releaseXferReq(req->req);
     if (ret == NIXL_SUCCESS) {
       req->req = nullptr;  // Prevent double-free in destroy
+      return NIXL_CAPI_SUCCESS;
     }
-    return ret == NIXL_SUCCESS ? NIXL_CAPI_SUCCESS : NIXL_CAPI_ERROR_BACKEND;
+    // The backend could not abort a transfer still in progress
+    return ret == NIXL_ERR_REPOST_ACTIVE ? NIXL_CAPI_ERROR_INVALID_STATE : NIXL_CAPI_ERROR_BACKEND;
   }
   catch (...) {
     return NIXL_CAPI_ERROR_BACKEND;
@@ -1751,6 +1907,23 @@ nixl_capi_notif_map_clear(nixl_capi_notif_map_t map)
   }
 }
 
+nixl_capi_status_t
+nixl_capi_notif_map_append(
+    nixl_capi_notif_map_t map, const char* agent_name, const void* data, size_t len)
+{
+  if (!map || !agent_name || (!data && len > 0)) {
+    return NIXL_CAPI_ERROR_INVALID_PARAM;
+  }
+
+  try {
+    map->notif_map[agent_name].emplace_back(static_cast<const char*>(data), len);
+    return NIXL_CAPI_SUCCESS;
+  }
+  catch (const std::exception& e) {
+    return NIXL_CAPI_ERROR_BACKEND;
+  }
+}
+
 // Query response list functions
 nixl_capi_status_t
 nixl_capi_create_query_resp_list(nixl_capi_query_resp_list_t *list) {
@@ -1876,7 +2049,7 @@ nixl_capi_status_t
 nixl_capi_get_xfer_telemetry(nixl_capi_agent_t agent,
                              nixl_capi_xfer_req_t req_hndl,
                              nixl_capi_xfer_telemetry_t telemetry) {
-    if (!agent || !req_hndl || !telemetry) {
+    if (!agent || !req_hndl || !req_hndl->req || !telemetry) {
         return NIXL_CAPI_ERROR_INVALID_PARAM;
     }
 
@@ -33,6 +33,11 @@ typedef enum {
     NIXL_CAPI_ERROR_EXCEPTION = -4,
     NIXL_CAPI_IN_PROG = 1,
     NIXL_CAPI_ERROR_NO_TELEMETRY = -5,
+    NIXL_CAPI_ERROR_REMOTE_DISCONNECT = -6,
+    NIXL_CAPI_ERROR_XFER_FAILED = -7,
+    NIXL_CAPI_ERROR_WOULD_BLOCK = -8,
+    // The backend refused the request for a reason retrying does not fix
+    NIXL_CAPI_ERROR_REJECTED = -9,
 } nixl_capi_status_t;
 
 // Memory types enum (matching nixl's memory types)
@@ -100,6 +105,8 @@ typedef struct nixl_capi_agent_config_s {
     uint64_t pthr_delay_us;
     uint64_t lthr_delay_us;
     bool capture_telemetry;
+    const char* etcd_endpoints;  // NULL to use NIXL_ETCD_ENDPOINTS
+    const char* etcd_namespace;  // NULL to use NIXL_ETCD_NAMESPACE
 } nixl_capi_agent_config_t;
 
 // Transfer request functions
@@ -159,6 +166,7 @@ nixl_capi_status_t nixl_capi_fetch_remote_md(nixl_capi_agent_t agent, const char
 
 // Plugin and parameter functions
 nixl_capi_status_t nixl_capi_get_available_plugins(nixl_capi_agent_t agent, nixl_capi_string_list_t* plugins);
+nixl_capi_status_t nixl_capi_has_plugin(nixl_capi_agent_t agent, const char* plugin_name, bool* has_plugin);
 nixl_capi_status_t nixl_capi_destroy_string_list(nixl_capi_string_list_t list);
 nixl_capi_status_t nixl_capi_string_list_size(nixl_capi_string_list_t list, size_t* size);
 nixl_capi_status_t nixl_capi_string_list_get(nixl_capi_string_list_t list, size_t index, const char** str);
@@ -174,6 +182,10 @@ nixl_capi_status_t nixl_capi_create_backend(
     nixl_capi_agent_t agent, const char* plugin_name, nixl_capi_params_t params, nixl_capi_backend_t* backend);
 nixl_capi_status_t nixl_capi_destroy_backend(nixl_capi_backend_t backend);
 
+// Checks whether two backend handles refer to the same backend engine
+nixl_capi_status_t
+nixl_capi_backend_is_same(nixl_capi_backend_t backend, nixl_capi_backend_t other, bool *same);
+
 // Get backend parameters after initialization
 nixl_capi_status_t nixl_capi_get_backend_params(
     nixl_capi_agent_t agent, nixl_capi_backend_t backend, nixl_capi_mem_list_t* mems, nixl_capi_params_t* params);
@@ -190,6 +202,8 @@ nixl_capi_status_t nixl_capi_opt_args_set_has_notif(nixl_capi_opt_args_t args, b
 nixl_capi_status_t nixl_capi_opt_args_get_has_notif(nixl_capi_opt_args_t args, bool* has_notif);
 nixl_capi_status_t nixl_capi_opt_args_set_skip_desc_merge(nixl_capi_opt_args_t args, bool skip_merge);
 nixl_capi_status_t nixl_capi_opt_args_get_skip_desc_merge(nixl_capi_opt_args_t args, bool* skip_merge);
+nixl_capi_status_t nixl_capi_opt_args_set_include_conn_info(nixl_capi_opt_args_t args, bool include_conn_info);
+nixl_capi_status_t nixl_capi_opt_args_get_include_conn_info(nixl_capi_opt_args_t args, bool* include_conn_info);
 nixl_capi_status_t
 nixl_capi_opt_args_set_ip_addr(nixl_capi_opt_args_t args, const char *ip_addr);
 nixl_capi_status_t
@@ -197,6 +211,7 @@ nixl_capi_opt_args_set_port(nixl_capi_opt_args_t args, uint16_t port);
 
 // Parameter access functions
 nixl_capi_status_t nixl_capi_params_is_empty(nixl_capi_params_t params, bool* is_empty);
+nixl_capi_status_t nixl_capi_params_set(nixl_capi_params_t params, const char* key, const char* value);
 nixl_capi_status_t nixl_capi_params_create_iterator(nixl_capi_params_t params, nixl_capi_param_iter_t* iter);
 nixl_capi_status_t nixl_capi_params_iterator_next(
     nixl_capi_param_iter_t iter, const char** key, const char** value, bool* has_next);
@@ -270,13 +285,17 @@ nixl_capi_status_t nixl_capi_estimate_xfer_cost(
 nixl_capi_status_t nixl_capi_post_xfer_req(
     nixl_capi_agent_t agent, nixl_capi_xfer_req_t req_hndl, nixl_capi_opt_args_t opt_args);
 
-nixl_capi_status_t nixl_capi_get_xfer_status(nixl_capi_agent_t agent, nixl_capi_xfer_req_t req_hndl);
+// On NIXL_CAPI_ERROR_XFER_FAILED, the transfer error code is stored in xfer_err if it is non-null
+nixl_capi_status_t
+nixl_capi_get_xfer_status(nixl_capi_agent_t agent, nixl_capi_xfer_req_t req_hndl, int *xfer_err);
 
 nixl_capi_status_t
 nixl_capi_query_xfer_backend(nixl_capi_agent_t agent,
                              nixl_capi_xfer_req_t req_hndl,
                              nixl_capi_backend_t *backend);
 
+// Aborts the transfer if it is still in progress. Returns NIXL_CAPI_ERROR_INVALID_STATE if
+// the backend cannot abort it, in which case the request stays valid
 nixl_capi_status_t nixl_capi_release_xfer_req(nixl_capi_agent_t agent, nixl_capi_xfer_req_t req);
 
 nixl_capi_status_t nixl_capi_destroy_xfer_req(nixl_capi_xfer_req_t req);
@@ -321,6 +340,8 @@ nixl_capi_status_t nixl_capi_notif_map_get_notifs_size(nixl_capi_notif_map_t map
 nixl_capi_status_t nixl_capi_notif_map_get_notif(
     nixl_capi_notif_map_t map, const char* agent_name, size_t index, const void** data, size_t* len);
 nixl_capi_status_t nixl_capi_notif_map_clear(nixl_capi_notif_map_t map);
+nixl_capi_status_t nixl_capi_notif_map_append(
+    nixl_capi_notif_map_t map, const char* agent_name, const void* data, size_t len);
 
 // Query response list functions
 nixl_capi_status_t
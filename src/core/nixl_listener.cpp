@@ -199,9 +199,13 @@ private:
 
 public:
     nixlEtcdClient(const std::string &my_agent_name,
-                   const std::chrono::microseconds &timeout = std::chrono::microseconds(5000000))
+                   const std::chrono::microseconds &timeout = std::chrono::microseconds(5000000),
+                   const std::string &endpoints = "",
+                   const std::string &ns = "")
         : watchTimeout_(timeout) {
-        const char* etcd_endpoints = std::getenv("NIXL_ETCD_ENDPOINTS");
+        // Per-agent configuration takes precedence over the environment
+        const char* etcd_endpoints = endpoints.empty() ? std::getenv("NIXL_ETCD_ENDPOINTS") :
+                                                         endpoints.c_str();
         if (!etcd_endpoints || strlen(etcd_endpoints) == 0) {
             throw std::runtime_error("No etcd endpoints provided");
         }
@@ -215,7 +219,7 @@ public:
         }
         NIXL_DEBUG << "Created etcd client to endpoints: " << etcd_endpoints;
 
-        const char* etcd_namespace = std::getenv("NIXL_ETCD_NAMESPACE");
+        const char* etcd_namespace = ns.empty() ? std::getenv("NIXL_ETCD_NAMESPACE") : ns.c_str();
         namespace_prefix = etcd_namespace ? etcd_namespace : NIXL_ETCD_NAMESPACE_DEFAULT;
 
         NIXL_DEBUG << "Using etcd namespace for agents: " << namespace_prefix;
@@ -439,9 +443,11 @@ void nixlAgentData::commWorker(nixlAgent* myAgent){
 
 #if HAVE_ETCD
     std::unique_ptr<nixlEtcdClient> etcdClient = nullptr;
-    // useEtcd is set in nixlAgent constructor and is true if NIXL_ETCD_ENDPOINTS is set
+    // useEtcd is set in nixlAgent constructor and is true if etcd endpoints are configured
+    // for this agent or NIXL_ETCD_ENDPOINTS is set
     if(useEtcd) {
-        etcdClient = std::make_unique<nixlEtcdClient>(name, config.etcdWatchTimeout);
+        etcdClient = std::make_unique<nixlEtcdClient>(
+            name, config.etcdWatchTimeout, config.etcdEndpoints, config.etcdNamespace);
     }
 #endif // HAVE_ETCD
 